@@ -3,23 +3,82 @@
 //! Provides database connection using Spin variables.
 //! Database name is configured in spin configuration files.
 
-use spin_sdk::sqlite::{Connection, Error as SqliteError};
+use spin_sdk::sqlite::{Connection, Error as SqliteError, Value};
 use spin_sdk::variables;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tracing::debug;
 
+/// Current schema version, stored in `PRAGMA user_version`
+///
+/// - 0: original schema (shared secret expires_at in hours since Unix epoch)
+/// - 1: shared secret expires_at in seconds, shared_secrets_tracking.not_before
+const SCHEMA_VERSION: i64 = 1;
+
+/// Set once this instance has seen the schema at SCHEMA_VERSION
+static SCHEMA_CURRENT: AtomicBool = AtomicBool::new(false);
+
 /// Get database connection using configured database name
 ///
+/// The first connection of each instance migrates the schema to SCHEMA_VERSION,
+/// so no handler ever reads rows in an older format.
+///
 /// # Returns
 /// * `Result<Connection, SqliteError>` - Database connection or error
 pub fn get_database_connection() -> Result<Connection, SqliteError> {
     let db_name = variables::get("database_name").map_err(|_| SqliteError::AccessDenied)?;
     debug!("Database: Connecting to database: '{}'", db_name);
-    Connection::open(&db_name)
+    let connection = Connection::open(&db_name)?;
+
+    if !SCHEMA_CURRENT.load(Ordering::Relaxed) {
+        migrate_schema(&connection)?;
+        SCHEMA_CURRENT.store(true, Ordering::Relaxed);
+    }
+
+    Ok(connection)
+}
+
+/// Whether the database schema is at SCHEMA_VERSION (after any pending migration)
+///
+/// Destructive maintenance (expired-row cleanup) must not run on an older
+/// schema, whose timestamps use different units.
+///
+/// # Returns
+/// * `Result<bool, SqliteError>` - true if the schema is current
+pub fn schema_is_current() -> Result<bool, SqliteError> {
+    let connection = get_database_connection()?;
+    Ok(schema_version(&connection)? >= SCHEMA_VERSION)
+}
+
+/// Schema version stored in `PRAGMA user_version` (0 for databases that predate it)
+///
+/// # Returns
+/// * `Result<i64, SqliteError>` - Stored schema version or database error
+fn schema_version(connection: &Connection) -> Result<i64, SqliteError> {
+    let result = connection.execute("PRAGMA user_version", &[])?;
+    match result.rows.first().map(|row| &row.values[0]) {
+        Some(Value::Integer(version)) => Ok(*version),
+        _ => Ok(0),
+    }
+}
+
+/// Number of rows changed by the last INSERT, UPDATE or DELETE on `connection`
+///
+/// Spin's SQLite interface has no rows_affected, so this asks SQLite's changes().
+///
+/// # Returns
+/// * `Result<u32, SqliteError>` - Row count or database error
+pub fn changed_rows(connection: &Connection) -> Result<u32, SqliteError> {
+    let result = connection.execute("SELECT changes()", &[])?;
+    match result.rows.first().map(|row| &row.values[0]) {
+        Some(Value::Integer(count)) => Ok(*count as u32),
+        _ => Err(SqliteError::Io("Invalid changes() result".to_string())),
+    }
 }
 
 /// Initialize database tables
 ///
 /// Creates all application tables: users, magiclinks, shared_secrets, shared_secrets_tracking,
+/// shared_secrets_receivers, shared_secrets_attachment_chunks, shared_secret_requests,
 /// user_privkey_context, user_ed25519_keys, user_x25519_keys
 ///
 /// # Returns
@@ -63,7 +122,7 @@ pub fn initialize_database() -> Result<(), SqliteError> {
         CREATE TABLE IF NOT EXISTS shared_secrets (
             id BLOB PRIMARY KEY,              -- Encrypted ID (similar to magic_link hash)
             encrypted_payload BLOB NOT NULL,  -- ChaCha20(sender_email || receiver_email || text || otp || created_at || reference_hash || max_reads)
-            expires_at INTEGER NOT NULL,      -- Expiration timestamp in hours since Unix epoch (for cleanup)
+            expires_at INTEGER NOT NULL,      -- Expiration timestamp in seconds since Unix epoch (for cleanup)
             role TEXT NOT NULL                -- 'sender' or 'receiver'
         )
         "#,
@@ -78,7 +137,56 @@ pub fn initialize_database() -> Result<(), SqliteError> {
             pending_reads INTEGER NOT NULL,   -- Countdown reads counter (moved from shared_secrets)
             read_at INTEGER,                  -- Timestamp of first read by receiver (NULL if unread)
             expires_at INTEGER NOT NULL,      -- Expiration timestamp (matches shared_secrets.expires_at)
-            encrypted_payload BLOB NOT NULL   -- v3: Centralized encrypted payload (ChaCha20-Poly1305)
+            encrypted_payload BLOB NOT NULL,  -- v3: Centralized encrypted payload (ChaCha20-Poly1305)
+            not_before INTEGER                -- Activation timestamp for receivers (NULL = readable immediately)
+        )
+        "#,
+        &[],
+    )?;
+
+    // Create shared_secrets_receivers table for per-receiver read tracking
+    connection.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS shared_secrets_receivers (
+            db_index BLOB PRIMARY KEY,        -- Receiver's shared_secrets.id
+            reference_hash BLOB NOT NULL,     -- Links receiver to shared_secrets_tracking
+            pending_reads INTEGER NOT NULL,   -- Countdown reads for this receiver (starts at max_reads)
+            read_at INTEGER,                  -- Timestamp of this receiver's first read (NULL if unread)
+            pending_downloads INTEGER,        -- Countdown attachment downloads (NULL if no attachment)
+            expires_at INTEGER NOT NULL       -- Expiration timestamp (matches shared_secrets_tracking.expires_at)
+        )
+        "#,
+        &[],
+    )?;
+
+    // Create index for listing receivers of a reference (sender view, deletion)
+    connection.execute(
+        "CREATE INDEX IF NOT EXISTS idx_shared_secrets_receivers_reference ON shared_secrets_receivers(reference_hash)",
+        &[],
+    )?;
+
+    // Create shared_secrets_attachment_chunks table for E2E encrypted file chunks
+    connection.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS shared_secrets_attachment_chunks (
+            reference_hash BLOB NOT NULL,     -- Same [u8;16] as shared_secrets_tracking
+            chunk_index INTEGER NOT NULL,     -- Zero-based chunk position
+            encrypted_chunk BLOB NOT NULL,    -- ChaCha20-Poly1305(chunk) from frontend (64 KiB + 16-byte tag)
+            expires_at INTEGER NOT NULL,      -- Expiration timestamp (for cleanup)
+            PRIMARY KEY (reference_hash, chunk_index)
+        )
+        "#,
+        &[],
+    )?;
+
+    // Create shared_secret_requests table for pending "request a secret" invitations
+    connection.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS shared_secret_requests (
+            db_index BLOB PRIMARY KEY,        -- Invitee's db_index (becomes the sender's shared_secrets.id)
+            encrypted_key_material BLOB NOT NULL, -- ChaCha20(key_material[44]) with db_index-derived key
+            encrypted_payload BLOB NOT NULL,  -- ChaCha20-Poly1305(requester, invitee, message, limits)
+            expires_at INTEGER NOT NULL       -- Request expiration in seconds since Unix epoch (for cleanup)
         )
         "#,
         &[],
@@ -138,3 +246,97 @@ pub fn initialize_database() -> Result<(), SqliteError> {
 
     Ok(())
 }
+
+/// Bring databases created by older versions up to SCHEMA_VERSION
+///
+/// Runs inside an IMMEDIATE transaction so concurrent requests cannot apply the
+/// same migration twice. Fresh databases (no tables yet) are simply stamped with
+/// SCHEMA_VERSION; initialize_database then creates the current layout.
+fn migrate_schema(connection: &Connection) -> Result<(), SqliteError> {
+    if schema_version(connection)? >= SCHEMA_VERSION {
+        return Ok(());
+    }
+
+    connection.execute("BEGIN IMMEDIATE", &[])?;
+
+    match apply_migrations(connection) {
+        Ok(()) => {
+            connection.execute("COMMIT", &[])?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = connection.execute("ROLLBACK", &[]);
+            Err(e)
+        }
+    }
+}
+
+/// Apply every migration newer than the stored user_version
+fn apply_migrations(connection: &Connection) -> Result<(), SqliteError> {
+    let version = schema_version(connection)?;
+
+    if version >= SCHEMA_VERSION {
+        return Ok(());
+    }
+
+    if version < 1 {
+        // v1: shared secret timestamps move from hours to seconds since Unix epoch.
+        // Only hour-based values are converted (HOUR_TIMESTAMP_LIMIT), so rows
+        // already written in seconds are never multiplied twice.
+        for table in [
+            "shared_secrets",
+            "shared_secrets_tracking",
+            "shared_secrets_receivers",
+            "shared_secrets_attachment_chunks",
+            "shared_secret_requests",
+        ] {
+            if !table_exists(connection, table)? {
+                continue;
+            }
+            connection.execute(
+                &format!(
+                    "UPDATE {} SET expires_at = expires_at * 3600 WHERE expires_at < ?",
+                    table
+                ),
+                &[Value::Integer(HOUR_TIMESTAMP_LIMIT)],
+            )?;
+        }
+
+        if table_exists(connection, "shared_secrets_tracking")?
+            && !column_exists(connection, "shared_secrets_tracking", "not_before")?
+        {
+            connection.execute(
+                "ALTER TABLE shared_secrets_tracking ADD COLUMN not_before INTEGER",
+                &[],
+            )?;
+        }
+    }
+
+    connection.execute(&format!("PRAGMA user_version = {}", SCHEMA_VERSION), &[])?;
+    debug!(
+        "Database: Migrated schema from version {} to {}",
+        version, SCHEMA_VERSION
+    );
+    Ok(())
+}
+
+/// Upper bound for hour-based timestamps in the v1 migration
+///
+/// Hours since the epoch stay below 10^8 until the year 13377, while second-based
+/// timestamps passed 10^8 in 1973.
+const HOUR_TIMESTAMP_LIMIT: i64 = 100_000_000;
+
+/// Check whether a table exists (PRAGMA table_info has no rows otherwise)
+fn table_exists(connection: &Connection, table: &str) -> Result<bool, SqliteError> {
+    let result = connection.execute(&format!("PRAGMA table_info({})", table), &[])?;
+    Ok(!result.rows.is_empty())
+}
+
+/// Check whether a table already has a column (PRAGMA table_info)
+fn column_exists(connection: &Connection, table: &str, column: &str) -> Result<bool, SqliteError> {
+    let result = connection.execute(&format!("PRAGMA table_info({})", table), &[])?;
+    Ok(result
+        .rows
+        .iter()
+        .any(|row| matches!(row.values.get(1), Some(Value::Text(name)) if name == column)))
+}
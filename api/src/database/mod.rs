@@ -4,4 +4,4 @@
 pub mod connection;
 pub mod operations;
 
-pub use connection::get_database_connection;
+pub use connection::{changed_rows, get_database_connection, schema_is_current};
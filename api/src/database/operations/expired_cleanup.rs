@@ -0,0 +1,61 @@
+//! Probabilistic cleanup of expired database rows
+//!
+//! Spin HTTP components have no scheduler, so roughly one request in
+//! CLEANUP_ONE_IN also purges expired magic links and shared secrets (every
+//! shared secret table, secret requests included) and logs how many rows
+//! each table lost.
+
+use super::magic_link_ops::MagicLinkOperations;
+use super::shared_secret_ops::SharedSecretOps;
+use crate::database::schema_is_current;
+use tracing::{info, warn};
+
+/// One request in this many triggers a cleanup
+pub const CLEANUP_ONE_IN: u32 = 50;
+
+/// Run the expired-rows cleanup with probability 1/CLEANUP_ONE_IN
+pub fn maybe_cleanup_expired() {
+    if rand::random_ratio(1, CLEANUP_ONE_IN) {
+        cleanup_expired();
+    }
+}
+
+/// Delete expired magic links and shared secrets, logging deleted-row counts
+///
+/// Failures are logged and never affect the request that triggered the cleanup
+/// (e.g. before the first login has created the tables). Nothing is deleted
+/// unless the schema is at SCHEMA_VERSION: older schemas store expiries in hours
+/// and would lose every live row.
+pub fn cleanup_expired() {
+    match schema_is_current() {
+        Ok(true) => {}
+        Ok(false) => {
+            warn!("⚠️  Cleanup: skipped, database schema is not migrated yet");
+            return;
+        }
+        Err(e) => {
+            warn!("⚠️  Cleanup: skipped, cannot check schema version: {}", e);
+            return;
+        }
+    }
+
+    match MagicLinkOperations::cleanup_expired_links() {
+        Ok(magiclinks) => info!("🧹 Cleanup: {} expired magic links deleted", magiclinks),
+        Err(e) => warn!("⚠️  Cleanup: failed to delete expired magic links: {}", e),
+    }
+
+    match SharedSecretOps::cleanup_expired() {
+        Ok(counts) => info!(
+            "🧹 Cleanup: expired shared secret rows deleted (shared_secrets={}, tracking={}, receivers={}, attachment_chunks={}, requests={})",
+            counts.shared_secrets,
+            counts.tracking,
+            counts.receivers,
+            counts.attachment_chunks,
+            counts.requests
+        ),
+        Err(e) => warn!(
+            "⚠️  Cleanup: failed to delete expired shared secrets: {}",
+            e
+        ),
+    }
+}
@@ -5,7 +5,7 @@
 
 use super::magic_link_crypto::MagicLinkCrypto;
 use super::magic_link_types::constants::*;
-use crate::database::get_database_connection;
+use crate::database::{changed_rows, get_database_connection};
 use bs58;
 use chrono::Utc;
 use spin_sdk::sqlite::{Error as SqliteError, Value};
@@ -169,13 +169,11 @@ impl MagicLinkStorage {
         let connection = get_database_connection()?;
 
         let now_hours = (Utc::now().timestamp() / 3600) as u64;
-        let _result = connection.execute(
+        connection.execute(
             "DELETE FROM magiclinks WHERE expires_at < ?",
             &[Value::Integer(now_hours as i64)],
         )?;
 
-        // SQLite doesn't provide rows_affected in Spin SDK
-        // We'll return 1 as a placeholder for successful cleanup
-        Ok(1)
+        changed_rows(&connection)
     }
 }
@@ -19,6 +19,9 @@ pub mod shared_secret_ops;
 pub mod shared_secret_storage;
 pub mod shared_secret_types;
 
+// Probabilistic cleanup of expired magic links and shared secrets
+pub mod expired_cleanup;
+
 // User private key context operations
 pub mod user_privkey_ops;
 
@@ -47,7 +47,6 @@ impl SharedSecretCrypto {
     ///
     /// # Returns
     /// * `[u8; 44]` - Random 44-byte key material (nonce[12] + cipher_key[32])
-    #[allow(dead_code)]
     pub fn generate_random_key_material() -> [u8; KEY_MATERIAL_LENGTH] {
         random::generate_random_key_material()
     }
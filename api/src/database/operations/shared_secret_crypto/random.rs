@@ -54,7 +54,6 @@ pub fn generate_otp() -> String {
 ///
 /// # Returns
 /// * `[u8; 44]` - Random 44-byte key material (nonce[12] + cipher_key[32])
-#[allow(dead_code)]
 pub fn generate_random_key_material() -> [u8; KEY_MATERIAL_LENGTH] {
     use rand::RngCore;
     use rand::SeedableRng;
@@ -0,0 +1,79 @@
+//! Attachment operations for shared secrets
+//!
+//! Handles chunked attachment downloads with per-read accounting.
+
+use super::super::shared_secret_storage::SharedSecretStorage;
+use super::super::shared_secret_types::{SecretRole, constants::*};
+use super::tracking::release_if_consumed;
+use spin_sdk::sqlite::Error as SqliteError;
+use tracing::debug;
+
+/// Fetch one encrypted attachment chunk and account for completed downloads
+///
+/// Receiver downloads are limited like reads: each receiver's `pending_downloads`
+/// starts at max_reads and is decremented when the last chunk is served. Sender
+/// downloads are unlimited and never counted (UNLIMITED_READS is returned).
+/// A receiver entry kept after its last read is deleted with its last download.
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+/// * `db_index` - Requester database index (32 bytes)
+/// * `chunk_index` - Zero-based chunk index (must be below chunk_count)
+/// * `chunk_count` - Chunk count from the decrypted payload
+/// * `role` - Requester role (validated from hash checksum)
+///
+/// # Returns
+/// * `Result<(Vec<u8>, i64), SqliteError>` - (encrypted_chunk, pending_downloads) or error
+pub fn download_attachment_chunk(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    db_index: &[u8; DB_INDEX_LENGTH],
+    chunk_index: u32,
+    chunk_count: u32,
+    role: SecretRole,
+) -> Result<(Vec<u8>, i64), SqliteError> {
+    if chunk_index >= chunk_count {
+        return Err(SqliteError::Io(format!(
+            "Attachment chunk {} out of range (attachment has {} chunks)",
+            chunk_index, chunk_count
+        )));
+    }
+
+    let pending_downloads = match role {
+        SecretRole::Sender => UNLIMITED_READS,
+        SecretRole::Receiver => SharedSecretStorage::get_receiver_status(db_index)?
+            .and_then(|status| status.pending_downloads)
+            .ok_or_else(|| SqliteError::Io("Attachment not found".to_string()))?,
+    };
+
+    if role == SecretRole::Receiver && pending_downloads <= 0 {
+        return Err(SqliteError::Io(
+            "All attachment downloads have been consumed".to_string(),
+        ));
+    }
+
+    let chunk = SharedSecretStorage::retrieve_attachment_chunk(reference_hash, chunk_index)?
+        .ok_or_else(|| SqliteError::Io(format!("Attachment chunk {} not found", chunk_index)))?;
+
+    // A download counts once its last chunk has been served
+    let is_last_chunk = chunk_index + 1 == chunk_count;
+    let pending_downloads = if role == SecretRole::Receiver && is_last_chunk {
+        let pending_downloads = SharedSecretStorage::decrement_receiver_downloads(db_index)?;
+        // Entry outlived the last read only to serve downloads
+        if release_if_consumed(db_index)? {
+            debug!("🗑️  SharedSecret: Auto-deleted receiver entry (reads and downloads consumed)");
+        }
+        pending_downloads
+    } else {
+        pending_downloads
+    };
+
+    debug!(
+        "📎 SharedSecret: Served attachment chunk {}/{} (role={}, pending_downloads={})",
+        chunk_index + 1,
+        chunk_count,
+        role.to_str(),
+        pending_downloads
+    );
+
+    Ok((chunk, pending_downloads))
+}
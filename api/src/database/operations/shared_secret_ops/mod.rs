@@ -3,12 +3,17 @@
 //! Provides high-level business operations for shared secrets including
 //! creation, retrieval, validation, and tracking.
 
+mod attachment;
 pub mod payload;
 mod receiver;
+mod request;
 mod sender;
 mod tracking;
 
-use super::shared_secret_types::{SecretRole, SharedSecretPayload, constants::*};
+use super::shared_secret_types::{
+    CleanupCounts, EncryptedAttachment, SecretLifetime, SecretRequest, SecretRole,
+    SharedSecretPayload, SharedSecretReceiver, constants::*,
+};
 use spin_sdk::sqlite::Error as SqliteError;
 
 /// Shared secret operations - High-level business logic
@@ -28,16 +33,15 @@ impl SharedSecretOps {
     ///
     /// # Arguments
     /// * `sender_email` - Sender email address
-    /// * `receiver_email` - Receiver email address
+    /// * `receivers` - Receivers (email + optional OTP) with pre-computed database indexes (32 bytes)
     /// * `encrypted_secret` - ChaCha20-Poly1305 encrypted secret from frontend
+    /// * `attachment` - Optional encrypted file attachment from frontend
     /// * `encrypted_key_material` - ECDH encrypted key material from frontend (60 bytes: 44 + 16 MAC)
     /// * `sender_ed25519_public_key_hex` - Sender's Ed25519 public key as hex string (64 chars)
     /// * `sender_x25519_public_key_hex` - Sender's X25519 public key as hex string (64 chars)
-    /// * `otp` - Optional 9-digit OTP
-    /// * `expires_hours` - Expiration in hours (1-72)
-    /// * `max_reads` - Maximum reads for receiver (1-10)
+    /// * `lifetime` - Activation and expiration timestamps
+    /// * `max_reads` - Maximum reads per receiver (1-10)
     /// * `sender_db_index` - Pre-computed sender database index (32 bytes)
-    /// * `receiver_db_index` - Pre-computed receiver database index (32 bytes)
     /// * `reference_hash` - Pre-generated reference hash (16 bytes)
     ///
     /// # Returns
@@ -52,30 +56,28 @@ impl SharedSecretOps {
     #[allow(clippy::too_many_arguments)]
     pub fn create_secret_pair_with_ecdh(
         sender_email: &str,
-        receiver_email: &str,
+        receivers: &[(SharedSecretReceiver, [u8; DB_INDEX_LENGTH])],
         encrypted_secret: &[u8],
+        attachment: Option<&EncryptedAttachment>,
         encrypted_key_material: &[u8],
         sender_ed25519_public_key_hex: &str,
         sender_x25519_public_key_hex: &str,
-        otp: Option<String>,
-        expires_hours: i64,
+        lifetime: &SecretLifetime,
         max_reads: i64,
         sender_db_index: &[u8; 32],
-        receiver_db_index: &[u8; 32],
         reference_hash: &[u8; REFERENCE_HASH_LENGTH],
     ) -> Result<[u8; REFERENCE_HASH_LENGTH], SqliteError> {
         sender::create_secret_pair_with_ecdh(
             sender_email,
-            receiver_email,
+            receivers,
             encrypted_secret,
+            attachment,
             encrypted_key_material,
             sender_ed25519_public_key_hex,
             sender_x25519_public_key_hex,
-            otp,
-            expires_hours,
+            lifetime,
             max_reads,
             sender_db_index,
-            receiver_db_index,
             reference_hash,
         )
     }
@@ -101,6 +103,21 @@ impl SharedSecretOps {
         receiver::read_secret(db_index, reference_hash)
     }
 
+    /// Find the payload receiver a URL hash belongs to
+    ///
+    /// # Arguments
+    /// * `payload` - Decrypted payload
+    /// * `user_id` - Receiver user_id extracted from the URL hash
+    ///
+    /// # Returns
+    /// * `Result<&SharedSecretReceiver, SqliteError>` - Matching receiver or error
+    pub fn find_receiver<'a>(
+        payload: &'a SharedSecretPayload,
+        user_id: &[u8; USER_ID_LENGTH],
+    ) -> Result<&'a SharedSecretReceiver, SqliteError> {
+        receiver::find_receiver(payload, user_id)
+    }
+
     /// Validate OTP against stored OTP in payload
     ///
     /// # Arguments
@@ -117,6 +134,75 @@ impl SharedSecretOps {
         receiver::validate_otp(stored_otp, provided_otp)
     }
 
+    // ============================================================================
+    // ATTACHMENT OPERATIONS (delegated to attachment module)
+    // ============================================================================
+
+    /// Fetch one encrypted attachment chunk and account for completed downloads
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    /// * `db_index` - Requester database index (32 bytes)
+    /// * `chunk_index` - Zero-based chunk index (must be below chunk_count)
+    /// * `chunk_count` - Chunk count from the decrypted payload
+    /// * `role` - Requester role (only receiver downloads are counted)
+    ///
+    /// # Returns
+    /// * `Result<(Vec<u8>, i64), SqliteError>` - (encrypted_chunk, pending_downloads) or error
+    pub fn download_attachment_chunk(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+        db_index: &[u8; DB_INDEX_LENGTH],
+        chunk_index: u32,
+        chunk_count: u32,
+        role: SecretRole,
+    ) -> Result<(Vec<u8>, i64), SqliteError> {
+        attachment::download_attachment_chunk(
+            reference_hash,
+            db_index,
+            chunk_index,
+            chunk_count,
+            role,
+        )
+    }
+
+    // ============================================================================
+    // REQUEST OPERATIONS (delegated to request module)
+    // ============================================================================
+
+    /// Validate and store a pending secret request
+    ///
+    /// The invitee later fulfils it through create_secret_pair_with_ecdh
+    /// (invitee as sender, requester as the only receiver).
+    ///
+    /// # Arguments
+    /// * `request` - Request details (limits of the resulting secret included)
+    /// * `db_index` - Invitee database index (32 bytes)
+    /// * `request_expires_hours` - How long the invitee has to fulfil the request (1-72)
+    ///
+    /// # Returns
+    /// * `Result<i64, SqliteError>` - Request expiration timestamp in seconds
+    pub fn create_request(
+        request: &SecretRequest,
+        db_index: &[u8; DB_INDEX_LENGTH],
+        request_expires_hours: i64,
+    ) -> Result<i64, SqliteError> {
+        request::create_request(request, db_index, request_expires_hours)
+    }
+
+    /// Read and decrypt a pending secret request
+    ///
+    /// # Arguments
+    /// * `db_index` - Invitee database index (32 bytes)
+    ///
+    /// # Returns
+    /// * `Result<Option<(SecretRequest, i64)>, SqliteError>` - (request, expires_at) or None if
+    ///   not found, already fulfilled or expired
+    pub fn read_request(
+        db_index: &[u8; DB_INDEX_LENGTH],
+    ) -> Result<Option<(SecretRequest, i64)>, SqliteError> {
+        request::read_request(db_index)
+    }
+
     // ============================================================================
     // TRACKING OPERATIONS (delegated to tracking module)
     // ============================================================================
@@ -132,9 +218,49 @@ impl SharedSecretOps {
         tracking::confirm_read(reference_hash)
     }
 
-    /// Clean up expired secrets and tracking
-    #[allow(dead_code)]
-    pub fn cleanup_expired() -> Result<(u32, u32), SqliteError> {
+    /// Activation time of a secret that `role` cannot read yet (receivers before not_before)
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    /// * `role` - Role of the URL being used
+    ///
+    /// # Returns
+    /// * `Result<Option<i64>, SqliteError>` - not_before if still pending for this role, None if readable
+    pub fn pending_activation(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+        role: SecretRole,
+    ) -> Result<Option<i64>, SqliteError> {
+        tracking::pending_activation(reference_hash, role)
+    }
+
+    /// Consume one read for a receiver (own counter + tracking total)
+    ///
+    /// # Arguments
+    /// * `db_index` - Receiver database index (32 bytes)
+    /// * `reference_hash` - Reference hash (16 bytes)
+    ///
+    /// # Returns
+    /// * `Result<i64, SqliteError>` - Receiver's new pending_reads
+    pub fn consume_read(
+        db_index: &[u8; DB_INDEX_LENGTH],
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    ) -> Result<i64, SqliteError> {
+        tracking::consume_read(db_index, reference_hash)
+    }
+
+    /// Delete a receiver's shared_secrets entry once its reads and downloads are used up
+    ///
+    /// # Arguments
+    /// * `db_index` - Receiver database index (32 bytes)
+    ///
+    /// # Returns
+    /// * `Result<bool, SqliteError>` - true if the entry was deleted
+    pub fn release_if_consumed(db_index: &[u8; DB_INDEX_LENGTH]) -> Result<bool, SqliteError> {
+        tracking::release_if_consumed(db_index)
+    }
+
+    /// Clean up expired secrets and tracking (deleted rows per table)
+    pub fn cleanup_expired() -> Result<CleanupCounts, SqliteError> {
         tracking::cleanup_expired()
     }
 }
@@ -2,7 +2,9 @@
 //!
 //! Handles binary payload format parsing.
 
-use super::super::shared_secret_types::{SharedSecretPayload, constants::*};
+use super::super::shared_secret_types::{
+    SharedSecretAttachment, SharedSecretPayload, SharedSecretReceiver, constants::*,
+};
 use spin_sdk::sqlite::Error as SqliteError;
 
 /// Deserialize payload bytes into SharedSecretPayload
@@ -145,15 +147,267 @@ pub fn deserialize_payload(payload: &[u8]) -> Result<SharedSecretPayload, Sqlite
         payload[offset + 6],
         payload[offset + 7],
     ]);
+    offset += 8;
+
+    // Optional attachment section (absent in payloads created before attachments)
+    let attachment = if offset < payload.len() {
+        let (attachment, consumed) = deserialize_attachment(&payload[offset..])?;
+        offset += consumed;
+        attachment
+    } else {
+        None
+    };
+
+    // First receiver lives in the legacy receiver_email/otp fields
+    let mut receivers = vec![SharedSecretReceiver {
+        email: receiver_email,
+        otp,
+    }];
+
+    // Optional additional receivers section (absent in single-receiver payloads)
+    if offset < payload.len() {
+        receivers.extend(deserialize_additional_receivers(&payload[offset..])?);
+    }
 
     Ok(SharedSecretPayload {
         sender_email,
-        receiver_email,
+        receivers,
         encrypted_secret,
         key_material,
-        otp,
         created_at,
         reference_hash,
         max_reads,
+        attachment,
     })
 }
+
+/// Append the optional attachment section to a serialized payload
+///
+/// Format: has_attachment[1] + filename_len[2] + filename + mime_type_len[1] + mime_type +
+///         size[8] + chunk_count[4]
+pub fn serialize_attachment(attachment: Option<&SharedSecretAttachment>, payload: &mut Vec<u8>) {
+    let Some(attachment) = attachment else {
+        payload.push(0); // No attachment
+        return;
+    };
+
+    payload.push(1);
+    payload.extend_from_slice(&(attachment.filename.len() as u16).to_be_bytes());
+    payload.extend_from_slice(attachment.filename.as_bytes());
+    payload.push(attachment.mime_type.len() as u8);
+    payload.extend_from_slice(attachment.mime_type.as_bytes());
+    payload.extend_from_slice(&attachment.size.to_be_bytes());
+    payload.extend_from_slice(&attachment.chunk_count.to_be_bytes());
+}
+
+/// Parse the attachment section written by `serialize_attachment`
+///
+/// # Returns
+/// * `Result<(Option<SharedSecretAttachment>, usize), SqliteError>` - (attachment, bytes consumed)
+fn deserialize_attachment(
+    section: &[u8],
+) -> Result<(Option<SharedSecretAttachment>, usize), SqliteError> {
+    let too_short = |field: &str| SqliteError::Io(format!("Payload too short for {}", field));
+
+    match section.first() {
+        Some(0) => return Ok((None, 1)),
+        Some(1) => {}
+        _ => {
+            return Err(SqliteError::Io(
+                "Invalid attachment flag in payload".to_string(),
+            ));
+        }
+    }
+    let mut offset = 1;
+
+    let filename_len = section
+        .get(offset..offset + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+        .ok_or_else(|| too_short("attachment filename_len"))?;
+    offset += 2;
+    let filename = section
+        .get(offset..offset + filename_len)
+        .ok_or_else(|| too_short("attachment filename"))?;
+    let filename = String::from_utf8(filename.to_vec())
+        .map_err(|_| SqliteError::Io("Invalid UTF-8 in attachment filename".to_string()))?;
+    offset += filename_len;
+
+    let mime_type_len = *section
+        .get(offset)
+        .ok_or_else(|| too_short("attachment mime_type_len"))? as usize;
+    offset += 1;
+    let mime_type = section
+        .get(offset..offset + mime_type_len)
+        .ok_or_else(|| too_short("attachment mime_type"))?;
+    let mime_type = String::from_utf8(mime_type.to_vec())
+        .map_err(|_| SqliteError::Io("Invalid UTF-8 in attachment mime_type".to_string()))?;
+    offset += mime_type_len;
+
+    let size = section
+        .get(offset..offset + 8)
+        .and_then(|b| <[u8; 8]>::try_from(b).ok())
+        .map(u64::from_be_bytes)
+        .ok_or_else(|| too_short("attachment size"))?;
+    offset += 8;
+
+    let chunk_count = section
+        .get(offset..offset + 4)
+        .and_then(|b| <[u8; 4]>::try_from(b).ok())
+        .map(u32::from_be_bytes)
+        .ok_or_else(|| too_short("attachment chunk_count"))?;
+    offset += 4;
+
+    Ok((
+        Some(SharedSecretAttachment {
+            filename,
+            mime_type,
+            size,
+            chunk_count,
+        }),
+        offset,
+    ))
+}
+
+/// Append receivers after the first one (which uses the legacy receiver_email/otp fields)
+///
+/// Format: count[1] + (email_len[2] + email + otp_len[1] + otp) per receiver.
+/// Nothing is written for single-receiver secrets, keeping their payload layout unchanged.
+pub fn serialize_additional_receivers(receivers: &[SharedSecretReceiver], payload: &mut Vec<u8>) {
+    if receivers.is_empty() {
+        return;
+    }
+
+    payload.push(receivers.len() as u8);
+    for receiver in receivers {
+        payload.extend_from_slice(&(receiver.email.len() as u16).to_be_bytes());
+        payload.extend_from_slice(receiver.email.as_bytes());
+        match &receiver.otp {
+            Some(otp) => {
+                payload.push(otp.len() as u8);
+                payload.extend_from_slice(otp.as_bytes());
+            }
+            None => payload.push(0),
+        }
+    }
+}
+
+/// Parse the section written by `serialize_additional_receivers`
+fn deserialize_additional_receivers(
+    section: &[u8],
+) -> Result<Vec<SharedSecretReceiver>, SqliteError> {
+    let too_short = |field: &str| SqliteError::Io(format!("Payload too short for {}", field));
+
+    let count = *section.first().ok_or_else(|| too_short("receiver count"))? as usize;
+    let mut offset = 1;
+    let mut receivers = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        let email_len = section
+            .get(offset..offset + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+            .ok_or_else(|| too_short("receiver email_len"))?;
+        offset += 2;
+        let email = section
+            .get(offset..offset + email_len)
+            .ok_or_else(|| too_short("receiver email"))?;
+        let email = String::from_utf8(email.to_vec())
+            .map_err(|_| SqliteError::Io("Invalid UTF-8 in receiver email".to_string()))?;
+        offset += email_len;
+
+        let otp_len = *section
+            .get(offset)
+            .ok_or_else(|| too_short("receiver otp_len"))? as usize;
+        offset += 1;
+        let otp = if otp_len > 0 {
+            let otp = section
+                .get(offset..offset + otp_len)
+                .ok_or_else(|| too_short("receiver otp"))?;
+            offset += otp_len;
+            Some(
+                String::from_utf8(otp.to_vec())
+                    .map_err(|_| SqliteError::Io("Invalid UTF-8 in receiver OTP".to_string()))?,
+            )
+        } else {
+            None
+        };
+
+        receivers.push(SharedSecretReceiver { email, otp });
+    }
+
+    Ok(receivers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal pre-attachment payload: empty emails/secret, no OTP
+    fn legacy_payload() -> Vec<u8> {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&0u16.to_be_bytes());
+        payload.extend_from_slice(&0u16.to_be_bytes());
+        payload.extend_from_slice(&0u32.to_be_bytes());
+        payload.extend_from_slice(&[7u8; KEY_MATERIAL_LENGTH]);
+        payload.push(0);
+        payload.extend_from_slice(&1_700_000_000i64.to_be_bytes());
+        payload.extend_from_slice(&[9u8; REFERENCE_HASH_LENGTH]);
+        payload.extend_from_slice(&3i64.to_be_bytes());
+        payload
+    }
+
+    #[test]
+    fn test_legacy_payload_has_no_attachment() {
+        let parsed = deserialize_payload(&legacy_payload()).unwrap();
+        assert_eq!(parsed.max_reads, 3);
+        assert_eq!(parsed.attachment, None);
+
+        let mut without = legacy_payload();
+        serialize_attachment(None, &mut without);
+        assert_eq!(deserialize_payload(&without).unwrap().attachment, None);
+    }
+
+    #[test]
+    fn test_attachment_roundtrip() {
+        let attachment = SharedSecretAttachment {
+            filename: "kubeconfig.yaml".to_string(),
+            mime_type: "application/yaml".to_string(),
+            size: 70_000,
+            chunk_count: 2,
+        };
+        let mut payload = legacy_payload();
+        serialize_attachment(Some(&attachment), &mut payload);
+        assert_eq!(
+            deserialize_payload(&payload).unwrap().attachment,
+            Some(attachment)
+        );
+
+        payload.truncate(payload.len() - 1);
+        assert!(deserialize_payload(&payload).is_err());
+    }
+
+    #[test]
+    fn test_additional_receivers_roundtrip() {
+        let parsed = deserialize_payload(&legacy_payload()).unwrap();
+        assert_eq!(parsed.receivers.len(), 1);
+
+        let additional = vec![
+            SharedSecretReceiver {
+                email: "ops@example.com".to_string(),
+                otp: Some("123456789".to_string()),
+            },
+            SharedSecretReceiver {
+                email: "dev@example.com".to_string(),
+                otp: None,
+            },
+        ];
+        let mut payload = legacy_payload();
+        serialize_attachment(None, &mut payload);
+        serialize_additional_receivers(&additional, &mut payload);
+        let parsed = deserialize_payload(&payload).unwrap();
+        assert_eq!(parsed.receivers.len(), 3);
+        assert_eq!(parsed.receivers[1..], additional[..]);
+
+        payload.truncate(payload.len() - 3);
+        assert!(deserialize_payload(&payload).is_err());
+    }
+}
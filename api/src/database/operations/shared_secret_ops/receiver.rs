@@ -4,7 +4,9 @@
 
 use super::super::shared_secret_crypto::SharedSecretCrypto;
 use super::super::shared_secret_storage::SharedSecretStorage;
-use super::super::shared_secret_types::{SecretRole, SharedSecretPayload, constants::*};
+use super::super::shared_secret_types::{
+    SecretRole, SharedSecretPayload, SharedSecretReceiver, constants::*,
+};
 use super::payload::deserialize_payload;
 use chrono::Utc;
 use spin_sdk::sqlite::Error as SqliteError;
@@ -43,8 +45,7 @@ pub fn read_secret(
     // ============================================================================
     // 2. VALIDATE EXPIRATION (before decryption - optimization)
     // ============================================================================
-    let now_hours = Utc::now().timestamp() / 3600;
-    if expires_at < now_hours {
+    if expires_at < Utc::now().timestamp() {
         SharedSecretStorage::delete_secret(db_index)?;
         return Err(SqliteError::Io("Secret has expired".to_string()));
     }
@@ -97,11 +98,17 @@ pub fn read_secret(
     }
 
     // ============================================================================
-    // 8. GET PENDING_READS from tracking
+    // 8. GET PENDING_READS (receiver: own counter; sender/legacy: tracking total)
     // ============================================================================
-    let pending_reads =
-        SharedSecretStorage::get_pending_reads_from_tracking(&reference_hash_from_payload)?
-            .unwrap_or(0);
+    let receiver_status = match role {
+        SecretRole::Receiver => SharedSecretStorage::get_receiver_status(db_index)?,
+        SecretRole::Sender => None,
+    };
+    let pending_reads = match receiver_status {
+        Some(status) => status.pending_reads,
+        None => SharedSecretStorage::get_pending_reads_from_tracking(&reference_hash_from_payload)?
+            .unwrap_or(0),
+    };
 
     debug!(
         "✅ SharedSecret: Read secret successfully (role={}, pending_reads={})",
@@ -125,6 +132,28 @@ pub fn read_secret_old(
 }
 */
 
+/// Find the payload receiver a URL hash belongs to
+///
+/// # Arguments
+/// * `payload` - Decrypted payload
+/// * `user_id` - Receiver user_id extracted from the URL hash
+///
+/// # Returns
+/// * `Result<&SharedSecretReceiver, SqliteError>` - Matching receiver or error
+pub fn find_receiver<'a>(
+    payload: &'a SharedSecretPayload,
+    user_id: &[u8; USER_ID_LENGTH],
+) -> Result<&'a SharedSecretReceiver, SqliteError> {
+    for receiver in &payload.receivers {
+        if &SharedSecretCrypto::calculate_user_id(&receiver.email)? == user_id {
+            return Ok(receiver);
+        }
+    }
+    Err(SqliteError::Io(
+        "Receiver not found in shared secret".to_string(),
+    ))
+}
+
 /// Validate OTP against stored OTP in payload
 ///
 /// # Arguments
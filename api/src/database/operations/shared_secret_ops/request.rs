@@ -0,0 +1,248 @@
+//! Secret request operations ("request a secret" reverse flow)
+//!
+//! A request is stored like a secret's key layers: random key_material[44]
+//! encrypts the serialized request, and key_material itself is encrypted with
+//! the key derived from the invitee's db_index. Fulfilling a request goes
+//! through create_secret_pair like any other secret.
+
+use super::super::shared_secret_crypto::SharedSecretCrypto;
+use super::super::shared_secret_storage::SharedSecretStorage;
+use super::super::shared_secret_types::{SecretRequest, constants::*};
+use chrono::Utc;
+use spin_sdk::sqlite::Error as SqliteError;
+use tracing::debug;
+
+/// Validate and store a pending secret request
+///
+/// # Arguments
+/// * `request` - Request details (limits of the resulting secret included)
+/// * `db_index` - Invitee database index (32 bytes)
+/// * `request_expires_hours` - How long the invitee has to fulfil the request (1-72)
+///
+/// # Returns
+/// * `Result<i64, SqliteError>` - Request expiration timestamp in seconds
+pub fn create_request(
+    request: &SecretRequest,
+    db_index: &[u8; DB_INDEX_LENGTH],
+    request_expires_hours: i64,
+) -> Result<i64, SqliteError> {
+    validate_request(request)?;
+
+    if !(MIN_REQUEST_EXPIRES_HOURS..=MAX_REQUEST_EXPIRES_HOURS).contains(&request_expires_hours) {
+        return Err(SqliteError::Io(format!(
+            "Request expiration must be between {} and {} hours",
+            MIN_REQUEST_EXPIRES_HOURS, MAX_REQUEST_EXPIRES_HOURS
+        )));
+    }
+
+    let key_material = SharedSecretCrypto::generate_random_key_material();
+    let encrypted_payload = SharedSecretCrypto::encrypt_payload_with_material(
+        &key_material,
+        &serialize_request(request),
+    )?;
+    let encrypted_key_material = SharedSecretCrypto::encrypt_key_material(db_index, &key_material)?;
+
+    let expires_at = Utc::now().timestamp() + request_expires_hours * 3600;
+    SharedSecretStorage::store_request(
+        db_index,
+        &encrypted_key_material,
+        &encrypted_payload,
+        expires_at,
+    )?;
+
+    debug!("📨 SharedSecret: Request created");
+    Ok(expires_at)
+}
+
+/// Read and decrypt a pending secret request
+///
+/// # Arguments
+/// * `db_index` - Invitee database index (32 bytes)
+///
+/// # Returns
+/// * `Result<Option<(SecretRequest, i64)>, SqliteError>` - (request, expires_at) or None if
+///   not found, already fulfilled or expired
+pub fn read_request(
+    db_index: &[u8; DB_INDEX_LENGTH],
+) -> Result<Option<(SecretRequest, i64)>, SqliteError> {
+    let Some((encrypted_key_material, encrypted_payload, expires_at)) =
+        SharedSecretStorage::retrieve_request(db_index)?
+    else {
+        return Ok(None);
+    };
+
+    if expires_at < Utc::now().timestamp() {
+        SharedSecretStorage::delete_request(db_index)?;
+        return Ok(None);
+    }
+
+    let key_material = SharedSecretCrypto::decrypt_key_material(db_index, &encrypted_key_material)?;
+    let payload =
+        SharedSecretCrypto::decrypt_payload_with_material(&key_material, &encrypted_payload)?;
+
+    Ok(Some((deserialize_request(&payload)?, expires_at)))
+}
+
+/// Validate request limits (the lifetime is checked against the configured bounds by the handler)
+fn validate_request(request: &SecretRequest) -> Result<(), SqliteError> {
+    if request.requester_email == request.invitee_email {
+        return Err(SqliteError::Io(
+            "You cannot request a secret from yourself".to_string(),
+        ));
+    }
+
+    if request.expires_seconds <= 0 {
+        return Err(SqliteError::Io(
+            "Expiration must be a positive number of seconds".to_string(),
+        ));
+    }
+
+    if !(MIN_READS..=MAX_READS).contains(&request.max_reads) {
+        return Err(SqliteError::Io(format!(
+            "Max reads must be between {} and {}",
+            MIN_READS, MAX_READS
+        )));
+    }
+
+    if request.message.chars().count() > MAX_REQUEST_MESSAGE_LENGTH {
+        return Err(SqliteError::Io(format!(
+            "Message must be at most {} characters",
+            MAX_REQUEST_MESSAGE_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+/// Serialize a request
+///
+/// Format: requester_len[2] + requester + invitee_len[2] + invitee + message_len[2] +
+///         message + expires_seconds[8] + max_reads[8] + require_otp[1] +
+///         language_len[1] + language (0 = default language)
+pub fn serialize_request(request: &SecretRequest) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for text in [
+        &request.requester_email,
+        &request.invitee_email,
+        &request.message,
+    ] {
+        payload.extend_from_slice(&(text.len() as u16).to_be_bytes());
+        payload.extend_from_slice(text.as_bytes());
+    }
+    payload.extend_from_slice(&request.expires_seconds.to_be_bytes());
+    payload.extend_from_slice(&request.max_reads.to_be_bytes());
+    payload.push(request.require_otp as u8);
+
+    let language = request.language.as_deref().unwrap_or("");
+    payload.push(language.len() as u8);
+    payload.extend_from_slice(language.as_bytes());
+    payload
+}
+
+/// Deserialize a request (see serialize_request for the format)
+pub fn deserialize_request(payload: &[u8]) -> Result<SecretRequest, SqliteError> {
+    let mut offset = 0;
+    let mut take = |len: usize, field: &str| {
+        let bytes = payload
+            .get(offset..offset + len)
+            .ok_or_else(|| SqliteError::Io(format!("Request payload too short for {}", field)))?;
+        offset += len;
+        Ok::<&[u8], SqliteError>(bytes)
+    };
+
+    let mut texts = Vec::with_capacity(3);
+    for field in ["requester_email", "invitee_email", "message"] {
+        let len_bytes = take(2, field)?;
+        let len = u16::from_be_bytes([len_bytes[0], len_bytes[1]]) as usize;
+        let text = String::from_utf8(take(len, field)?.to_vec())
+            .map_err(|_| SqliteError::Io(format!("Invalid UTF-8 in {}", field)))?;
+        texts.push(text);
+    }
+
+    let read_i64 = |bytes: &[u8]| i64::from_be_bytes(bytes.try_into().expect("8 bytes"));
+    let expires_seconds = read_i64(take(8, "expires_seconds")?);
+    let max_reads = read_i64(take(8, "max_reads")?);
+    let require_otp = take(1, "require_otp")?[0] != 0;
+
+    let language_len = take(1, "language_len")?[0] as usize;
+    let language = String::from_utf8(take(language_len, "language")?.to_vec())
+        .map_err(|_| SqliteError::Io("Invalid UTF-8 in language".to_string()))?;
+
+    let message = texts.pop().unwrap_or_default();
+    let invitee_email = texts.pop().unwrap_or_default();
+    let requester_email = texts.pop().unwrap_or_default();
+
+    Ok(SecretRequest {
+        requester_email,
+        invitee_email,
+        message,
+        expires_seconds,
+        max_reads,
+        require_otp,
+        language: (!language.is_empty()).then_some(language),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> SecretRequest {
+        SecretRequest {
+            requester_email: "ops@example.com".to_string(),
+            invitee_email: "vendor@example.org".to_string(),
+            message: "Staging database password, please".to_string(),
+            expires_seconds: 48 * 3600,
+            max_reads: 2,
+            require_otp: true,
+            language: Some("es".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_request_roundtrip() {
+        let original = request();
+        let serialized = serialize_request(&original);
+        assert_eq!(deserialize_request(&serialized).unwrap(), original);
+
+        let plain = SecretRequest {
+            message: String::new(),
+            require_otp: false,
+            language: None,
+            ..original
+        };
+        assert_eq!(
+            deserialize_request(&serialize_request(&plain)).unwrap(),
+            plain
+        );
+
+        assert!(deserialize_request(&serialized[..serialized.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_validate_request_limits() {
+        assert!(validate_request(&request()).is_ok());
+
+        let invalid = [
+            SecretRequest {
+                invitee_email: "ops@example.com".to_string(),
+                ..request()
+            },
+            SecretRequest {
+                expires_seconds: 0,
+                ..request()
+            },
+            SecretRequest {
+                max_reads: 0,
+                ..request()
+            },
+            SecretRequest {
+                message: "x".repeat(MAX_REQUEST_MESSAGE_LENGTH + 1),
+                ..request()
+            },
+        ];
+        for request in invalid {
+            assert!(validate_request(&request).is_err());
+        }
+    }
+}
@@ -4,24 +4,26 @@
 
 use super::super::shared_secret_crypto::SharedSecretCrypto;
 use super::super::shared_secret_storage::SharedSecretStorage;
-use super::super::shared_secret_types::{SecretRole, constants::*};
+use super::super::shared_secret_types::{
+    EncryptedAttachment, SecretLifetime, SecretRole, SharedSecretReceiver, constants::*,
+};
+use super::payload::{serialize_additional_receivers, serialize_attachment};
 use crate::utils::crypto::{decrypt_with_ecdh, get_backend_x25519_private_key};
 use chrono::Utc;
 use spin_sdk::sqlite::Error as SqliteError;
 use tracing::debug;
 
-/// Create a pair of shared secret entries with E2E encrypted data (sender + receiver)
+/// Create shared secret entries with E2E encrypted data (sender + one entry per receiver)
 ///
 /// # Arguments
 /// * `sender_email` - Sender email address
-/// * `receiver_email` - Receiver email address
+/// * `receivers` - Receivers (email + optional OTP) with pre-computed database indexes (32 bytes)
 /// * `encrypted_secret` - ChaCha20-Poly1305 encrypted secret from frontend
+/// * `attachment` - Optional encrypted file attachment from frontend
 /// * `key_material` - Decrypted key material (nonce[12] + cipher_key[32])
-/// * `otp` - Optional 9-digit OTP
-/// * `expires_hours` - Expiration in hours (1-72)
-/// * `max_reads` - Maximum reads for receiver (1-10)
+/// * `lifetime` - Activation and expiration timestamps (validated against the configured bounds)
+/// * `max_reads` - Maximum reads per receiver (1-10)
 /// * `sender_db_index` - Pre-computed sender database index (32 bytes)
-/// * `receiver_db_index` - Pre-computed receiver database index (32 bytes)
 /// * `reference_hash` - Pre-generated reference hash (16 bytes)
 ///
 /// # Returns
@@ -29,14 +31,13 @@ use tracing::debug;
 #[allow(clippy::too_many_arguments)]
 pub fn create_secret_pair(
     sender_email: &str,
-    receiver_email: &str,
+    receivers: &[(SharedSecretReceiver, [u8; DB_INDEX_LENGTH])],
     encrypted_secret: &[u8],
+    attachment: Option<&EncryptedAttachment>,
     key_material: &[u8; KEY_MATERIAL_LENGTH],
-    otp: Option<String>,
-    expires_hours: i64,
+    lifetime: &SecretLifetime,
     max_reads: i64,
     sender_db_index: &[u8; 32],                   // DB_INDEX_LENGTH
-    receiver_db_index: &[u8; 32],                 // DB_INDEX_LENGTH
     reference_hash: &[u8; REFERENCE_HASH_LENGTH], // Pre-generated reference hash
 ) -> Result<[u8; REFERENCE_HASH_LENGTH], SqliteError> {
     // Validate inputs
@@ -46,11 +47,14 @@ pub fn create_secret_pair(
         ));
     }
 
-    if !(MIN_EXPIRES_HOURS..=MAX_EXPIRES_HOURS).contains(&expires_hours) {
-        return Err(SqliteError::Io(format!(
-            "Expiration must be between {} and {} hours",
-            MIN_EXPIRES_HOURS, MAX_EXPIRES_HOURS
-        )));
+    if lifetime.expires_at <= Utc::now().timestamp()
+        || lifetime
+            .not_before
+            .is_some_and(|not_before| not_before >= lifetime.expires_at)
+    {
+        return Err(SqliteError::Io(
+            "Secret must expire in the future and after its activation time".to_string(),
+        ));
     }
 
     if !(MIN_READS..=MAX_READS).contains(&max_reads) {
@@ -60,28 +64,56 @@ pub fn create_secret_pair(
         )));
     }
 
-    if let Some(ref otp_val) = otp
-        && (otp_val.len() != OTP_LENGTH || !otp_val.chars().all(|c| c.is_ascii_digit()))
-    {
+    if receivers.is_empty() || receivers.len() > MAX_RECEIVERS {
         return Err(SqliteError::Io(format!(
-            "OTP must be exactly {} digits",
-            OTP_LENGTH
+            "Receivers must be between 1 and {}",
+            MAX_RECEIVERS
         )));
     }
 
+    for (index, (receiver, db_index)) in receivers.iter().enumerate() {
+        if let Some(ref otp_val) = receiver.otp
+            && (otp_val.len() != OTP_LENGTH || !otp_val.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(SqliteError::Io(format!(
+                "OTP must be exactly {} digits",
+                OTP_LENGTH
+            )));
+        }
+
+        // Same email twice would map to the same db_index (PRIMARY KEY)
+        if receivers[..index]
+            .iter()
+            .any(|(_, other)| other == db_index)
+        {
+            return Err(SqliteError::Io(format!(
+                "Duplicate receiver: {}",
+                receiver.email
+            )));
+        }
+    }
+
+    if let Some(attachment) = attachment {
+        validate_attachment(attachment)?;
+    }
+
     // ============================================================================
     // v4: E2E ENCRYPTION - Store encrypted_secret + key_material in payload
     // ============================================================================
 
-    // 1. Create payload (same for both sender and receiver)
+    // 1. Create payload (same for sender and all receivers)
     let created_at = Utc::now().timestamp();
     let mut payload = Vec::new();
 
     // Serialize: sender_email_len[2] + sender_email + receiver_email_len[2] + receiver_email +
     //            encrypted_secret_len[4] + encrypted_secret + key_material[44] +
-    //            otp_len[1] + otp + created_at[8] + reference_hash[16] + max_reads[8]
+    //            otp_len[1] + otp + created_at[8] + reference_hash[16] + max_reads[8] +
+    //            attachment section (see payload::serialize_attachment) +
+    //            additional receivers (see payload::serialize_additional_receivers)
+    // The first receiver uses the original receiver_email/otp fields
+    let (first_receiver, _) = &receivers[0];
     let sender_email_bytes = sender_email.as_bytes();
-    let receiver_email_bytes = receiver_email.as_bytes();
+    let receiver_email_bytes = first_receiver.email.as_bytes();
 
     payload.extend_from_slice(&(sender_email_bytes.len() as u16).to_be_bytes());
     payload.extend_from_slice(sender_email_bytes);
@@ -91,7 +123,7 @@ pub fn create_secret_pair(
     payload.extend_from_slice(encrypted_secret);
     payload.extend_from_slice(key_material); // Fixed 44 bytes
 
-    if let Some(otp_val) = &otp {
+    if let Some(otp_val) = &first_receiver.otp {
         payload.push(OTP_LENGTH as u8);
         payload.extend_from_slice(otp_val.as_bytes());
     } else {
@@ -101,6 +133,12 @@ pub fn create_secret_pair(
     payload.extend_from_slice(&created_at.to_be_bytes());
     payload.extend_from_slice(reference_hash); // Already a reference
     payload.extend_from_slice(&max_reads.to_be_bytes());
+    serialize_attachment(attachment.map(|a| a.metadata()).as_ref(), &mut payload);
+    let additional_receivers: Vec<SharedSecretReceiver> = receivers[1..]
+        .iter()
+        .map(|(receiver, _)| receiver.clone())
+        .collect();
+    serialize_additional_receivers(&additional_receivers, &mut payload);
 
     // ============================================================================
     // 2. LAYER 2: Encrypt payload ONCE for tracking (ChaCha20-Poly1305 with key_material)
@@ -109,33 +147,34 @@ pub fn create_secret_pair(
         SharedSecretCrypto::encrypt_payload_with_material(key_material, &payload)?;
 
     // ============================================================================
-    // 3. LAYER 1: Encrypt key_material TWICE (ChaCha20 for sender + receiver)
+    // 3. LAYER 1: Encrypt key_material for sender (receivers below, one per db_index)
     // ============================================================================
     let encrypted_key_material_sender =
         SharedSecretCrypto::encrypt_key_material(sender_db_index, key_material)?;
 
-    let encrypted_key_material_receiver =
-        SharedSecretCrypto::encrypt_key_material(receiver_db_index, key_material)?;
+    let expires_at = lifetime.expires_at;
 
     // ============================================================================
-    // 4. Calculate expiration timestamp
-    // ============================================================================
-    let expires_at = (Utc::now().timestamp() / 3600) + expires_hours;
-
-    // ============================================================================
-    // 5. CRITICAL ORDER: Store tracking FIRST, then shared_secrets
+    // 4. CRITICAL ORDER: Store tracking FIRST, then shared_secrets
     // ============================================================================
     // Reason: If tracking fails, we don't create orphaned key_material entries
     // If shared_secrets fail, we have orphaned payload (cleaned up later)
 
     // FIRST: Store tracking with encrypted payload
+    // Tracking pending_reads is the total across receivers (each has its own counter too)
     SharedSecretStorage::store_tracking_with_payload(
         reference_hash,
-        max_reads,
+        max_reads * receivers.len() as i64,
         expires_at,
+        lifetime.not_before,
         &encrypted_payload_tracking,
     )?;
 
+    // Attachment chunks share the tracking lifetime (downloads counted per receiver)
+    if let Some(attachment) = attachment {
+        SharedSecretStorage::store_attachment(reference_hash, &attachment.chunks, expires_at)?;
+    }
+
     // SECOND: Store sender entry (encrypted key_material, unlimited reads)
     SharedSecretStorage::store_shared_secret(
         sender_db_index,
@@ -144,22 +183,104 @@ pub fn create_secret_pair(
         SecretRole::Sender,
     )?;
 
-    // THIRD: Store receiver entry (encrypted key_material, limited reads)
-    SharedSecretStorage::store_shared_secret(
-        receiver_db_index,
-        &encrypted_key_material_receiver,
-        expires_at,
-        SecretRole::Receiver,
-    )?;
+    // THIRD: Store receiver entries (encrypted key_material + own read counter)
+    let pending_downloads = attachment.map(|_| max_reads);
+    for (_, receiver_db_index) in receivers {
+        let encrypted_key_material_receiver =
+            SharedSecretCrypto::encrypt_key_material(receiver_db_index, key_material)?;
+
+        SharedSecretStorage::store_receiver(
+            receiver_db_index,
+            reference_hash,
+            max_reads,
+            pending_downloads,
+            expires_at,
+        )?;
+
+        SharedSecretStorage::store_shared_secret(
+            receiver_db_index,
+            &encrypted_key_material_receiver,
+            expires_at,
+            SecretRole::Receiver,
+        )?;
+    }
 
     debug!(
-        "✅ SharedSecret: Created pair (tracking → sender → receiver) with centralized payload (expires in {}h)",
-        expires_hours
+        "✅ SharedSecret: Created entries (tracking → sender → {} receivers) with centralized payload (expires_at={}, not_before={:?})",
+        receivers.len(),
+        expires_at,
+        lifetime.not_before
     );
 
     Ok(*reference_hash) // Dereference to return owned array
 }
 
+/// Validate attachment metadata and chunk layout
+///
+/// Every chunk but the last must hold exactly ATTACHMENT_CHUNK_SIZE plaintext bytes,
+/// and the chunks must add up to the declared size (each carries a 16-byte tag).
+pub fn validate_attachment(attachment: &EncryptedAttachment) -> Result<(), SqliteError> {
+    let filename = &attachment.filename;
+    if filename.is_empty()
+        || filename.len() > MAX_ATTACHMENT_FILENAME_LENGTH
+        || filename == "."
+        || filename == ".."
+        || filename
+            .chars()
+            .any(|c| c == '/' || c == '\\' || c.is_control())
+    {
+        return Err(SqliteError::Io(format!(
+            "Attachment filename must be 1-{} bytes without path separators or control characters",
+            MAX_ATTACHMENT_FILENAME_LENGTH
+        )));
+    }
+
+    let mime_type = &attachment.mime_type;
+    let valid_mime = mime_type.len() <= MAX_ATTACHMENT_MIME_TYPE_LENGTH
+        && mime_type
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| !kind.is_empty() && !subtype.is_empty())
+        && mime_type.chars().all(|c| c.is_ascii_graphic());
+    if !valid_mime {
+        return Err(SqliteError::Io(format!(
+            "Attachment MIME type must be type/subtype, at most {} characters",
+            MAX_ATTACHMENT_MIME_TYPE_LENGTH
+        )));
+    }
+
+    if !(1..=MAX_ATTACHMENT_SIZE).contains(&attachment.size) {
+        return Err(SqliteError::Io(format!(
+            "Attachment size must be between 1 and {} bytes",
+            MAX_ATTACHMENT_SIZE
+        )));
+    }
+
+    let expected_chunks = attachment.size.div_ceil(ATTACHMENT_CHUNK_SIZE);
+    if attachment.chunks.len() as u64 != expected_chunks {
+        return Err(SqliteError::Io(format!(
+            "Attachment of {} bytes must have {} chunks, got {}",
+            attachment.size,
+            expected_chunks,
+            attachment.chunks.len()
+        )));
+    }
+
+    for (index, chunk) in attachment.chunks.iter().enumerate() {
+        let offset = index as u64 * ATTACHMENT_CHUNK_SIZE;
+        let plaintext_len = ATTACHMENT_CHUNK_SIZE.min(attachment.size - offset);
+        if chunk.len() as u64 != plaintext_len + POLY1305_TAG_LENGTH {
+            return Err(SqliteError::Io(format!(
+                "Attachment chunk {} must be {} bytes, got {}",
+                index,
+                plaintext_len + POLY1305_TAG_LENGTH,
+                chunk.len()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 /// Create a pair of shared secret entries with E2E encryption (high-level ECDH wrapper)
 ///
 /// This function handles the E2E encryption workflow:
@@ -169,16 +290,15 @@ pub fn create_secret_pair(
 ///
 /// # Arguments
 /// * `sender_email` - Sender email address
-/// * `receiver_email` - Receiver email address
+/// * `receivers` - Receivers (email + optional OTP) with pre-computed database indexes (32 bytes)
 /// * `encrypted_secret` - ChaCha20-Poly1305 encrypted secret from frontend
+/// * `attachment` - Optional encrypted file attachment from frontend
 /// * `encrypted_key_material` - ECDH encrypted key material from frontend (60 bytes: 44 + 16 MAC)
 /// * `sender_ed25519_public_key_hex` - Sender's Ed25519 public key as hex string (64 chars)
 /// * `sender_x25519_public_key_hex` - Sender's X25519 public key as hex string (64 chars)
-/// * `otp` - Optional 9-digit OTP
-/// * `expires_hours` - Expiration in hours (1-72)
-/// * `max_reads` - Maximum reads for receiver (1-10)
+/// * `lifetime` - Activation and expiration timestamps
+/// * `max_reads` - Maximum reads per receiver (1-10)
 /// * `sender_db_index` - Pre-computed sender database index (32 bytes)
-/// * `receiver_db_index` - Pre-computed receiver database index (32 bytes)
 /// * `reference_hash` - Pre-generated reference hash (16 bytes)
 ///
 /// # Returns
@@ -193,16 +313,15 @@ pub fn create_secret_pair(
 #[allow(clippy::too_many_arguments)]
 pub fn create_secret_pair_with_ecdh(
     sender_email: &str,
-    receiver_email: &str,
+    receivers: &[(SharedSecretReceiver, [u8; DB_INDEX_LENGTH])],
     encrypted_secret: &[u8],
+    attachment: Option<&EncryptedAttachment>,
     encrypted_key_material: &[u8],
     _sender_ed25519_public_key_hex: &str,
     sender_x25519_public_key_hex: &str,
-    otp: Option<String>,
-    expires_hours: i64,
+    lifetime: &SecretLifetime,
     max_reads: i64,
     sender_db_index: &[u8; 32],
-    receiver_db_index: &[u8; 32],
     reference_hash: &[u8; REFERENCE_HASH_LENGTH],
 ) -> Result<[u8; REFERENCE_HASH_LENGTH], SqliteError> {
     debug!("🔐 SharedSecret: Starting E2E encryption workflow");
@@ -273,14 +392,98 @@ pub fn create_secret_pair_with_ecdh(
     // 7. Call create_secret_pair with decrypted data
     create_secret_pair(
         sender_email,
-        receiver_email,
+        receivers,
         encrypted_secret,
+        attachment,
         &key_material,
-        otp,
-        expires_hours,
+        lifetime,
         max_reads,
         sender_db_index,
-        receiver_db_index,
         reference_hash,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(size: u64) -> EncryptedAttachment {
+        let chunks = (0..size.div_ceil(ATTACHMENT_CHUNK_SIZE))
+            .map(|index| {
+                let plaintext_len = ATTACHMENT_CHUNK_SIZE.min(size - index * ATTACHMENT_CHUNK_SIZE);
+                vec![0u8; (plaintext_len + POLY1305_TAG_LENGTH) as usize]
+            })
+            .collect();
+        EncryptedAttachment {
+            filename: "report.pdf".to_string(),
+            mime_type: "application/pdf".to_string(),
+            size,
+            chunks,
+        }
+    }
+
+    #[test]
+    fn test_validate_attachment_layout() {
+        assert!(validate_attachment(&attachment(1)).is_ok());
+        assert!(validate_attachment(&attachment(ATTACHMENT_CHUNK_SIZE * 2 + 5)).is_ok());
+        assert!(validate_attachment(&attachment(MAX_ATTACHMENT_SIZE)).is_ok());
+        assert!(validate_attachment(&attachment(MAX_ATTACHMENT_SIZE + 1)).is_err());
+
+        let mut truncated = attachment(ATTACHMENT_CHUNK_SIZE + 1);
+        truncated.chunks[1].pop();
+        assert!(validate_attachment(&truncated).is_err());
+
+        let mut missing = attachment(ATTACHMENT_CHUNK_SIZE + 1);
+        missing.chunks.pop();
+        assert!(validate_attachment(&missing).is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_receiver_lists() {
+        let receiver = |email: &str| SharedSecretReceiver {
+            email: email.to_string(),
+            otp: None,
+        };
+        let create = |receivers: &[(SharedSecretReceiver, [u8; DB_INDEX_LENGTH])]| {
+            create_secret_pair(
+                "sender@example.com",
+                receivers,
+                &[1u8; 32],
+                None,
+                &[0u8; KEY_MATERIAL_LENGTH],
+                &SecretLifetime {
+                    not_before: None,
+                    expires_at: Utc::now().timestamp() + DEFAULT_EXPIRES_SECONDS,
+                },
+                DEFAULT_READS,
+                &[0u8; DB_INDEX_LENGTH],
+                &[0u8; REFERENCE_HASH_LENGTH],
+            )
+        };
+
+        assert!(create(&[]).is_err());
+        let duplicate = [
+            (receiver("a@example.com"), [1u8; DB_INDEX_LENGTH]),
+            (receiver("a@example.com"), [1u8; DB_INDEX_LENGTH]),
+        ];
+        assert!(create(&duplicate).is_err());
+        let too_many: Vec<_> = (0..=MAX_RECEIVERS as u8)
+            .map(|i| (receiver("a@example.com"), [i; DB_INDEX_LENGTH]))
+            .collect();
+        assert!(create(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_validate_attachment_names() {
+        for filename in ["", "..", "dir/file", "a\\b", "line\nbreak"] {
+            let mut invalid = attachment(10);
+            invalid.filename = filename.to_string();
+            assert!(validate_attachment(&invalid).is_err(), "{:?}", filename);
+        }
+        for mime_type in ["pdf", "application/", "text/plain; charset=utf-8"] {
+            let mut invalid = attachment(10);
+            invalid.mime_type = mime_type.to_string();
+            assert!(validate_attachment(&invalid).is_err(), "{:?}", mime_type);
+        }
+    }
+}
@@ -3,7 +3,8 @@
 //! Handles tracking-related operations (read confirmation, cleanup).
 
 use super::super::shared_secret_storage::SharedSecretStorage;
-use super::super::shared_secret_types::constants::*;
+use super::super::shared_secret_types::{CleanupCounts, SecretRole, constants::*};
+use chrono::Utc;
 use spin_sdk::sqlite::Error as SqliteError;
 
 /// Confirm read by updating tracking record
@@ -17,8 +18,81 @@ pub fn confirm_read(reference_hash: &[u8; REFERENCE_HASH_LENGTH]) -> Result<bool
     SharedSecretStorage::update_tracking_read(reference_hash)
 }
 
-/// Clean up expired secrets and tracking
-#[allow(dead_code)]
-pub fn cleanup_expired() -> Result<(u32, u32), SqliteError> {
+/// Activation time of a secret that `role` cannot read yet
+///
+/// Senders can always open their own secret; receivers must wait until not_before.
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+/// * `role` - Role of the URL being used
+///
+/// # Returns
+/// * `Result<Option<i64>, SqliteError>` - not_before if still pending for this role, None if readable
+pub fn pending_activation(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    role: SecretRole,
+) -> Result<Option<i64>, SqliteError> {
+    if role == SecretRole::Sender {
+        return Ok(None);
+    }
+
+    let now = Utc::now().timestamp();
+    Ok(
+        SharedSecretStorage::get_not_before_from_tracking(reference_hash)?
+            .filter(|&not_before| not_before > now),
+    )
+}
+
+/// Consume one read for a receiver
+///
+/// Decrements the receiver's own counter and the tracking total. Secrets
+/// created before per-receiver counters only have the tracking counter.
+///
+/// # Arguments
+/// * `db_index` - Receiver database index (32 bytes)
+/// * `reference_hash` - Reference hash (16 bytes)
+///
+/// # Returns
+/// * `Result<i64, SqliteError>` - Receiver's new pending_reads
+pub fn consume_read(
+    db_index: &[u8; DB_INDEX_LENGTH],
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+) -> Result<i64, SqliteError> {
+    match SharedSecretStorage::get_receiver_status(db_index)? {
+        Some(status) if status.pending_reads <= 0 => Ok(0),
+        Some(_) => {
+            SharedSecretStorage::decrement_tracking_reads(reference_hash)?;
+            SharedSecretStorage::decrement_receiver_reads(db_index)
+        }
+        None => SharedSecretStorage::decrement_tracking_reads(reference_hash),
+    }
+}
+
+/// Delete a receiver's shared_secrets entry once its reads and downloads are used up
+///
+/// The entry holds the receiver's key material, so after the last read it is
+/// kept until the attachment downloads are used up too. Receivers without a
+/// counter row (created before per-receiver counters) have no downloads.
+///
+/// # Arguments
+/// * `db_index` - Receiver database index (32 bytes)
+///
+/// # Returns
+/// * `Result<bool, SqliteError>` - true if the entry was deleted
+pub fn release_if_consumed(db_index: &[u8; DB_INDEX_LENGTH]) -> Result<bool, SqliteError> {
+    let consumed = SharedSecretStorage::get_receiver_status(db_index)?.is_none_or(|status| {
+        status.pending_reads <= 0
+            && status
+                .pending_downloads
+                .is_none_or(|downloads| downloads <= 0)
+    });
+    if !consumed {
+        return Ok(false);
+    }
+    SharedSecretStorage::delete_secret(db_index)
+}
+
+/// Clean up expired secrets and tracking (deleted rows per table)
+pub fn cleanup_expired() -> Result<CleanupCounts, SqliteError> {
     SharedSecretStorage::cleanup_expired()
 }
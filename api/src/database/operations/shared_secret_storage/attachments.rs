@@ -0,0 +1,102 @@
+//! Attachment operations for shared secrets
+//!
+//! Handles the shared_secrets_attachment_chunks table (encrypted file chunks).
+//! Download counters are per receiver (see receivers module).
+
+use super::super::shared_secret_types::constants::*;
+use crate::database::get_database_connection;
+use spin_sdk::sqlite::{Error as SqliteError, Value};
+use tracing::{debug, warn};
+
+/// Store encrypted attachment chunks
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+/// * `chunks` - Encrypted chunks in order
+/// * `expires_at` - Expiration timestamp in seconds (matches tracking)
+///
+/// # Returns
+/// * `Result<(), SqliteError>` - Success or error
+pub fn store_attachment(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    chunks: &[Vec<u8>],
+    expires_at: i64,
+) -> Result<(), SqliteError> {
+    let connection = get_database_connection()?;
+
+    debug!(
+        "📎 SharedSecret: Storing attachment ({} chunks, expires_at={})",
+        chunks.len(),
+        expires_at
+    );
+
+    for (index, chunk) in chunks.iter().enumerate() {
+        connection.execute(
+            "INSERT INTO shared_secrets_attachment_chunks (reference_hash, chunk_index, encrypted_chunk, expires_at) VALUES (?, ?, ?, ?)",
+            &[
+                Value::Blob(reference_hash.to_vec()),
+                Value::Integer(index as i64),
+                Value::Blob(chunk.clone()),
+                Value::Integer(expires_at),
+            ],
+        )?;
+    }
+
+    debug!("✅ SharedSecret: Attachment stored");
+    Ok(())
+}
+
+/// Retrieve one encrypted attachment chunk
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+/// * `chunk_index` - Zero-based chunk index
+///
+/// # Returns
+/// * `Result<Option<Vec<u8>>, SqliteError>` - Encrypted chunk or None
+pub fn retrieve_attachment_chunk(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    chunk_index: u32,
+) -> Result<Option<Vec<u8>>, SqliteError> {
+    let connection = get_database_connection()?;
+
+    let result = connection.execute(
+        "SELECT encrypted_chunk FROM shared_secrets_attachment_chunks WHERE reference_hash = ? AND chunk_index = ?",
+        &[
+            Value::Blob(reference_hash.to_vec()),
+            Value::Integer(chunk_index as i64),
+        ],
+    )?;
+
+    if let Some(row) = result.rows.first() {
+        match &row.values[0] {
+            Value::Blob(data) => Ok(Some(data.clone())),
+            _ => Err(SqliteError::Io("Invalid encrypted_chunk type".to_string())),
+        }
+    } else {
+        warn!(
+            "⚠️  SharedSecret: Attachment chunk {} not found",
+            chunk_index
+        );
+        Ok(None)
+    }
+}
+
+/// Delete attachment chunks by reference_hash
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+///
+/// # Returns
+/// * `Result<(), SqliteError>` - Success or error (no-op without attachment)
+pub fn delete_attachment(reference_hash: &[u8; REFERENCE_HASH_LENGTH]) -> Result<(), SqliteError> {
+    let connection = get_database_connection()?;
+
+    connection.execute(
+        "DELETE FROM shared_secrets_attachment_chunks WHERE reference_hash = ?",
+        &[Value::Blob(reference_hash.to_vec())],
+    )?;
+
+    debug!("🗑️ SharedSecret: Attachment deleted (or didn't exist)");
+    Ok(())
+}
@@ -1,21 +1,21 @@
 //! Cleanup operations for shared secrets
 //!
-//! Handles cleanup of expired records from both tables.
+//! Handles cleanup of expired records from every shared secret table.
 
-use crate::database::get_database_connection;
+use super::super::shared_secret_types::CleanupCounts;
+use crate::database::{changed_rows, get_database_connection};
 use chrono::Utc;
-use spin_sdk::sqlite::{Error as SqliteError, Value};
+use spin_sdk::sqlite::{Connection, Error as SqliteError, Value};
 use tracing::debug;
 
-/// Clean up expired shared secrets and tracking records
+/// Clean up expired shared secrets, tracking, receivers, attachments and requests
 ///
 /// # Returns
-/// * `Result<(u32, u32), SqliteError>` - (secrets_deleted, tracking_deleted) or error
-#[allow(dead_code)]
-pub fn cleanup_expired() -> Result<(u32, u32), SqliteError> {
+/// * `Result<CleanupCounts, SqliteError>` - Deleted rows per table or error
+pub fn cleanup_expired() -> Result<CleanupCounts, SqliteError> {
     let connection = get_database_connection()?;
 
-    let now_hours = Utc::now().timestamp() / 3600;
+    let now = Utc::now().timestamp();
 
     // ============================================================================
     // CRITICAL: Delete ORDER matters (v3)
@@ -31,18 +31,38 @@ pub fn cleanup_expired() -> Result<(u32, u32), SqliteError> {
     // ============================================================================
 
     // Delete expired secrets (key_material) - FIRST
-    connection.execute(
-        "DELETE FROM shared_secrets WHERE expires_at < ?",
-        &[Value::Integer(now_hours)],
-    )?;
+    let shared_secrets = delete_expired(&connection, "shared_secrets", now)?;
 
     // Delete expired tracking records (payload) - SECOND
+    let tracking = delete_expired(&connection, "shared_secrets_tracking", now)?;
+
+    // Delete expired receiver counters and attachment chunks - THIRD
+    // Without the payload their metadata and key are gone, so chunks are unreadable
+    let receivers = delete_expired(&connection, "shared_secrets_receivers", now)?;
+    let attachment_chunks = delete_expired(&connection, "shared_secrets_attachment_chunks", now)?;
+
+    // Delete expired (never fulfilled) secret requests - independent of the above
+    let requests = delete_expired(&connection, "shared_secret_requests", now)?;
+
+    let counts = CleanupCounts {
+        shared_secrets,
+        tracking,
+        receivers,
+        attachment_chunks,
+        requests,
+    };
+    debug!("🧹 SharedSecret: Cleaned up expired records: {:?}", counts);
+    Ok(counts)
+}
+
+/// Delete rows of `table` whose expires_at (seconds) is in the past
+///
+/// # Returns
+/// * `Result<u32, SqliteError>` - Number of rows deleted
+fn delete_expired(connection: &Connection, table: &str, now: i64) -> Result<u32, SqliteError> {
     connection.execute(
-        "DELETE FROM shared_secrets_tracking WHERE expires_at < ?",
-        &[Value::Integer(now_hours)],
+        &format!("DELETE FROM {} WHERE expires_at < ?", table),
+        &[Value::Integer(now)],
     )?;
-
-    debug!("🧹 SharedSecret: Cleaned up expired records (shared_secrets first, then tracking)");
-    // Spin SQLite doesn't provide rows_affected, return placeholder
-    Ok((1, 1))
+    changed_rows(connection)
 }
@@ -3,16 +3,20 @@
 //! Provides database storage functions for shared secrets including
 //! storage, retrieval, deletion, tracking, and cleanup operations.
 
+mod attachments;
 mod cleanup;
 mod deletion;
+mod receivers;
+mod requests;
 mod retrieval;
 mod storage;
 mod tracking;
 
-use super::shared_secret_types::{SecretRole, constants::*};
+use super::shared_secret_types::{CleanupCounts, ReceiverStatus, SecretRole, constants::*};
 use spin_sdk::sqlite::Error as SqliteError;
 
 // Re-export type aliases
+pub use requests::RequestData;
 pub use retrieval::SecretData;
 
 /// Type alias for secret retrieval result tuple v2: (encrypted_payload, expires_at) - NO ROLE
@@ -32,7 +36,7 @@ impl SharedSecretStorage {
     /// # Arguments
     /// * `db_index` - Database index (32 bytes) - PRIMARY KEY
     /// * `encrypted_payload` - Encrypted payload blob
-    /// * `expires_at` - Expiration timestamp in hours since Unix epoch
+    /// * `expires_at` - Expiration timestamp in seconds since Unix epoch
     /// * `role` - 'sender' or 'receiver' (TEMPORARY - will be removed when schema updated)
     ///
     /// # Returns
@@ -51,7 +55,7 @@ impl SharedSecretStorage {
     /// # Arguments
     /// * `id` - Encrypted ID (32 bytes)
     /// * `encrypted_payload` - Encrypted payload blob
-    /// * `expires_at` - Expiration timestamp in hours since Unix epoch
+    /// * `expires_at` - Expiration timestamp in seconds since Unix epoch
     /// * `role` - 'sender' or 'receiver'
     ///
     /// # Returns
@@ -169,6 +173,19 @@ impl SharedSecretStorage {
         tracking::get_read_at_from_tracking(reference_hash)
     }
 
+    /// Get not_before (activation) timestamp from tracking table by reference_hash
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    ///
+    /// # Returns
+    /// * `Result<Option<i64>, SqliteError>` - not_before timestamp (seconds) or None if not set/not found
+    pub fn get_not_before_from_tracking(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    ) -> Result<Option<i64>, SqliteError> {
+        tracking::get_not_before_from_tracking(reference_hash)
+    }
+
     /// Decrement pending_reads in tracking table
     ///
     /// # Arguments
@@ -190,7 +207,8 @@ impl SharedSecretStorage {
     /// # Arguments
     /// * `reference_hash` - Reference hash (16 bytes)
     /// * `pending_reads` - Initial pending_reads counter
-    /// * `expires_at` - Expiration timestamp in hours
+    /// * `expires_at` - Expiration timestamp in seconds
+    /// * `not_before` - Activation timestamp in seconds (None = readable immediately)
     /// * `encrypted_payload` - Encrypted payload blob (NEW)
     ///
     /// # Returns
@@ -199,12 +217,14 @@ impl SharedSecretStorage {
         reference_hash: &[u8; REFERENCE_HASH_LENGTH],
         pending_reads: i64,
         expires_at: i64,
+        not_before: Option<i64>,
         encrypted_payload: &[u8],
     ) -> Result<(), SqliteError> {
         tracking::store_tracking_with_payload(
             reference_hash,
             pending_reads,
             expires_at,
+            not_before,
             encrypted_payload,
         )
     }
@@ -248,16 +268,210 @@ impl SharedSecretStorage {
         tracking::update_tracking_read(reference_hash)
     }
 
+    // ============================================================================
+    // RECEIVER OPERATIONS (delegated to receivers module)
+    // ============================================================================
+
+    /// Store receiver counters
+    ///
+    /// # Arguments
+    /// * `db_index` - Receiver database index (32 bytes, same as shared_secrets.id)
+    /// * `reference_hash` - Reference hash (16 bytes)
+    /// * `pending_reads` - Initial read counter (max_reads)
+    /// * `pending_downloads` - Initial attachment download counter (None without attachment)
+    /// * `expires_at` - Expiration timestamp in seconds (matches tracking)
+    ///
+    /// # Returns
+    /// * `Result<(), SqliteError>` - Success or error
+    pub fn store_receiver(
+        db_index: &[u8; DB_INDEX_LENGTH],
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+        pending_reads: i64,
+        pending_downloads: Option<i64>,
+        expires_at: i64,
+    ) -> Result<(), SqliteError> {
+        receivers::store_receiver(
+            db_index,
+            reference_hash,
+            pending_reads,
+            pending_downloads,
+            expires_at,
+        )
+    }
+
+    /// Get receiver counters by db_index
+    ///
+    /// # Arguments
+    /// * `db_index` - Receiver database index (32 bytes)
+    ///
+    /// # Returns
+    /// * `Result<Option<ReceiverStatus>, SqliteError>` - Counters or None (legacy single-receiver secret)
+    pub fn get_receiver_status(
+        db_index: &[u8; DB_INDEX_LENGTH],
+    ) -> Result<Option<ReceiverStatus>, SqliteError> {
+        receivers::get_receiver_status(db_index)
+    }
+
+    /// List all receiver counters for a reference_hash
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    ///
+    /// # Returns
+    /// * `Result<Vec<([u8; DB_INDEX_LENGTH], ReceiverStatus)>, SqliteError>` - (db_index, counters) pairs
+    pub fn list_receiver_statuses(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    ) -> Result<Vec<([u8; DB_INDEX_LENGTH], ReceiverStatus)>, SqliteError> {
+        receivers::list_receiver_statuses(reference_hash)
+    }
+
+    /// Decrement a receiver's pending_reads and stamp its first read time
+    ///
+    /// # Arguments
+    /// * `db_index` - Receiver database index (32 bytes)
+    ///
+    /// # Returns
+    /// * `Result<i64, SqliteError>` - New pending_reads value
+    pub fn decrement_receiver_reads(db_index: &[u8; DB_INDEX_LENGTH]) -> Result<i64, SqliteError> {
+        receivers::decrement_receiver_reads(db_index)
+    }
+
+    /// Decrement a receiver's pending_downloads
+    ///
+    /// # Arguments
+    /// * `db_index` - Receiver database index (32 bytes)
+    ///
+    /// # Returns
+    /// * `Result<i64, SqliteError>` - New pending_downloads value
+    pub fn decrement_receiver_downloads(
+        db_index: &[u8; DB_INDEX_LENGTH],
+    ) -> Result<i64, SqliteError> {
+        receivers::decrement_receiver_downloads(db_index)
+    }
+
+    /// Delete all receiver counters by reference_hash
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    ///
+    /// # Returns
+    /// * `Result<(), SqliteError>` - Success or error
+    pub fn delete_receivers_by_reference_hash(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    ) -> Result<(), SqliteError> {
+        receivers::delete_receivers_by_reference_hash(reference_hash)
+    }
+
+    // ============================================================================
+    // ATTACHMENT OPERATIONS (delegated to attachments module)
+    // ============================================================================
+
+    /// Store encrypted attachment chunks
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    /// * `chunks` - Encrypted chunks in order
+    /// * `expires_at` - Expiration timestamp in seconds (matches tracking)
+    ///
+    /// # Returns
+    /// * `Result<(), SqliteError>` - Success or error
+    pub fn store_attachment(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+        chunks: &[Vec<u8>],
+        expires_at: i64,
+    ) -> Result<(), SqliteError> {
+        attachments::store_attachment(reference_hash, chunks, expires_at)
+    }
+
+    /// Retrieve one encrypted attachment chunk
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    /// * `chunk_index` - Zero-based chunk index
+    ///
+    /// # Returns
+    /// * `Result<Option<Vec<u8>>, SqliteError>` - Encrypted chunk or None
+    pub fn retrieve_attachment_chunk(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+        chunk_index: u32,
+    ) -> Result<Option<Vec<u8>>, SqliteError> {
+        attachments::retrieve_attachment_chunk(reference_hash, chunk_index)
+    }
+
+    /// Delete attachment chunks by reference_hash
+    ///
+    /// # Arguments
+    /// * `reference_hash` - Reference hash (16 bytes)
+    ///
+    /// # Returns
+    /// * `Result<(), SqliteError>` - Success or error (no-op without attachment)
+    pub fn delete_attachment(
+        reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    ) -> Result<(), SqliteError> {
+        attachments::delete_attachment(reference_hash)
+    }
+
+    // ============================================================================
+    // REQUEST OPERATIONS (delegated to requests module)
+    // ============================================================================
+
+    /// Store a pending secret request
+    ///
+    /// # Arguments
+    /// * `db_index` - Invitee database index (32 bytes) - PRIMARY KEY
+    /// * `encrypted_key_material` - Key material encrypted with the db_index-derived key (44 bytes)
+    /// * `encrypted_payload` - Request details encrypted with the key material
+    /// * `expires_at` - Expiration timestamp in seconds since Unix epoch
+    ///
+    /// # Returns
+    /// * `Result<(), SqliteError>` - Success or error
+    pub fn store_request(
+        db_index: &[u8; DB_INDEX_LENGTH],
+        encrypted_key_material: &[u8],
+        encrypted_payload: &[u8],
+        expires_at: i64,
+    ) -> Result<(), SqliteError> {
+        requests::store_request(
+            db_index,
+            encrypted_key_material,
+            encrypted_payload,
+            expires_at,
+        )
+    }
+
+    /// Retrieve a pending secret request by db_index
+    ///
+    /// # Arguments
+    /// * `db_index` - Invitee database index (32 bytes)
+    ///
+    /// # Returns
+    /// * `Result<Option<RequestData>, SqliteError>` - (encrypted_key_material, encrypted_payload, expires_at) or None
+    pub fn retrieve_request(
+        db_index: &[u8; DB_INDEX_LENGTH],
+    ) -> Result<Option<RequestData>, SqliteError> {
+        requests::retrieve_request(db_index)
+    }
+
+    /// Delete a secret request by db_index
+    ///
+    /// # Arguments
+    /// * `db_index` - Invitee database index (32 bytes)
+    ///
+    /// # Returns
+    /// * `Result<(), SqliteError>` - Success or error (no-op if not found)
+    pub fn delete_request(db_index: &[u8; DB_INDEX_LENGTH]) -> Result<(), SqliteError> {
+        requests::delete_request(db_index)
+    }
+
     // ============================================================================
     // CLEANUP OPERATIONS (delegated to cleanup module)
     // ============================================================================
 
-    /// Clean up expired shared secrets and tracking records
+    /// Clean up expired shared secrets, tracking, receivers, attachments and requests
     ///
     /// # Returns
-    /// * `Result<(u32, u32), SqliteError>` - (secrets_deleted, tracking_deleted) or error
-    #[allow(dead_code)]
-    pub fn cleanup_expired() -> Result<(u32, u32), SqliteError> {
+    /// * `Result<CleanupCounts, SqliteError>` - Deleted rows per table or error
+    pub fn cleanup_expired() -> Result<CleanupCounts, SqliteError> {
         cleanup::cleanup_expired()
     }
 }
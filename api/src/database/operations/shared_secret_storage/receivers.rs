@@ -0,0 +1,216 @@
+//! Per-receiver counters for shared secrets
+//!
+//! Handles the shared_secrets_receivers table: one row per receiver, keyed by
+//! the receiver's db_index and linked to shared_secrets_tracking by reference_hash.
+
+use super::super::shared_secret_types::{ReceiverStatus, constants::*};
+use crate::database::get_database_connection;
+use chrono::Utc;
+use spin_sdk::sqlite::{Error as SqliteError, Value};
+use tracing::{debug, warn};
+
+/// Store receiver counters
+///
+/// # Arguments
+/// * `db_index` - Receiver database index (32 bytes, same as shared_secrets.id)
+/// * `reference_hash` - Reference hash (16 bytes)
+/// * `pending_reads` - Initial read counter (max_reads)
+/// * `pending_downloads` - Initial attachment download counter (None without attachment)
+/// * `expires_at` - Expiration timestamp in seconds (matches tracking)
+///
+/// # Returns
+/// * `Result<(), SqliteError>` - Success or error
+pub fn store_receiver(
+    db_index: &[u8; DB_INDEX_LENGTH],
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    pending_reads: i64,
+    pending_downloads: Option<i64>,
+    expires_at: i64,
+) -> Result<(), SqliteError> {
+    let connection = get_database_connection()?;
+
+    connection.execute(
+        "INSERT INTO shared_secrets_receivers (db_index, reference_hash, pending_reads, read_at, pending_downloads, expires_at) VALUES (?, ?, ?, NULL, ?, ?)",
+        &[
+            Value::Blob(db_index.to_vec()),
+            Value::Blob(reference_hash.to_vec()),
+            Value::Integer(pending_reads),
+            pending_downloads.map_or(Value::Null, Value::Integer),
+            Value::Integer(expires_at),
+        ],
+    )?;
+
+    debug!(
+        "📊 SharedSecret: Receiver counters stored (pending_reads={})",
+        pending_reads
+    );
+    Ok(())
+}
+
+/// Parse a (pending_reads, read_at, pending_downloads) row
+fn parse_status(values: &[Value]) -> Result<ReceiverStatus, SqliteError> {
+    let optional = |value: &Value, field: &str| match value {
+        Value::Integer(val) => Ok(Some(*val)),
+        Value::Null => Ok(None),
+        _ => Err(SqliteError::Io(format!("Invalid {} type", field))),
+    };
+
+    let pending_reads = match &values[0] {
+        Value::Integer(val) => *val,
+        _ => return Err(SqliteError::Io("Invalid pending_reads type".to_string())),
+    };
+
+    Ok(ReceiverStatus {
+        pending_reads,
+        read_at: optional(&values[1], "read_at")?,
+        pending_downloads: optional(&values[2], "pending_downloads")?,
+    })
+}
+
+/// Get receiver counters by db_index
+///
+/// # Arguments
+/// * `db_index` - Receiver database index (32 bytes)
+///
+/// # Returns
+/// * `Result<Option<ReceiverStatus>, SqliteError>` - Counters or None (legacy single-receiver secret)
+pub fn get_receiver_status(
+    db_index: &[u8; DB_INDEX_LENGTH],
+) -> Result<Option<ReceiverStatus>, SqliteError> {
+    let connection = get_database_connection()?;
+
+    let result = connection.execute(
+        "SELECT pending_reads, read_at, pending_downloads FROM shared_secrets_receivers WHERE db_index = ?",
+        &[Value::Blob(db_index.to_vec())],
+    )?;
+
+    result
+        .rows
+        .first()
+        .map(|row| parse_status(&row.values))
+        .transpose()
+}
+
+/// List all receiver counters for a reference_hash
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+///
+/// # Returns
+/// * `Result<Vec<([u8; DB_INDEX_LENGTH], ReceiverStatus)>, SqliteError>` - (db_index, counters) pairs
+pub fn list_receiver_statuses(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+) -> Result<Vec<([u8; DB_INDEX_LENGTH], ReceiverStatus)>, SqliteError> {
+    let connection = get_database_connection()?;
+
+    let result = connection.execute(
+        "SELECT pending_reads, read_at, pending_downloads, db_index FROM shared_secrets_receivers WHERE reference_hash = ?",
+        &[Value::Blob(reference_hash.to_vec())],
+    )?;
+
+    result
+        .rows
+        .iter()
+        .map(|row| {
+            let db_index = match &row.values[3] {
+                Value::Blob(data) => <[u8; DB_INDEX_LENGTH]>::try_from(data.as_slice())
+                    .map_err(|_| SqliteError::Io("Invalid db_index length".to_string()))?,
+                _ => return Err(SqliteError::Io("Invalid db_index type".to_string())),
+            };
+            Ok((db_index, parse_status(&row.values)?))
+        })
+        .collect()
+}
+
+/// Decrement a receiver's pending_reads and stamp its first read time
+///
+/// # Arguments
+/// * `db_index` - Receiver database index (32 bytes)
+///
+/// # Returns
+/// * `Result<i64, SqliteError>` - New pending_reads value
+pub fn decrement_receiver_reads(db_index: &[u8; DB_INDEX_LENGTH]) -> Result<i64, SqliteError> {
+    let connection = get_database_connection()?;
+
+    let status = get_receiver_status(db_index)?
+        .ok_or_else(|| SqliteError::Io("Receiver record not found".to_string()))?;
+
+    if status.pending_reads <= 0 {
+        warn!("⚠️  SharedSecret: Receiver already at 0 reads");
+        return Ok(0);
+    }
+
+    let new_reads = status.pending_reads - 1;
+
+    connection.execute(
+        "UPDATE shared_secrets_receivers SET pending_reads = ?, read_at = COALESCE(read_at, ?) WHERE db_index = ?",
+        &[
+            Value::Integer(new_reads),
+            Value::Integer(Utc::now().timestamp()),
+            Value::Blob(db_index.to_vec()),
+        ],
+    )?;
+
+    debug!(
+        "📖 SharedSecret: Receiver decremented to {} reads",
+        new_reads
+    );
+    Ok(new_reads)
+}
+
+/// Decrement a receiver's pending_downloads
+///
+/// # Arguments
+/// * `db_index` - Receiver database index (32 bytes)
+///
+/// # Returns
+/// * `Result<i64, SqliteError>` - New pending_downloads value
+pub fn decrement_receiver_downloads(db_index: &[u8; DB_INDEX_LENGTH]) -> Result<i64, SqliteError> {
+    let connection = get_database_connection()?;
+
+    let pending_downloads = get_receiver_status(db_index)?
+        .and_then(|status| status.pending_downloads)
+        .ok_or_else(|| SqliteError::Io("Attachment record not found".to_string()))?;
+
+    if pending_downloads <= 0 {
+        warn!("⚠️  SharedSecret: Attachment already at 0 downloads");
+        return Ok(0);
+    }
+
+    let new_downloads = pending_downloads - 1;
+
+    connection.execute(
+        "UPDATE shared_secrets_receivers SET pending_downloads = ? WHERE db_index = ?",
+        &[
+            Value::Integer(new_downloads),
+            Value::Blob(db_index.to_vec()),
+        ],
+    )?;
+
+    debug!(
+        "📎 SharedSecret: Attachment downloads decremented to {}",
+        new_downloads
+    );
+    Ok(new_downloads)
+}
+
+/// Delete all receiver counters by reference_hash
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+///
+/// # Returns
+/// * `Result<(), SqliteError>` - Success or error
+pub fn delete_receivers_by_reference_hash(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+) -> Result<(), SqliteError> {
+    let connection = get_database_connection()?;
+
+    connection.execute(
+        "DELETE FROM shared_secrets_receivers WHERE reference_hash = ?",
+        &[Value::Blob(reference_hash.to_vec())],
+    )?;
+
+    debug!("🗑️ SharedSecret: Receiver counters deleted (or didn't exist)");
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Secret request storage
+//!
+//! Handles the shared_secret_requests table: one row per pending request, keyed
+//! by the invitee's db_index. Rows are deleted once the request is fulfilled.
+
+use super::super::shared_secret_types::constants::*;
+use crate::database::get_database_connection;
+use spin_sdk::sqlite::{Error as SqliteError, Value};
+use tracing::debug;
+
+/// Stored request row: (encrypted_key_material, encrypted_payload, expires_at)
+pub type RequestData = (Vec<u8>, Vec<u8>, i64);
+
+/// Store a pending secret request
+///
+/// # Arguments
+/// * `db_index` - Invitee database index (32 bytes) - PRIMARY KEY
+/// * `encrypted_key_material` - Key material encrypted with the db_index-derived key (44 bytes)
+/// * `encrypted_payload` - Request details encrypted with the key material
+/// * `expires_at` - Expiration timestamp in seconds since Unix epoch
+///
+/// # Returns
+/// * `Result<(), SqliteError>` - Success or error
+pub fn store_request(
+    db_index: &[u8; DB_INDEX_LENGTH],
+    encrypted_key_material: &[u8],
+    encrypted_payload: &[u8],
+    expires_at: i64,
+) -> Result<(), SqliteError> {
+    let connection = get_database_connection()?;
+
+    connection.execute(
+        "INSERT INTO shared_secret_requests (db_index, encrypted_key_material, encrypted_payload, expires_at) VALUES (?, ?, ?, ?)",
+        &[
+            Value::Blob(db_index.to_vec()),
+            Value::Blob(encrypted_key_material.to_vec()),
+            Value::Blob(encrypted_payload.to_vec()),
+            Value::Integer(expires_at),
+        ],
+    )?;
+
+    debug!(
+        "📨 SharedSecret: Request stored (expires_at={})",
+        expires_at
+    );
+    Ok(())
+}
+
+/// Retrieve a pending secret request by db_index
+///
+/// # Arguments
+/// * `db_index` - Invitee database index (32 bytes)
+///
+/// # Returns
+/// * `Result<Option<RequestData>, SqliteError>` - Row or None if not found
+pub fn retrieve_request(
+    db_index: &[u8; DB_INDEX_LENGTH],
+) -> Result<Option<RequestData>, SqliteError> {
+    let connection = get_database_connection()?;
+
+    let result = connection.execute(
+        "SELECT encrypted_key_material, encrypted_payload, expires_at FROM shared_secret_requests WHERE db_index = ?",
+        &[Value::Blob(db_index.to_vec())],
+    )?;
+
+    let Some(row) = result.rows.first() else {
+        return Ok(None);
+    };
+
+    match (&row.values[0], &row.values[1], &row.values[2]) {
+        (Value::Blob(key_material), Value::Blob(payload), Value::Integer(expires_at)) => {
+            Ok(Some((key_material.clone(), payload.clone(), *expires_at)))
+        }
+        _ => Err(SqliteError::Io(
+            "Invalid secret request row types".to_string(),
+        )),
+    }
+}
+
+/// Delete a secret request by db_index
+///
+/// # Arguments
+/// * `db_index` - Invitee database index (32 bytes)
+///
+/// # Returns
+/// * `Result<(), SqliteError>` - Success or error (no-op if not found)
+pub fn delete_request(db_index: &[u8; DB_INDEX_LENGTH]) -> Result<(), SqliteError> {
+    let connection = get_database_connection()?;
+
+    connection.execute(
+        "DELETE FROM shared_secret_requests WHERE db_index = ?",
+        &[Value::Blob(db_index.to_vec())],
+    )?;
+
+    debug!("🗑️ SharedSecret: Request deleted");
+    Ok(())
+}
@@ -12,7 +12,7 @@ use tracing::debug;
 /// # Arguments
 /// * `db_index` - Database index (32 bytes) - PRIMARY KEY
 /// * `encrypted_payload` - Encrypted payload blob
-/// * `expires_at` - Expiration timestamp in hours since Unix epoch
+/// * `expires_at` - Expiration timestamp in seconds since Unix epoch
 /// * `role` - 'sender' or 'receiver' (TEMPORARY - will be removed when schema updated)
 ///
 /// # Returns
@@ -54,7 +54,7 @@ pub fn store_shared_secret(
 /// # Arguments
 /// * `id` - Encrypted ID (32 bytes)
 /// * `encrypted_payload` - Encrypted payload blob
-/// * `expires_at` - Expiration timestamp in hours since Unix epoch
+/// * `expires_at` - Expiration timestamp in seconds since Unix epoch
 /// * `role` - 'sender' or 'receiver'
 ///
 /// # Returns
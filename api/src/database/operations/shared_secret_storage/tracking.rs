@@ -75,6 +75,34 @@ pub fn get_read_at_from_tracking(
     }
 }
 
+/// Get not_before (activation) timestamp from tracking table by reference_hash
+///
+/// # Arguments
+/// * `reference_hash` - Reference hash (16 bytes)
+///
+/// # Returns
+/// * `Result<Option<i64>, SqliteError>` - not_before timestamp (seconds) or None if not set/not found
+pub fn get_not_before_from_tracking(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+) -> Result<Option<i64>, SqliteError> {
+    let connection = get_database_connection()?;
+
+    let result = connection.execute(
+        "SELECT not_before FROM shared_secrets_tracking WHERE reference_hash = ?",
+        &[Value::Blob(reference_hash.to_vec())],
+    )?;
+
+    if let Some(row) = result.rows.first() {
+        match &row.values[0] {
+            Value::Integer(val) => Ok(Some(*val)),
+            Value::Null => Ok(None),
+            _ => Err(SqliteError::Io("Invalid not_before type".to_string())),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
 /// Decrement pending_reads in tracking table
 ///
 /// # Arguments
@@ -126,7 +154,8 @@ pub fn decrement_tracking_reads(
 /// # Arguments
 /// * `reference_hash` - Reference hash (16 bytes)
 /// * `pending_reads` - Initial pending_reads counter
-/// * `expires_at` - Expiration timestamp in hours
+/// * `expires_at` - Expiration timestamp in seconds
+/// * `not_before` - Activation timestamp in seconds (None = readable immediately)
 /// * `encrypted_payload` - Encrypted payload blob (NEW)
 ///
 /// # Returns
@@ -135,6 +164,7 @@ pub fn store_tracking_with_payload(
     reference_hash: &[u8; REFERENCE_HASH_LENGTH],
     pending_reads: i64,
     expires_at: i64,
+    not_before: Option<i64>,
     encrypted_payload: &[u8],
 ) -> Result<(), SqliteError> {
     let connection = get_database_connection()?;
@@ -147,11 +177,12 @@ pub fn store_tracking_with_payload(
     );
 
     connection.execute(
-        "INSERT INTO shared_secrets_tracking (reference_hash, pending_reads, read_at, expires_at, encrypted_payload) VALUES (?, ?, NULL, ?, ?)",
+        "INSERT INTO shared_secrets_tracking (reference_hash, pending_reads, read_at, expires_at, not_before, encrypted_payload) VALUES (?, ?, NULL, ?, ?, ?)",
         &[
             Value::Blob(reference_hash.to_vec()),
             Value::Integer(pending_reads),
             Value::Integer(expires_at),
+            not_before.map_or(Value::Null, Value::Integer),
             Value::Blob(encrypted_payload.to_vec()),
         ],
     )?;
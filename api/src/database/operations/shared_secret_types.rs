@@ -55,16 +55,14 @@ impl SecretRole {
 pub struct SharedSecretPayload {
     /// Sender email address
     pub sender_email: String,
-    /// Receiver email address
-    pub receiver_email: String,
+    /// Receivers in creation order (legacy payloads have exactly one)
+    pub receivers: Vec<SharedSecretReceiver>,
     /// ChaCha20-Poly1305 encrypted secret text from frontend (E2E encrypted)
     /// Size: original_text_bytes + 16 (Poly1305 MAC tag)
     pub encrypted_secret: Vec<u8>,
     /// Key material for decrypting encrypted_secret (nonce[12] + cipher_key[32])
     /// Stored in cleartext inside the encrypted payload (44 bytes)
     pub key_material: Vec<u8>,
-    /// Optional 9-digit OTP
-    pub otp: Option<String>,
     /// Creation timestamp (Unix epoch seconds)
     #[allow(dead_code)]
     pub created_at: i64,
@@ -72,6 +70,164 @@ pub struct SharedSecretPayload {
     pub reference_hash: Vec<u8>,
     /// Maximum reads allowed (stored in encrypted payload, used for validation & UI)
     pub max_reads: i64,
+    /// Optional file attachment metadata (chunks live in shared_secrets_attachment_chunks)
+    pub attachment: Option<SharedSecretAttachment>,
+}
+
+/// Receiver of a shared secret, stored inside the encrypted payload
+///
+/// Each receiver has its own URL hash (derived from its email), its own
+/// read counter in shared_secrets_receivers and its own optional OTP.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedSecretReceiver {
+    /// Receiver email address
+    pub email: String,
+    /// Optional 9-digit OTP for this receiver
+    pub otp: Option<String>,
+}
+
+/// Per-receiver counters from shared_secrets_receivers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiverStatus {
+    /// Remaining reads for this receiver
+    pub pending_reads: i64,
+    /// Timestamp of this receiver's first confirmed read (None if unread)
+    pub read_at: Option<i64>,
+    /// Remaining attachment downloads (None if the secret has no attachment)
+    pub pending_downloads: Option<i64>,
+}
+
+/// File attachment metadata, stored inside the encrypted payload
+///
+/// The file itself is encrypted by the frontend in ATTACHMENT_CHUNK_SIZE chunks with
+/// ChaCha20-Poly1305 and the secret's cipher_key. Chunk `i` uses the key_material
+/// nonce with its last 4 bytes XORed with big-endian `i + 1`, so no chunk reuses the
+/// nonce of the secret text and chunks cannot be reordered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedSecretAttachment {
+    /// Original file name (no path components)
+    pub filename: String,
+    /// MIME type declared by the sender (e.g. application/x-pem-file)
+    pub mime_type: String,
+    /// Plaintext size in bytes
+    pub size: u64,
+    /// Number of encrypted chunks
+    pub chunk_count: u32,
+}
+
+/// Encrypted file attachment as uploaded by the sender
+#[derive(Debug, Clone)]
+pub struct EncryptedAttachment {
+    pub filename: String,
+    pub mime_type: String,
+    /// Plaintext size in bytes
+    pub size: u64,
+    /// ChaCha20-Poly1305 encrypted chunks, in order (each plaintext chunk + 16-byte tag)
+    pub chunks: Vec<Vec<u8>>,
+}
+
+impl EncryptedAttachment {
+    /// Metadata stored in the encrypted payload
+    pub fn metadata(&self) -> SharedSecretAttachment {
+        SharedSecretAttachment {
+            filename: self.filename.clone(),
+            mime_type: self.mime_type.clone(),
+            size: self.size,
+            chunk_count: self.chunks.len() as u32,
+        }
+    }
+}
+
+/// Activation and expiration of a shared secret (seconds since Unix epoch)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretLifetime {
+    /// Receivers cannot read the secret before this time (None = immediately)
+    pub not_before: Option<i64>,
+    /// All rows of the secret expire at this time
+    pub expires_at: i64,
+}
+
+/// Configured bounds for a secret's lifetime (shared_secret_{min,max}_expires_seconds Spin variables)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpiryLimits {
+    /// Shortest allowed lifetime in seconds
+    pub min_seconds: i64,
+    /// Longest allowed lifetime in seconds (also caps how far ahead not_before may be)
+    pub max_seconds: i64,
+}
+
+impl ExpiryLimits {
+    /// Validate a requested lifetime and compute its timestamps
+    ///
+    /// The lifetime starts at `not_before` when given, so a delayed secret stays
+    /// readable for the full `expires_seconds`. Activation times already in the
+    /// past mean "readable immediately".
+    ///
+    /// # Arguments
+    /// * `expires_seconds` - Requested lifetime in seconds
+    /// * `not_before` - Optional activation timestamp in seconds
+    /// * `now` - Current timestamp in seconds
+    pub fn lifetime(
+        &self,
+        expires_seconds: i64,
+        not_before: Option<i64>,
+        now: i64,
+    ) -> Result<SecretLifetime, String> {
+        if !(self.min_seconds..=self.max_seconds).contains(&expires_seconds) {
+            return Err(format!(
+                "Expiration must be between {} and {} seconds",
+                self.min_seconds, self.max_seconds
+            ));
+        }
+
+        let not_before = not_before.filter(|&not_before| not_before > now);
+        if let Some(not_before) = not_before
+            && not_before - now > self.max_seconds
+        {
+            return Err(format!(
+                "Activation time cannot be more than {} seconds ahead",
+                self.max_seconds
+            ));
+        }
+
+        Ok(SecretLifetime {
+            not_before,
+            expires_at: not_before.unwrap_or(now) + expires_seconds,
+        })
+    }
+}
+
+/// Request for a secret, stored encrypted in shared_secret_requests
+///
+/// The requester asks the invitee to send them a secret. Fulfilling the request
+/// creates a regular shared secret with the invitee as sender and the requester
+/// as its only receiver, under the request's reference_hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretRequest {
+    /// Requester email address (receiver of the resulting secret)
+    pub requester_email: String,
+    /// Invitee email address (sender of the resulting secret)
+    pub invitee_email: String,
+    /// Note from the requester shown on the upload form (may be empty)
+    pub message: String,
+    /// Expiration of the resulting secret in seconds (counted from fulfilment)
+    pub expires_seconds: i64,
+    /// Maximum reads of the resulting secret
+    pub max_reads: i64,
+    /// Generate an OTP for the requester when the secret is created
+    pub require_otp: bool,
+    /// Requester's language for the notification email (ISO 639-1)
+    pub language: Option<String>,
+}
+
+/// Rows deleted per table by one expired-records cleanup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CleanupCounts {
+    pub shared_secrets: u32,
+    pub tracking: u32,
+    pub receivers: u32,
+    pub attachment_chunks: u32,
+    pub requests: u32,
 }
 
 /// Shared secret database operations struct
@@ -111,14 +267,14 @@ pub mod constants {
     /// Key material length for payload encryption (nonce[12] + cipher_key[32])
     pub const KEY_MATERIAL_LENGTH: usize = 44;
 
-    /// Minimum expiration hours
-    pub const MIN_EXPIRES_HOURS: i64 = 1;
+    /// Default expiration in seconds (24 hours)
+    pub const DEFAULT_EXPIRES_SECONDS: i64 = 24 * 3600;
 
-    /// Maximum expiration hours (72 hours = 3 days)
-    pub const MAX_EXPIRES_HOURS: i64 = 72;
+    /// Minimum hours a secret request link stays valid
+    pub const MIN_REQUEST_EXPIRES_HOURS: i64 = 1;
 
-    /// Default expiration hours
-    pub const DEFAULT_EXPIRES_HOURS: i64 = 24;
+    /// Maximum hours a secret request link stays valid (72 hours = 3 days)
+    pub const MAX_REQUEST_EXPIRES_HOURS: i64 = 72;
 
     /// Minimum read limit
     pub const MIN_READS: i64 = 1;
@@ -131,4 +287,72 @@ pub mod constants {
 
     /// Unlimited reads (for sender)
     pub const UNLIMITED_READS: i64 = -1;
+
+    /// Maximum receivers per shared secret
+    pub const MAX_RECEIVERS: usize = 10;
+
+    /// Plaintext bytes per encrypted attachment chunk (the last chunk may be shorter)
+    pub const ATTACHMENT_CHUNK_SIZE: u64 = 64 * 1024;
+
+    /// Maximum attachment size in bytes (5 MiB)
+    pub const MAX_ATTACHMENT_SIZE: u64 = 5 * 1024 * 1024;
+
+    /// Maximum attachment file name length in bytes
+    pub const MAX_ATTACHMENT_FILENAME_LENGTH: usize = 255;
+
+    /// Maximum attachment MIME type length in bytes
+    pub const MAX_ATTACHMENT_MIME_TYPE_LENGTH: usize = 127;
+
+    /// Poly1305 authentication tag length appended to every encrypted chunk
+    pub const POLY1305_TAG_LENGTH: u64 = 16;
+
+    /// Maximum secret request message length in characters
+    pub const MAX_REQUEST_MESSAGE_LENGTH: usize = 500;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: ExpiryLimits = ExpiryLimits {
+        min_seconds: 60,
+        max_seconds: 7 * 24 * 3600,
+    };
+    const NOW: i64 = 1_800_000_000;
+
+    #[test]
+    fn test_lifetime_bounds() {
+        assert_eq!(
+            LIMITS.lifetime(300, None, NOW),
+            Ok(SecretLifetime {
+                not_before: None,
+                expires_at: NOW + 300,
+            })
+        );
+        assert!(LIMITS.lifetime(60, None, NOW).is_ok());
+        assert!(LIMITS.lifetime(59, None, NOW).is_err());
+        assert!(LIMITS.lifetime(LIMITS.max_seconds + 1, None, NOW).is_err());
+    }
+
+    #[test]
+    fn test_lifetime_not_before() {
+        let delayed = LIMITS.lifetime(3600, Some(NOW + 86400), NOW).unwrap();
+        assert_eq!(delayed.not_before, Some(NOW + 86400));
+        assert_eq!(delayed.expires_at, NOW + 86400 + 3600);
+
+        // Past activation times are readable immediately
+        assert_eq!(
+            LIMITS
+                .lifetime(3600, Some(NOW - 10), NOW)
+                .unwrap()
+                .not_before,
+            None
+        );
+
+        assert!(
+            LIMITS
+                .lifetime(3600, Some(NOW + LIMITS.max_seconds + 1), NOW)
+                .is_err()
+        );
+    }
 }
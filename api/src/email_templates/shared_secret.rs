@@ -1,13 +1,45 @@
+use chrono::DateTime;
 use maud::{DOCTYPE, PreEscaped, html};
 use rust_i18n::t;
 
+/// Pick the largest unit (days, hours, minutes) that expresses a lifetime exactly
+///
+/// Lifetimes that are not whole hours are shown in minutes, rounded up.
+fn duration_unit(seconds: i64) -> (&'static str, i64) {
+    if seconds >= 86400 && seconds % 86400 == 0 {
+        ("days", seconds / 86400)
+    } else if seconds >= 3600 && seconds % 3600 == 0 {
+        ("hours", seconds / 3600)
+    } else {
+        ("minutes", (seconds + 59) / 60)
+    }
+}
+
+/// Localized lifetime (e.g. "15 minutes", "3 days") for the current locale
+fn format_duration(seconds: i64) -> String {
+    match duration_unit(seconds) {
+        ("days", count) => t!("email.shared_secret.duration.days", count = count),
+        ("hours", count) => t!("email.shared_secret.duration.hours", count = count),
+        (_, count) => t!("email.shared_secret.duration.minutes", count = count),
+    }
+    .to_string()
+}
+
+/// Activation time as "YYYY-MM-DD HH:MM UTC"
+fn format_timestamp(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|date| date.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
 /// Render shared secret receiver email using Maud template with i18n support
 ///
 /// # Arguments
 /// * `secret_url` - The complete secret URL for the receiver
 /// * `reference` - The reference hash (Base58)
 /// * `sender_email` - Email of the sender
-/// * `expires_hours` - Expiration time in hours
+/// * `expires_seconds` - Lifetime of the secret in seconds
+/// * `not_before` - Activation timestamp in seconds (None = readable immediately)
 /// * `max_reads` - Maximum number of reads allowed
 /// * `language` - Language code (e.g., "en", "es", "eu")
 ///
@@ -18,7 +50,8 @@ pub fn render_shared_secret_receiver_email(
     secret_url: &str,
     reference: &str,
     sender_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
+    not_before: Option<i64>,
     max_reads: i64,
     language: &str,
 ) -> (String, String, String) {
@@ -34,7 +67,8 @@ pub fn render_shared_secret_receiver_email(
         secret_url,
         reference,
         sender_email,
-        expires_hours,
+        expires_seconds,
+        not_before,
         max_reads,
         language,
     );
@@ -42,7 +76,8 @@ pub fn render_shared_secret_receiver_email(
         secret_url,
         reference,
         sender_email,
-        expires_hours,
+        expires_seconds,
+        not_before,
         max_reads,
         language,
     );
@@ -56,7 +91,7 @@ pub fn render_shared_secret_receiver_email(
 /// * `secret_url` - The complete secret URL for the sender
 /// * `reference` - The reference hash (Base58)
 /// * `receiver_email` - Email of the receiver
-/// * `expires_hours` - Expiration time in hours
+/// * `expires_seconds` - Lifetime of the secret in seconds
 /// * `language` - Language code (e.g., "en", "es", "eu")
 ///
 /// # Returns
@@ -66,7 +101,7 @@ pub fn render_shared_secret_sender_email(
     secret_url: &str,
     reference: &str,
     receiver_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
     language: &str,
 ) -> (String, String, String) {
     // Set the locale for this email
@@ -81,14 +116,14 @@ pub fn render_shared_secret_sender_email(
         secret_url,
         reference,
         receiver_email,
-        expires_hours,
+        expires_seconds,
         language,
     );
     let text_body = render_sender_text_body(
         secret_url,
         reference,
         receiver_email,
-        expires_hours,
+        expires_seconds,
         language,
     );
 
@@ -100,7 +135,8 @@ fn render_receiver_html_body(
     secret_url: &str,
     reference: &str,
     sender_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
+    not_before: Option<i64>,
     max_reads: i64,
     language: &str,
 ) -> String {
@@ -142,7 +178,13 @@ fn render_receiver_html_body(
                             }
                             p style="margin: 5px 0;" {
                                 "⏰ " strong { (t!("email.shared_secret.receiver.expires_label")) ": " }
-                                (t!("email.shared_secret.receiver.expires_value", hours = expires_hours))
+                                (format_duration(expires_seconds))
+                            }
+                            @if let Some(not_before) = not_before {
+                                p style="margin: 5px 0;" {
+                                    "🔓 " strong { (t!("email.shared_secret.receiver.available_label")) ": " }
+                                    (format_timestamp(not_before))
+                                }
                             }
                             p style="margin: 5px 0;" {
                                 "👀 " strong { (t!("email.shared_secret.receiver.reads_label")) ": " }
@@ -187,7 +229,8 @@ fn render_receiver_text_body(
     secret_url: &str,
     reference: &str,
     sender_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
+    not_before: Option<i64>,
     max_reads: i64,
     language: &str,
 ) -> String {
@@ -206,7 +249,7 @@ fn render_receiver_text_body(
 📧 {sender_label}: {sender_email}
 🔢 {reference_label}: {reference}
 ⏰ {expires_label}: {expires_value}
-👀 {reads_label}: {reads_value}
+{available_line}👀 {reads_label}: {reads_value}
 
 {access_instructions}
 {secret_url}
@@ -232,10 +275,14 @@ fn render_receiver_text_body(
         sender_label = t!("email.shared_secret.receiver.sender_label"),
         reference_label = t!("email.shared_secret.receiver.reference_label"),
         expires_label = t!("email.shared_secret.receiver.expires_label"),
-        expires_value = t!(
-            "email.shared_secret.receiver.expires_value",
-            hours = expires_hours
-        ),
+        expires_value = format_duration(expires_seconds),
+        available_line = not_before
+            .map(|not_before| format!(
+                "🔓 {}: {}\n",
+                t!("email.shared_secret.receiver.available_label"),
+                format_timestamp(not_before)
+            ))
+            .unwrap_or_default(),
         reads_label = t!("email.shared_secret.receiver.reads_label"),
         reads_value = t!(
             "email.shared_secret.receiver.reads_value",
@@ -262,7 +309,7 @@ fn render_sender_html_body(
     secret_url: &str,
     reference: &str,
     receiver_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
     language: &str,
 ) -> String {
     // RTL languages that need right-to-left text direction
@@ -303,7 +350,7 @@ fn render_sender_html_body(
                             }
                             p style="margin: 5px 0;" {
                                 "⏰ " strong { (t!("email.shared_secret.sender.expires_label")) ": " }
-                                (t!("email.shared_secret.sender.expires_value", hours = expires_hours))
+                                (format_duration(expires_seconds))
                             }
                         }
 
@@ -344,7 +391,7 @@ fn render_sender_text_body(
     secret_url: &str,
     reference: &str,
     receiver_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
     language: &str,
 ) -> String {
     // Ensure locale is set for this text rendering
@@ -386,10 +433,7 @@ fn render_sender_text_body(
         receiver_label = t!("email.shared_secret.sender.receiver_label"),
         reference_label = t!("email.shared_secret.sender.reference_label"),
         expires_label = t!("email.shared_secret.sender.expires_label"),
-        expires_value = t!(
-            "email.shared_secret.sender.expires_value",
-            hours = expires_hours
-        ),
+        expires_value = format_duration(expires_seconds),
         access_instructions = format_args!(
             ">> {} <<",
             t!("email.shared_secret.sender.text_access_label")
@@ -404,3 +448,232 @@ fn render_sender_text_body(
         secret_url = secret_url
     )
 }
+
+/// Render secret request email (sent to the invitee) using Maud template with i18n support
+///
+/// # Arguments
+/// * `request_url` - The complete upload form URL for the invitee
+/// * `reference` - The reference hash (Base58)
+/// * `requester_email` - Email of the requester
+/// * `message` - Note from the requester (omitted when empty)
+/// * `expires_seconds` - Time left to fulfil the request in seconds
+/// * `language` - Language code (e.g., "en", "es", "eu")
+///
+/// # Returns
+/// * (subject, html_body, text_body) tuple
+pub fn render_shared_secret_request_email(
+    request_url: &str,
+    reference: &str,
+    requester_email: &str,
+    message: &str,
+    expires_seconds: i64,
+    language: &str,
+) -> (String, String, String) {
+    // Set the locale for this email
+    rust_i18n::set_locale(language);
+
+    let subject = format!(
+        "{} [Ref: {}]",
+        t!(
+            "email.shared_secret.request.subject",
+            requester = requester_email
+        ),
+        reference
+    );
+    let html_body = render_request_html_body(
+        request_url,
+        reference,
+        requester_email,
+        message,
+        expires_seconds,
+        language,
+    );
+    let text_body = render_request_text_body(
+        request_url,
+        reference,
+        requester_email,
+        message,
+        expires_seconds,
+        language,
+    );
+
+    (subject, html_body, text_body)
+}
+
+fn render_request_html_body(
+    request_url: &str,
+    reference: &str,
+    requester_email: &str,
+    message: &str,
+    expires_seconds: i64,
+    language: &str,
+) -> String {
+    // RTL languages that need right-to-left text direction
+    let is_rtl = matches!(language, "ar" | "he" | "fa" | "ur");
+
+    let markup = html! {
+        (DOCTYPE)
+        html lang=(language) dir=(if is_rtl { "rtl" } else { "ltr" }) {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1.0";
+                meta http-equiv="X-UA-Compatible" content="IE=edge";
+                title { (t!("email.shared_secret.request.subject", requester = requester_email)) " [Ref: " (reference) "]" }
+                style type="text/css" {
+                    (PreEscaped(include_str!("email_styles.css")))
+                }
+            }
+            body {
+                div.email-container {
+                    div.email-header {
+                        h1 { (t!("email.shared_secret.request.title")) }
+                        p { (t!("email.shared_secret.request.subtitle")) }
+                    }
+
+                    div.email-body {
+                        p.greeting { (t!("email.shared_secret.request.greeting")) }
+
+                        p.intro-text {
+                            (t!("email.shared_secret.request.intro", requester = requester_email))
+                        }
+
+                        div.security-info style="background: #f3f4f6; padding: 15px; border-radius: 8px; margin: 20px 0;" {
+                            p style="margin: 5px 0;" {
+                                "📧 " strong { (t!("email.shared_secret.request.requester_label")) ": " } (requester_email)
+                            }
+                            @if !message.is_empty() {
+                                p style="margin: 5px 0;" {
+                                    "💬 " strong { (t!("email.shared_secret.request.message_label")) ": " } (message)
+                                }
+                            }
+                            p style="margin: 5px 0;" {
+                                "🔢 " strong { (t!("email.shared_secret.request.reference_label")) ": " } code { (reference) }
+                            }
+                            p style="margin: 5px 0;" {
+                                "⏰ " strong { (t!("email.shared_secret.request.expires_label")) ": " }
+                                (format_duration(expires_seconds))
+                            }
+                        }
+
+                        div style="text-align: center; margin: 30px 0;" {
+                            a.action-button href=(request_url) {
+                                (t!("email.shared_secret.request.button_text"))
+                            }
+                        }
+
+                        div.manual-link {
+                            p { (t!("email.shared_secret.request.manual_link_intro")) }
+                            code { (request_url) }
+                        }
+
+                        div.security-info {
+                            p { "⚠️ " (t!("email.shared_secret.request.security_warning")) }
+                        }
+
+                        p.security-notice {
+                            "🔒 " (t!("email.shared_secret.request.security_notice"))
+                        }
+                    }
+
+                    div.email-footer {
+                        p.footer-text { (t!("email.shared_secret.request.footer_text")) }
+                        p.no-reply-notice { (t!("email.shared_secret.request.no_reply_notice")) }
+                    }
+                }
+            }
+        }
+    };
+
+    markup.into_string()
+}
+
+fn render_request_text_body(
+    request_url: &str,
+    reference: &str,
+    requester_email: &str,
+    message: &str,
+    expires_seconds: i64,
+    language: &str,
+) -> String {
+    // Ensure locale is set for this text rendering
+    rust_i18n::set_locale(language);
+
+    let message_line = if message.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "💬 {}: {}\n",
+            t!("email.shared_secret.request.message_label"),
+            message
+        )
+    };
+
+    format!(
+        r#"{title} - {subtitle}
+{separator}
+
+{greeting}
+
+{intro_text}
+
+{info_section}
+📧 {requester_label}: {requester_email}
+{message_line}🔢 {reference_label}: {reference}
+⏰ {expires_label}: {expires_value}
+
+{access_instructions}
+{request_url}
+
+{security_section}
+• {security_warning}
+
+• {security_notice}
+
+{footer_separator}
+{footer_text}
+{no_reply_notice}
+        "#,
+        title = t!("email.shared_secret.request.title"),
+        subtitle = t!("email.shared_secret.request.subtitle"),
+        separator = "=".repeat(50),
+        greeting = t!("email.shared_secret.request.greeting"),
+        intro_text = t!(
+            "email.shared_secret.request.text_intro",
+            requester = requester_email
+        ),
+        info_section = t!("email.shared_secret.request.text_info_section"),
+        requester_label = t!("email.shared_secret.request.requester_label"),
+        reference_label = t!("email.shared_secret.request.reference_label"),
+        expires_label = t!("email.shared_secret.request.expires_label"),
+        expires_value = format_duration(expires_seconds),
+        access_instructions = format_args!(
+            ">> {} <<",
+            t!("email.shared_secret.request.text_access_label")
+        ),
+        security_section = t!("email.shared_secret.request.text_security_section"),
+        security_warning = t!("email.shared_secret.request.security_warning"),
+        security_notice = t!("email.shared_secret.request.security_notice"),
+        footer_separator = "-".repeat(50),
+        footer_text = t!("email.shared_secret.request.footer_text"),
+        no_reply_notice = t!("email.shared_secret.request.no_reply_notice"),
+        requester_email = requester_email,
+        message_line = message_line,
+        reference = reference,
+        request_url = request_url
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_unit() {
+        assert_eq!(duration_unit(60), ("minutes", 1));
+        assert_eq!(duration_unit(90), ("minutes", 2));
+        assert_eq!(duration_unit(3600), ("hours", 1));
+        assert_eq!(duration_unit(5400), ("minutes", 90));
+        assert_eq!(duration_unit(36 * 3600), ("hours", 36));
+        assert_eq!(duration_unit(14 * 86400), ("days", 14));
+    }
+}
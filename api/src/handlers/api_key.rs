@@ -3,19 +3,23 @@
 //! Provides GET and POST endpoints for API key generation with:
 //! - JWT authentication and Ed25519 signature validation
 //! - SignedResponse for all outputs (enterprise security)
+//! - Structured keys with prefix, environment tag and CRC32/BLAKE3 checksum,
+//!   verifiable offline through /api/api-key/verify
 //! - SOLID/DRY/KISS architecture with <225 lines
 
-use crate::types::{AlphabetType, CustomHashResponse};
+use crate::types::{AlphabetType, ApiKeyChecksum, ApiKeyVerifyResponse, GeneratorType};
+use crate::utils::api_key::{build_api_key, validate_label, verify_api_key};
+use crate::utils::entropy::string_entropy_bits;
+use crate::utils::generation::{GeneratedValue, create_generation_signed_response};
 use crate::utils::protected_endpoint::{extract_seed_from_payload, payload_to_params};
 use crate::utils::{
-    ProtectedEndpointMiddleware, ProtectedEndpointResult, create_error_response,
-    create_signed_endpoint_response, extract_crypto_material_from_request, generate_otp,
-    generate_random_seed, generate_with_seed, handle_signed_get_request, seed_to_base58,
-    validate_length,
+    ProtectedEndpointMiddleware, ProtectedEndpointResult, create_client_error_response,
+    create_error_response, create_server_error_response, create_signed_endpoint_response,
+    extract_crypto_material_from_request, generate_with_seed, handle_signed_get_request,
+    handle_signed_post_request, parse_generation_algorithm, validate_length,
 };
 use spin_sdk::http::{Method, Request, Response};
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::info;
 
 /// Handle API key requests (both GET and POST)
@@ -59,28 +63,94 @@ pub async fn handle_api_key_post_signed(req: Request) -> anyhow::Result<Response
         }
     };
 
-    // Convert payload to parameters and extract seed
-    let mut params = payload_to_params(&result.payload);
-    let provided_seed = match extract_seed_from_payload(&result.payload) {
-        Ok(seed) => seed,
-        Err(e) => return Ok(create_error_response(400, &e)),
-    };
-
-    // Use provided seed or generate fresh one
-    if provided_seed.is_none() && !params.contains_key("seed") {
-        let seed = generate_random_seed();
-        params.insert("seed".to_string(), seed_to_base58(&seed));
+    // Convert payload to parameters and validate a caller-supplied seed
+    // (a missing seed stays missing: the generator draws a fresh one)
+    let params = payload_to_params(&result.payload);
+    if let Err(e) = extract_seed_from_payload(&result.payload) {
+        return Ok(create_error_response(400, &e));
     }
 
     // Generate API key and create signed response
     generate_api_key_signed(&params, &crypto_material)
 }
 
-/// Generate secure API key and return SignedResponse (DRY implementation)
+/// Handle API key verification requests (POST only, keys never travel in query strings)
+pub async fn handle_api_key_verify_request(req: Request) -> anyhow::Result<Response> {
+    info!("🔑 Request to /api/api-key/verify endpoint");
+    match req.method() {
+        Method::Post => handle_signed_post_request(&req, verify_api_key_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Verify a structured API key and return SignedResponse
+fn verify_api_key_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let key = match params.get("key") {
+        Some(key) if !key.trim().is_empty() => key,
+        _ => return Ok(create_client_error_response("Missing key parameter")),
+    };
+
+    let payload = api_key_verification(key, params.get("prefix").map(String::as_str));
+
+    match create_signed_endpoint_response(payload, crypto_material) {
+        Ok(signed_response) => Ok(signed_response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+/// Check a key's structure and checksum, optionally requiring a prefix
+pub fn api_key_verification(key: &str, expected_prefix: Option<&str>) -> ApiKeyVerifyResponse {
+    let invalid = |error: String| ApiKeyVerifyResponse {
+        valid: false,
+        prefix: None,
+        environment: None,
+        checksum: None,
+        error: Some(error),
+    };
+
+    match verify_api_key(key) {
+        Ok(parts) if expected_prefix.is_some_and(|prefix| prefix != parts.prefix) => {
+            invalid(format!("Unexpected prefix '{}'", parts.prefix))
+        }
+        Ok(parts) => ApiKeyVerifyResponse {
+            valid: true,
+            prefix: Some(parts.prefix),
+            environment: parts.environment,
+            checksum: Some(parts.checksum.into()),
+            error: None,
+        },
+        Err(e) => invalid(e),
+    }
+}
+
+/// Generate secure API key(s) and return SignedResponse (DRY implementation)
 fn generate_api_key_signed(
     params: &HashMap<String, String>,
     crypto_material: &crate::utils::CryptoMaterial,
 ) -> anyhow::Result<Response> {
+    create_generation_signed_response(
+        params,
+        crypto_material,
+        GeneratorType::ApiKey,
+        generate_api_key_value,
+    )
+}
+
+/// Generate a single API key from request parameters and seed
+pub fn generate_api_key_value(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
     // Parse alphabet parameter (integer: 1=no-look-alike, 2=full)
     // API key allows only no-look-alike (1) and full (2) for compatibility
     let alphabet_type = if let Some(alphabet_str) = params.get("alphabet") {
@@ -91,25 +161,20 @@ fn generate_api_key_signed(
                     match alphabet {
                         AlphabetType::NoLookAlike | AlphabetType::Full => alphabet,
                         _ => {
-                            return Ok(create_error_response(
-                                400,
-                                "API key alphabet must be 1 (no-look-alike) or 2 (full)",
-                            ));
+                            return Err("API key alphabet must be 1 (no-look-alike) or 2 (full)"
+                                .to_string());
                         }
                     }
                 }
                 Err(_) => {
-                    return Ok(create_error_response(
-                        400,
-                        "Invalid alphabet index. Valid range for API keys: 1 or 2 (1=no-look-alike, 2=full)",
-                    ));
+                    return Err("Invalid alphabet index. Valid range for API keys: 1 or 2 (1=no-look-alike, 2=full)".to_string());
                 }
             },
             Err(_) => {
-                return Ok(create_error_response(
-                    400,
-                    "Invalid alphabet parameter. Must be integer 1 or 2 (1=no-look-alike, 2=full)",
-                ));
+                return Err(
+                    "Invalid alphabet parameter. Must be integer 1 or 2 (1=no-look-alike, 2=full)"
+                        .to_string(),
+                );
             }
         }
     } else {
@@ -130,40 +195,103 @@ fn generate_api_key_signed(
 
     // Validate length (min_length-64 for API keys)
     if let Err(e) = validate_length(length, min_length, 64) {
-        return Ok(create_error_response(400, &format!("API key {}", e)));
+        return Err(format!("API key {}", e));
     }
 
-    // Get or generate seed
-    let seed_32 = if let Some(seed_str) = params.get("seed") {
-        crate::utils::base58_to_seed(seed_str)
-            .map_err(|e| anyhow::anyhow!("Invalid seed: {}", e))?
-    } else {
-        generate_random_seed()
+    // Parse generation algorithm version (latest for fresh seeds, legacy for bare seeds)
+    // Checksummed keys postdate versioning, so their bare seeds use the latest algorithm
+    let algorithm = parse_generation_algorithm(params, &["checksum", "prefix", "environment"])?;
+
+    // Parse checksum parameter (integer: 0=none/legacy, 1=crc32, 2=blake3)
+    let checksum = match params.get("checksum") {
+        Some(checksum_str) => checksum_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| ApiKeyChecksum::try_from(index).ok())
+            .ok_or_else(|| {
+                "Invalid checksum parameter. Must be integer 0-2 (0=none, 1=crc32, 2=blake3)"
+                    .to_string()
+            })?,
+        None => ApiKeyChecksum::None,
     };
 
-    let seed_base58 = seed_to_base58(&seed_32);
+    let prefix = params.get("prefix").map(String::as_str);
+    let environment = params.get("environment").map(String::as_str);
+    if checksum == ApiKeyChecksum::None && (prefix.is_some() || environment.is_some()) {
+        return Err(
+            "Parameters 'prefix' and 'environment' require a checksum (1=crc32, 2=blake3)"
+                .to_string(),
+        );
+    }
+    if let Some(prefix) = prefix {
+        validate_label("prefix", prefix)?;
+    }
+    if let Some(environment) = environment {
+        validate_label("environment", environment)?;
+    }
 
-    // Generate API key with ak_ prefix using seeded generator
+    // Generate the random part using seeded generator
     let alphabet = alphabet_type.as_chars();
-    let key_part = generate_with_seed(seed_32, length, &alphabet);
-    let api_key = format!("ak_{}", key_part);
+    let key_part = generate_with_seed(seed_32, length, &alphabet, algorithm);
+    let api_key = match checksum {
+        ApiKeyChecksum::None => format!("ak_{}", key_part),
+        _ => build_api_key(prefix.unwrap_or("ak"), environment, &key_part, checksum),
+    };
 
-    // Generate OTP and timestamp
-    let otp = generate_otp(seed_32);
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
-        .as_secs();
+    // Prefix, environment tag and checksum add no entropy
+    let entropy_bits = string_entropy_bits(&alphabet, length, "ak_", "", false, algorithm);
 
-    // Create payload directly
-    let payload = CustomHashResponse::new(api_key, seed_base58, otp, timestamp);
+    Ok(GeneratedValue::new(api_key)
+        .with_algorithm(algorithm)
+        .with_entropy_bits(entropy_bits))
+}
 
-    // Create signed response using DRY helper
-    match create_signed_endpoint_response(payload, crypto_material) {
-        Ok(signed_response) => Ok(signed_response),
-        Err(e) => Ok(create_error_response(
-            500,
-            &format!("Failed to create signed response: {}", e),
-        )),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_legacy_format_unchanged_by_default() {
+        let value = generate_api_key_value(&params(&[]), [1u8; 32]).unwrap();
+        assert!(value.value.starts_with("ak_"));
+        assert_eq!(value.value.len(), 3 + 44);
+        assert!(api_key_verification(&value.value, None).error.is_some());
+    }
+
+    #[test]
+    fn test_structured_key_verifies() {
+        let request = params(&[
+            ("checksum", "2"),
+            ("prefix", "acme"),
+            ("environment", "test"),
+        ]);
+        let value = generate_api_key_value(&request, [1u8; 32]).unwrap();
+        assert!(value.value.starts_with("acme_test_"));
+
+        // Same random part as the legacy key from the same seed
+        let legacy = generate_api_key_value(&params(&[]), [1u8; 32]).unwrap();
+        assert!(value.value.contains(&legacy.value[3..]));
+        assert_eq!(value.entropy_bits, legacy.entropy_bits);
+
+        let verification = api_key_verification(&value.value, Some("acme"));
+        assert!(verification.valid);
+        assert_eq!(verification.environment.as_deref(), Some("test"));
+        assert_eq!(verification.checksum, Some(2));
+        assert!(!api_key_verification(&value.value, Some("ak")).valid);
+    }
+
+    #[test]
+    fn test_structured_parameters_validation() {
+        let seed = [0u8; 32];
+        assert!(generate_api_key_value(&params(&[("prefix", "acme")]), seed).is_err());
+        assert!(generate_api_key_value(&params(&[("checksum", "3")]), seed).is_err());
+        assert!(
+            generate_api_key_value(&params(&[("checksum", "1"), ("environment", "LIVE")]), seed)
+                .is_err()
+        );
+        let default_prefix = generate_api_key_value(&params(&[("checksum", "1")]), seed).unwrap();
+        assert!(default_prefix.value.starts_with("ak_"));
+        assert!(api_key_verification(&default_prefix.value, None).valid);
     }
 }
@@ -3,22 +3,34 @@
 //! Provides GET and POST endpoints for custom hash generation with:
 //! - JWT authentication and Ed25519 signature validation
 //! - SignedResponse for all outputs (enterprise security)
+//! - Byte mode: seeded random bytes or SHA-2/SHA-3/BLAKE3 digests of caller input
+//! - Templates: fixed shapes with placeholders, literals and Luhn/mod-97 check digits
 //! - SOLID/DRY/KISS architecture with <225 lines
 
-use crate::types::{AlphabetType, CustomHashResponse};
+use crate::types::{
+    AlphabetType, ByteEncoding, CustomAlphabet, CustomMode, DigestAlgorithm, GenerationAlgorithm,
+    GeneratorType,
+};
+use crate::utils::digest::{digest_bytes, encode_bytes, random_bytes};
+use crate::utils::entropy::string_entropy_bits;
+use crate::utils::generation::{GeneratedValue, create_generation_signed_response};
 use crate::utils::protected_endpoint::{extract_seed_from_payload, payload_to_params};
+use crate::utils::template::Template;
 use crate::utils::{
     ProtectedEndpointMiddleware, ProtectedEndpointResult, create_auth_error_response,
-    create_client_error_response, create_server_error_response, create_signed_endpoint_response,
-    extract_crypto_material_from_request, generate_avoiding_unwanted_patterns, generate_otp,
-    generate_random_seed, generate_with_seed, handle_signed_get_request, seed_to_base58,
-    validate_length, validate_prefix_suffix,
+    extract_crypto_material_from_request, generate_avoiding_unwanted_patterns, generate_with_seed,
+    handle_signed_get_request, parse_generation_algorithm, validate_length, validate_prefix_suffix,
 };
 use spin_sdk::http::{Method, Request, Response};
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::info;
 
+/// Byte mode output length bounds (random bytes and BLAKE3 XOF)
+const MIN_BYTES: usize = 16;
+const MAX_BYTES: usize = 512;
+/// Maximum caller-supplied input digested in byte mode
+const MAX_DIGEST_INPUT_BYTES: usize = 65536;
+
 /// Main request handler for /api/custom endpoint
 pub async fn handle_custom_request(req: Request) -> anyhow::Result<Response> {
     info!("🎲 Request to /api/custom endpoint");
@@ -60,35 +72,60 @@ pub async fn handle_custom_post_signed(req: Request) -> anyhow::Result<Response>
         }
     };
 
-    // Convert payload to parameters and extract seed
-    let mut params = payload_to_params(&result.payload);
-    let provided_seed = match extract_seed_from_payload(&result.payload) {
-        Ok(seed) => seed,
-        Err(e) => return Ok(create_auth_error_response(&e)),
-    };
-
-    // Use provided seed or generate fresh one
-    if provided_seed.is_none() && !params.contains_key("seed") {
-        let seed = generate_random_seed();
-        params.insert("seed".to_string(), seed_to_base58(&seed));
+    // Convert payload to parameters and validate a caller-supplied seed
+    // (a missing seed stays missing: the generator draws a fresh one)
+    let params = payload_to_params(&result.payload);
+    if let Err(e) = extract_seed_from_payload(&result.payload) {
+        return Ok(create_auth_error_response(&e));
     }
 
     // Generate hash and create signed response
     generate_custom_hash_signed(&params, &crypto_material)
 }
 
-/// Generate custom hash and return SignedResponse (DRY implementation)
+/// Generate custom hash(es) and return SignedResponse (DRY implementation)
 fn generate_custom_hash_signed(
     params: &HashMap<String, String>,
     crypto_material: &crate::utils::CryptoMaterial,
 ) -> anyhow::Result<Response> {
-    // Parse parameters with default values (inline for DRY)
-    // Default length 21: Provides ~110 bits of entropy with Base58 (58^21 ≈ 2^110)
-    // Balances strong security with reasonable output length for custom hashes
-    let length = params
-        .get("length")
-        .and_then(|s| s.parse::<usize>().ok())
-        .unwrap_or(21);
+    create_generation_signed_response(
+        params,
+        crypto_material,
+        GeneratorType::Custom,
+        generate_custom_hash_value,
+    )
+}
+
+/// Generate a single custom hash from request parameters and seed
+pub fn generate_custom_hash_value(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
+    // Parse mode parameter (integer: 0=alphabet, 1=bytes)
+    let mode = match params.get("mode") {
+        Some(mode_str) => mode_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| CustomMode::try_from(index).ok())
+            .ok_or_else(|| {
+                "Invalid mode parameter. Must be 0 (alphabet) or 1 (bytes)".to_string()
+            })?,
+        None => CustomMode::Alphabet,
+    };
+    if mode == CustomMode::Bytes {
+        return generate_custom_bytes_value(params, seed_32);
+    }
+
+    // Parse optional custom alphabet (caller-supplied character set)
+    let custom_alphabet = match params.get("custom_alphabet") {
+        Some(_) if params.contains_key("alphabet") => {
+            return Err(
+                "Parameters 'alphabet' and 'custom_alphabet' are mutually exclusive".to_string(),
+            );
+        }
+        Some(raw) => Some(CustomAlphabet::parse(raw)?),
+        None => None,
+    };
 
     // Parse alphabet parameter (integer 0-4)
     let alphabet_type = if let Some(alphabet_str) = params.get("alphabet") {
@@ -96,72 +133,305 @@ fn generate_custom_hash_signed(
             Ok(index) => match AlphabetType::try_from(index) {
                 Ok(alphabet) => alphabet,
                 Err(_) => {
-                    return Ok(create_client_error_response(
-                        "Invalid alphabet index. Valid range: 0-4 (0=base58, 1=no-look-alike, 2=full, 3=full-with-symbols, 4=numeric)",
-                    ));
+                    return Err("Invalid alphabet index. Valid range: 0-4 (0=base58, 1=no-look-alike, 2=full, 3=full-with-symbols, 4=numeric)".to_string());
                 }
             },
             Err(_) => {
-                return Ok(create_client_error_response(
-                    "Invalid alphabet parameter. Must be integer 0-4 (0=base58, 1=no-look-alike, 2=full, 3=full-with-symbols, 4=numeric)",
-                ));
+                return Err("Invalid alphabet parameter. Must be integer 0-4 (0=base58, 1=no-look-alike, 2=full, 3=full-with-symbols, 4=numeric)".to_string());
             }
         }
     } else {
         AlphabetType::Base58 // Default
     };
 
+    // Template mode: the template fixes the shape, placeholders draw from the alphabet
+    if let Some(template) = params.get("template") {
+        return generate_custom_template_value(
+            params,
+            template,
+            custom_alphabet.as_ref(),
+            alphabet_type,
+            seed_32,
+        );
+    }
+
+    // Parse parameters with default values (inline for DRY)
+    // Default length 21: Provides ~110 bits of entropy with Base58 (58^21 ≈ 2^110)
+    // Balances strong security with reasonable output length for custom hashes
+    // Custom alphabets default to their own ~110-bit recommendation instead
+    let default_length = custom_alphabet
+        .as_ref()
+        .map(|alphabet| alphabet.min_length().min(128))
+        .unwrap_or(21);
+    let length = params
+        .get("length")
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(default_length);
+
     let prefix = params.get("prefix").cloned().unwrap_or_default();
     let suffix = params.get("suffix").cloned().unwrap_or_default();
 
     // Validate parameters
-    if let Err(e) = validate_length(length, 2, 128) {
-        return Ok(create_client_error_response(&e.to_string()));
+    validate_length(length, 2, 128).map_err(|e| e.to_string())?;
+    validate_prefix_suffix(&prefix, "Prefix").map_err(|e| e.to_string())?;
+    validate_prefix_suffix(&suffix, "Suffix").map_err(|e| e.to_string())?;
+
+    // Parse generation algorithm version (latest for fresh seeds, legacy for bare seeds)
+    // Custom alphabets postdate versioning, so their bare seeds use the latest algorithm
+    let algorithm = parse_generation_algorithm(params, &["custom_alphabet"])?;
+
+    // Generate hash using seeded generator
+    // Pattern avoidance applies whenever the alphabet can produce "--" or "__"
+    let (alphabet, avoid_patterns) = match &custom_alphabet {
+        Some(custom) => (
+            custom.as_chars(),
+            custom.contains('-') || custom.contains('_'),
+        ),
+        None => (
+            alphabet_type.as_chars(),
+            alphabet_type == AlphabetType::FullWithSymbols,
+        ),
+    };
+    let hash = if avoid_patterns {
+        generate_avoiding_unwanted_patterns(length, &alphabet, &prefix, &suffix, seed_32, algorithm)
+    } else {
+        let base_hash = generate_with_seed(seed_32, length, &alphabet, algorithm);
+        format!("{}{}{}", prefix, base_hash, suffix)
+    };
+
+    let entropy_bits = string_entropy_bits(
+        &alphabet,
+        length,
+        &prefix,
+        &suffix,
+        avoid_patterns,
+        algorithm,
+    );
+
+    Ok(GeneratedValue::new(hash)
+        .with_algorithm(algorithm)
+        .with_entropy_bits(entropy_bits))
+}
+
+/// Fill a template such as `XXXX-XXXX-XXXX` or `AA-9999{luhn}` (template mode)
+fn generate_custom_template_value(
+    params: &HashMap<String, String>,
+    template: &str,
+    custom_alphabet: Option<&CustomAlphabet>,
+    alphabet_type: AlphabetType,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
+    for unsupported in ["length", "prefix", "suffix"] {
+        if params.contains_key(unsupported) {
+            return Err(format!(
+                "Parameter '{}' is not supported with a template (use literals instead)",
+                unsupported
+            ));
+        }
     }
-    if let Err(e) = validate_prefix_suffix(&prefix, "Prefix") {
-        return Ok(create_client_error_response(&e.to_string()));
+
+    // Templates always draw with rejection sampling and have no legacy variant
+    if params
+        .get("algorithm")
+        .is_some_and(|algorithm| *algorithm != u8::from(GenerationAlgorithm::latest()).to_string())
+    {
+        return Err("Templates require the latest generation algorithm (2)".to_string());
     }
-    if let Err(e) = validate_prefix_suffix(&suffix, "Suffix") {
-        return Ok(create_client_error_response(&e.to_string()));
+
+    let alphabet = match custom_alphabet {
+        Some(custom) => custom.as_chars(),
+        None => alphabet_type.as_chars(),
+    };
+    let template = Template::parse(template, &alphabet)?;
+
+    Ok(GeneratedValue::new(template.generate(seed_32)).with_entropy_bits(template.entropy_bits()))
+}
+
+/// Generate random bytes or a digest of `input` (byte mode)
+fn generate_custom_bytes_value(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
+    for unsupported in ["alphabet", "custom_alphabet", "length", "template"] {
+        if params.contains_key(unsupported) {
+            return Err(format!(
+                "Parameter '{}' is not supported in byte mode (use 'bytes')",
+                unsupported
+            ));
+        }
     }
 
-    // Get or generate seed
-    let seed_32 = if let Some(seed_str) = params.get("seed") {
-        crate::utils::base58_to_seed(seed_str)
-            .map_err(|e| anyhow::anyhow!("Invalid seed: {}", e))?
-    } else {
-        generate_random_seed()
+    // Parse encoding parameter (integer: 0=hex, 1=base58, 2=base64, 3=base64url)
+    let encoding = match params.get("encoding") {
+        Some(encoding_str) => encoding_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| ByteEncoding::try_from(index).ok())
+            .ok_or_else(|| {
+                "Invalid encoding parameter. Must be integer 0-3 (0=hex, 1=base58, 2=base64, 3=base64url)"
+                    .to_string()
+            })?,
+        None => ByteEncoding::Hex,
     };
 
-    let seed_base58 = seed_to_base58(&seed_32);
+    // Parse digest parameter (integer: 0=sha-256, 1=sha-512, 2=sha3-256, 3=blake3)
+    let digest = match params.get("digest") {
+        Some(digest_str) => Some(
+            digest_str
+                .parse::<u8>()
+                .ok()
+                .and_then(|index| DigestAlgorithm::try_from(index).ok())
+                .ok_or_else(|| {
+                    "Invalid digest parameter. Must be integer 0-3 (0=sha-256, 1=sha-512, 2=sha3-256, 3=blake3)"
+                        .to_string()
+                })?,
+        ),
+        None => None,
+    };
+    let input = params.get("input");
 
-    // Generate hash using seeded generator
-    let alphabet = alphabet_type.as_chars();
-    let hash = if alphabet_type == AlphabetType::FullWithSymbols {
-        generate_avoiding_unwanted_patterns(length, &alphabet, &prefix, &suffix, seed_32)
-    } else {
-        let base_hash = generate_with_seed(seed_32, length, &alphabet);
-        format!("{}{}{}", prefix, base_hash, suffix)
+    // Output length: random bytes or BLAKE3 XOF length (16-512, default 32)
+    let bytes = match params.get("bytes") {
+        Some(bytes_str) => {
+            let bytes = bytes_str
+                .parse::<usize>()
+                .map_err(|_| "Invalid bytes parameter. Must be integer".to_string())?;
+            validate_length(bytes, MIN_BYTES, MAX_BYTES).map_err(|e| format!("Bytes {}", e))?;
+            if input.is_some()
+                && let Some(fixed_len) = digest.unwrap_or(DigestAlgorithm::Sha256).output_len()
+            {
+                return Err(format!(
+                    "Parameter 'bytes' only applies to random bytes and BLAKE3 (selected digest outputs {} bytes)",
+                    fixed_len
+                ));
+            }
+            bytes
+        }
+        None => 32,
     };
 
-    // Generate OTP and timestamp
-    let otp = generate_otp(seed_32);
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
-        .as_secs();
-
-    // Create payload directly
-    let payload = CustomHashResponse::new(hash, seed_base58, otp, timestamp);
-
-    // Create signed response using DRY helper
-    match create_signed_endpoint_response(payload, crypto_material) {
-        Ok(signed_response) => Ok(signed_response),
-        Err(e) => Ok(create_server_error_response(&format!(
-            "Failed to create signed response: {}",
-            e
-        ))),
-    }
+    let prefix = params.get("prefix").cloned().unwrap_or_default();
+    let suffix = params.get("suffix").cloned().unwrap_or_default();
+    validate_prefix_suffix(&prefix, "Prefix").map_err(|e| e.to_string())?;
+    validate_prefix_suffix(&suffix, "Suffix").map_err(|e| e.to_string())?;
+
+    let value = match input {
+        // Digest mode: output depends only on the input, so no entropy is claimed,
+        // a seed or batch would only repeat the same value and no seed or OTP is returned
+        Some(input) => {
+            if params.contains_key("seed") {
+                return Err(
+                    "Parameter 'seed' is not supported with 'input' (digests are not seeded)"
+                        .to_string(),
+                );
+            }
+            if params.get("count").is_some_and(|count| count != "1") {
+                return Err(
+                    "Parameter 'count' must be 1 with 'input' (a digest has a single value)"
+                        .to_string(),
+                );
+            }
+            if input.len() > MAX_DIGEST_INPUT_BYTES {
+                return Err(format!(
+                    "Input must be at most {} bytes",
+                    MAX_DIGEST_INPUT_BYTES
+                ));
+            }
+            let digest = digest.unwrap_or(DigestAlgorithm::Sha256);
+            let output = digest_bytes(digest, input.as_bytes(), bytes);
+            GeneratedValue::new(format!(
+                "{}{}{}",
+                prefix,
+                encode_bytes(&output, encoding),
+                suffix
+            ))
+            .unseeded()
+        }
+        None => {
+            if digest.is_some() {
+                return Err("Parameter 'digest' requires 'input'".to_string());
+            }
+            let output = random_bytes(seed_32, bytes);
+            GeneratedValue::new(format!(
+                "{}{}{}",
+                prefix,
+                encode_bytes(&output, encoding),
+                suffix
+            ))
+            .with_entropy_bits(bytes as f64 * 8.0)
+        }
+    };
+
+    Ok(value)
 }
 
 // DELETED: Legacy function handle_custom_with_params removed - was completely unused legacy code
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_digest_rejects_seed_and_batches() {
+        let seed = [1u8; 32];
+        let digest = params(&[("mode", "1"), ("input", "abc")]);
+        assert!(!generate_custom_hash_value(&digest, seed).unwrap().seeded);
+        assert!(
+            generate_custom_hash_value(&params(&[("mode", "1")]), seed)
+                .unwrap()
+                .seeded
+        );
+
+        for (key, value) in [("seed", "x"), ("count", "2")] {
+            let mut request = digest.clone();
+            request.insert(key.to_string(), value.to_string());
+            assert!(generate_custom_hash_value(&request, seed).is_err());
+        }
+        let mut single = digest.clone();
+        single.insert("count".to_string(), "1".to_string());
+        assert!(generate_custom_hash_value(&single, seed).is_ok());
+    }
+
+    #[test]
+    fn test_template_rejects_legacy_algorithm() {
+        let seed = [1u8; 32];
+        let template = |algorithm: &str| {
+            generate_custom_hash_value(
+                &params(&[("template", "XXXX"), ("algorithm", algorithm)]),
+                seed,
+            )
+        };
+        assert!(template("1").is_err());
+        assert!(template("2").is_ok());
+        assert!(generate_custom_hash_value(&params(&[("template", "XXXX")]), seed).is_ok());
+    }
+
+    #[test]
+    fn test_seeded_custom_alphabet_uses_latest_algorithm() {
+        let seed = [1u8; 32];
+        let algorithm = |pairs: &[(&str, &str)]| {
+            generate_custom_hash_value(&params(pairs), seed)
+                .unwrap()
+                .algorithm
+        };
+        // Custom alphabets postdate versioning: no legacy output to reproduce
+        assert_eq!(
+            algorithm(&[("seed", "x"), ("custom_alphabet", "abcdef")]),
+            Some(GenerationAlgorithm::RejectionV2)
+        );
+        assert_eq!(
+            algorithm(&[
+                ("seed", "x"),
+                ("custom_alphabet", "abcdef"),
+                ("algorithm", "1")
+            ]),
+            Some(GenerationAlgorithm::ModuloV1)
+        );
+        // Built-in alphabets keep the bare-seed legacy default
+        assert_eq!(
+            algorithm(&[("seed", "x"), ("alphabet", "0")]),
+            Some(GenerationAlgorithm::ModuloV1)
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Verifiable random draw endpoint with SignedResponse
+//!
+//! Provides GET and POST endpoints for dice, lotteries and shuffles with:
+//! - JWT authentication and Ed25519 signature validation
+//! - Unbiased integer ranges, sampling without replacement and Fisher-Yates
+//!   shuffles of caller-supplied lists (algorithms in `utils::draw`)
+//! - Seed and normalized parameters echoed back, so POSTing them again (or
+//!   re-running the documented algorithm) reproduces the exact draw
+
+use crate::types::{DrawMode, DrawResponse, GeneratorType};
+use crate::utils::draw::{draw_range, sample, shuffle};
+use crate::utils::generation::resolve_seed;
+use crate::utils::seed_envelope::SeedEnvelope;
+use crate::utils::{
+    create_client_error_response, create_server_error_response, create_signed_endpoint_response,
+    generate_otp, handle_signed_get_request, handle_signed_post_request, parse_bounded,
+};
+use serde_json::json;
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+/// Maximum values per range draw and picks per sample
+const MAX_DRAWS: usize = 1000;
+
+/// Maximum number of caller-supplied items
+const MAX_ITEMS: usize = 1000;
+
+/// Maximum length of a single item
+const MAX_ITEM_CHARS: usize = 256;
+
+/// Maximum integer population size for sampling from [min, max]
+const MAX_POPULATION: u64 = 100_000;
+
+/// Handle draw requests (GET and POST)
+pub async fn handle_draw_request(req: Request) -> anyhow::Result<Response> {
+    info!("🎲 Request to /api/draw endpoint");
+    match req.method() {
+        Method::Get => handle_signed_get_request(&req, generate_draw_signed),
+        Method::Post => handle_signed_post_request(&req, generate_draw_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Perform the draw and return SignedResponse
+fn generate_draw_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let seed_32 = match resolve_seed(params, GeneratorType::Draw) {
+        Ok(seed) => seed,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
+        .as_secs();
+
+    let payload = match generate_draw(params, seed_32, timestamp) {
+        Ok(payload) => payload,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    match create_signed_endpoint_response(payload, crypto_material) {
+        Ok(signed_response) => Ok(signed_response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+/// Draw from request parameters and seed
+pub fn generate_draw(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+    timestamp: u64,
+) -> Result<DrawResponse, String> {
+    // Parse mode parameter (integer: 0=range, 1=sample, 2=shuffle)
+    let mode = match params.get("mode") {
+        Some(mode_str) => mode_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| DrawMode::try_from(index).ok())
+            .ok_or_else(|| {
+                "Invalid mode parameter. Must be 0 (range), 1 (sample) or 2 (shuffle)".to_string()
+            })?,
+        None => DrawMode::Range,
+    };
+
+    let mut values = None;
+    let mut items = None;
+    let parameters = match mode {
+        DrawMode::Range => {
+            let (min, max) = parse_range(params)?;
+            let draws = parse_bounded(params, "draws", 1, MAX_DRAWS, 1)?;
+            values = Some(draw_range(seed_32, min, max, draws));
+            json!({ "mode": 0, "min": min, "max": max, "draws": draws })
+        }
+        DrawMode::Sample => match parse_items(params)? {
+            Some(population) => {
+                let k = parse_bounded(params, "k", 1, population.len().min(MAX_DRAWS), 1)?;
+                items = Some(sample(seed_32, &population, k));
+                json!({ "mode": 1, "k": k, "items": population })
+            }
+            None => {
+                let (min, max) = parse_range(params)?;
+                // The full i64 range holds 2^64 integers, which overflows u64
+                let size = match max.abs_diff(min).checked_add(1) {
+                    Some(size) if size <= MAX_POPULATION => size,
+                    _ => {
+                        return Err(format!(
+                            "Sample range too large. [min, max] may hold at most {} integers",
+                            MAX_POPULATION
+                        ));
+                    }
+                };
+                let k = parse_bounded(params, "k", 1, (size as usize).min(MAX_DRAWS), 1)?;
+                let population: Vec<i64> = (min..=max).collect();
+                values = Some(sample(seed_32, &population, k));
+                json!({ "mode": 1, "k": k, "min": min, "max": max })
+            }
+        },
+        DrawMode::Shuffle => {
+            let list = parse_items(params)?.ok_or("Missing items parameter for shuffle mode")?;
+            items = Some(shuffle(seed_32, &list));
+            json!({ "mode": 2, "items": list })
+        }
+    };
+
+    Ok(DrawResponse {
+        mode: mode.into(),
+        values,
+        items,
+        parameters,
+        seed: SeedEnvelope::new(seed_32, GeneratorType::Draw, None).encode(),
+        otp: generate_otp(seed_32),
+        timestamp,
+    })
+}
+
+/// Parse inclusive integer bounds (default 1-6, a die)
+fn parse_range(params: &HashMap<String, String>) -> Result<(i64, i64), String> {
+    let bound = |name: &str, default: i64| match params.get(name) {
+        Some(value) => value
+            .parse::<i64>()
+            .map_err(|_| format!("Invalid {} parameter. Must be a 64-bit integer", name)),
+        None => Ok(default),
+    };
+    let (min, max) = (bound("min", 1)?, bound("max", 6)?);
+    if min > max {
+        return Err("Invalid range. min must not be greater than max".to_string());
+    }
+    Ok((min, max))
+}
+
+/// Parse the optional item list: a JSON array of strings or one item per line
+fn parse_items(params: &HashMap<String, String>) -> Result<Option<Vec<String>>, String> {
+    let Some(items_str) = params.get("items") else {
+        return Ok(None);
+    };
+    let items: Vec<String> = serde_json::from_str(items_str).unwrap_or_else(|_| {
+        items_str
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    });
+
+    if items.is_empty() || items.len() > MAX_ITEMS {
+        return Err(format!(
+            "Invalid items parameter. Must hold 1-{} items",
+            MAX_ITEMS
+        ));
+    }
+    if items
+        .iter()
+        .any(|item| item.chars().count() > MAX_ITEM_CHARS)
+    {
+        return Err(format!(
+            "Invalid items parameter. Each item must be at most {} characters",
+            MAX_ITEM_CHARS
+        ));
+    }
+    Ok(Some(items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::params;
+
+    /// Turn echoed parameters back into request parameters
+    fn replay(response: &DrawResponse) -> HashMap<String, String> {
+        response
+            .parameters
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(k, v)| {
+                let value = match v {
+                    serde_json::Value::Array(_) => v.to_string(),
+                    _ => v.to_string().trim_matches('"').to_string(),
+                };
+                (k.clone(), value)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_default_is_single_die_roll() {
+        let response = generate_draw(&params(&[]), [1u8; 32], 0).unwrap();
+        assert_eq!(response.mode, 0);
+        let values = response.values.unwrap();
+        assert_eq!(values.len(), 1);
+        assert!((1..=6).contains(&values[0]));
+        assert_eq!(response.items, None);
+    }
+
+    #[test]
+    fn test_echoed_parameters_reproduce_draw() {
+        for request in [
+            params(&[("min", "-10"), ("max", "10"), ("draws", "20")]),
+            params(&[("mode", "1"), ("min", "1"), ("max", "49"), ("k", "6")]),
+            params(&[("mode", "1"), ("items", "red\ngreen\nblue"), ("k", "2")]),
+            params(&[("mode", "2"), ("items", r#"["a","b","c","d"]"#)]),
+        ] {
+            let original = generate_draw(&request, [2u8; 32], 0).unwrap();
+            let replayed = generate_draw(&replay(&original), [2u8; 32], 0).unwrap();
+            assert_eq!(original, replayed);
+        }
+    }
+
+    #[test]
+    fn test_lottery_and_shuffle_shapes() {
+        let lottery = generate_draw(
+            &params(&[("mode", "1"), ("min", "1"), ("max", "49"), ("k", "6")]),
+            [3u8; 32],
+            0,
+        )
+        .unwrap();
+        let mut numbers = lottery.values.unwrap();
+        numbers.sort();
+        numbers.dedup();
+        assert_eq!(numbers.len(), 6);
+
+        let shuffled = generate_draw(
+            &params(&[("mode", "2"), ("items", "x\ny\nz")]),
+            [3u8; 32],
+            0,
+        )
+        .unwrap();
+        let mut items = shuffled.items.unwrap();
+        items.sort();
+        assert_eq!(items, vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        for request in [
+            params(&[("mode", "3")]),
+            params(&[("min", "7"), ("max", "6")]),
+            params(&[("draws", "0")]),
+            params(&[("max", "abc")]),
+            params(&[("mode", "1"), ("min", "1"), ("max", "5"), ("k", "6")]),
+            params(&[("mode", "1"), ("min", "0"), ("max", "100000")]),
+            params(&[
+                ("mode", "1"),
+                ("min", "-9223372036854775808"),
+                ("max", "9223372036854775807"),
+            ]),
+            params(&[("mode", "2")]),
+            params(&[("mode", "2"), ("items", "[]")]),
+        ] {
+            assert!(generate_draw(&request, [0u8; 32], 0).is_err());
+        }
+    }
+}
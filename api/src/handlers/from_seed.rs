@@ -0,0 +1,244 @@
+//! Seed reproduction endpoint with SignedResponse
+//!
+//! Regenerates the exact output of a generator endpoint from its recorded
+//! seed and parameters, so generated credentials can be audited:
+//! - POST only with a mandatory `seed` (never generated here)
+//! - Seed envelopes supply the generator and algorithm; bare seeds need `generator`
+//! - Same generator functions and single/batch pipeline as the original endpoints
+//! - Same OTP (derived from the seed); only the timestamp is new
+
+use crate::types::GeneratorType;
+use crate::utils::generation::{
+    GeneratorFn, create_generation_signed_response, generator_mismatch,
+};
+use crate::utils::protected_endpoint::{extract_seed_from_payload, payload_to_params};
+use crate::utils::seed_envelope::unwrap_seed_envelope;
+use crate::utils::{
+    ProtectedEndpointMiddleware, ProtectedEndpointResult, create_auth_error_response,
+    create_client_error_response, extract_crypto_material_from_request, seed_to_base58,
+};
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use tracing::info;
+
+use super::api_key::generate_api_key_value;
+use super::custom::generate_custom_hash_value;
+use super::identifier::generate_identifier_value;
+use super::mnemonic::generate_mnemonic_value;
+use super::passphrase::generate_passphrase_value;
+use super::password::generate_password_value;
+use super::pronounceable::generate_pronounceable_value;
+
+/// Main request handler for /api/from-seed endpoint
+pub async fn handle_from_seed_request(req: Request) -> anyhow::Result<Response> {
+    info!("🔁 Request to /api/from-seed endpoint");
+    match req.method() {
+        Method::Post => handle_from_seed_post_signed(req).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Handle POST requests with signed request validation (seed is mandatory)
+async fn handle_from_seed_post_signed(req: Request) -> anyhow::Result<Response> {
+    // Validate signed request using protected middleware
+    let result: ProtectedEndpointResult<serde_json::Value> =
+        match ProtectedEndpointMiddleware::validate_request(&req, req.body()).await {
+            Ok(result) => result,
+            Err(error_response) => return Ok(error_response),
+        };
+
+    // Extract crypto material for signing response
+    let crypto_material = match extract_crypto_material_from_request(&req) {
+        Ok(material) => material,
+        Err(e) => {
+            return Ok(create_auth_error_response(&format!(
+                "Crypto extraction failed: {}",
+                e
+            )));
+        }
+    };
+
+    // Reproduction needs the recorded seed: never fall back to a fresh one
+    let mut params = payload_to_params(&result.payload);
+    let recorded_generator = match unwrap_seed_envelope(&mut params) {
+        Ok(recorded) => recorded,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+    let seed = match extract_seed_from_payload(&result.payload) {
+        Ok(Some(seed)) => seed,
+        Ok(None) => return Ok(create_client_error_response("Missing seed parameter")),
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+    params.insert("seed".to_string(), seed_to_base58(&seed));
+
+    let generator_type = match resolve_generator_type(&params, recorded_generator) {
+        Ok(generator_type) => generator_type,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+    let generator = match generator_fn(generator_type) {
+        Ok(generator) => generator,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+    info!("🔁 Reproducing {} output", generator_type.endpoint());
+
+    create_generation_signed_response(&params, &crypto_material, generator_type, generator)
+}
+
+/// Generator from the seed envelope, or the `generator` parameter for bare seeds
+///
+/// Both may be given as long as they agree.
+pub fn resolve_generator_type(
+    params: &HashMap<String, String>,
+    recorded: Option<GeneratorType>,
+) -> Result<GeneratorType, String> {
+    match (recorded, params.contains_key("generator")) {
+        (Some(recorded), false) => Ok(recorded),
+        (Some(recorded), true) => {
+            let requested = parse_generator_type(params)?;
+            if requested == recorded {
+                Ok(recorded)
+            } else {
+                Err(format!(
+                    "Parameter generator={} conflicts with generator {} recorded in the seed",
+                    u8::from(requested),
+                    u8::from(recorded)
+                ))
+            }
+        }
+        (None, _) => parse_generator_type(params),
+    }
+}
+
+/// Parse the mandatory `generator` parameter
+pub fn parse_generator_type(params: &HashMap<String, String>) -> Result<GeneratorType, String> {
+    const VALID: &str = "Must be integer 0-6 (0=custom, 1=password, 2=api-key, 3=mnemonic, 4=passphrase, 5=identifier, 6=pronounceable)";
+    let generator_str = params
+        .get("generator")
+        .ok_or_else(|| format!("Missing generator parameter. {}", VALID))?;
+    generator_str
+        .parse::<u8>()
+        .ok()
+        .and_then(|index| GeneratorType::try_from(index).ok())
+        .filter(GeneratorType::reproducible_from_seed)
+        .ok_or_else(|| format!("Invalid generator parameter. {}", VALID))
+}
+
+/// Generator function used by the original endpoint
+///
+/// Seeds from endpoints outside the generation pipeline (OTP, keypair,
+/// symmetric key, draw, SLIP-39) reproduce only at their own endpoint.
+pub fn generator_fn(generator_type: GeneratorType) -> Result<GeneratorFn, String> {
+    match generator_type {
+        GeneratorType::Custom => Ok(generate_custom_hash_value),
+        GeneratorType::Password => Ok(generate_password_value),
+        GeneratorType::ApiKey => Ok(generate_api_key_value),
+        GeneratorType::Mnemonic => Ok(generate_mnemonic_value),
+        GeneratorType::Passphrase => Ok(generate_passphrase_value),
+        GeneratorType::Identifier => Ok(generate_identifier_value),
+        GeneratorType::Pronounceable => Ok(generate_pronounceable_value),
+        GeneratorType::Otp
+        | GeneratorType::Keypair
+        | GeneratorType::SymmetricKey
+        | GeneratorType::Draw
+        | GeneratorType::Slip39 => Err(generator_mismatch(generator_type)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GenerationAlgorithm;
+    use crate::utils::base58_to_seed;
+    use crate::utils::generation::generate_from_params;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_parse_generator_type() {
+        assert_eq!(
+            parse_generator_type(&params(&[("generator", "2")])).unwrap(),
+            GeneratorType::ApiKey
+        );
+        assert!(parse_generator_type(&params(&[])).is_err());
+        assert!(parse_generator_type(&params(&[("generator", "9")])).is_err());
+        assert!(parse_generator_type(&params(&[("generator", "custom")])).is_err());
+    }
+
+    #[test]
+    fn test_resolve_generator_type_from_envelope() {
+        let recorded = Some(GeneratorType::Passphrase);
+        assert_eq!(
+            resolve_generator_type(&params(&[]), recorded).unwrap(),
+            GeneratorType::Passphrase
+        );
+        assert_eq!(
+            resolve_generator_type(&params(&[("generator", "4")]), recorded).unwrap(),
+            GeneratorType::Passphrase
+        );
+        assert!(resolve_generator_type(&params(&[("generator", "1")]), recorded).is_err());
+        assert!(resolve_generator_type(&params(&[]), None).is_err());
+    }
+
+    #[test]
+    fn test_reproduces_original_generators() {
+        let seed = [42u8; 32];
+        let request = params(&[("generator", "3"), ("words", "24")]);
+        assert_eq!(
+            generator_fn(GeneratorType::Mnemonic).unwrap()(&request, seed).unwrap(),
+            generate_mnemonic_value(&request, seed).unwrap()
+        );
+
+        let request = params(&[("generator", "0"), ("length", "30"), ("alphabet", "4")]);
+        let value = generator_fn(GeneratorType::Custom).unwrap()(&request, seed).unwrap();
+        assert_eq!(value, generate_custom_hash_value(&request, seed).unwrap());
+        assert_eq!(value.value.len(), 30);
+    }
+
+    #[test]
+    fn test_bare_seed_reproduces_pre_versioning_output() {
+        // Captured from the endpoints before algorithm versioning, seed = [42u8; 32]
+        let bare = "3qbR1eZRqXUWroWKKYhbDmR3FfqTHfqSU8zZSxtANzYh";
+        let seed = base58_to_seed(bare).unwrap();
+
+        let request = params(&[("seed", bare), ("length", "21")]);
+        let password = generator_fn(GeneratorType::Password).unwrap()(&request, seed).unwrap();
+        assert_eq!(password.value, "6LrWIl-aeL1h@EkXJY47R");
+        assert_eq!(password.algorithm, Some(GenerationAlgorithm::ModuloV1));
+
+        let request = params(&[("seed", bare), ("length", "21"), ("alphabet", "0")]);
+        let custom = generator_fn(GeneratorType::Custom).unwrap()(&request, seed).unwrap();
+        assert_eq!(custom.value, "9XUTsh4uHUe8x873EcsBt");
+    }
+
+    #[test]
+    fn test_fresh_requests_use_latest_algorithm() {
+        // No seed and no algorithm: the generator draws a fresh seed (never a bare
+        // caller seed), so the unbiased algorithm applies
+        for generator_type in [
+            GeneratorType::Custom,
+            GeneratorType::Password,
+            GeneratorType::ApiKey,
+        ] {
+            let generator = generator_fn(generator_type).unwrap();
+            let (_, values) =
+                generate_from_params(&params(&[]), generator_type, generator).unwrap();
+            assert_eq!(values[0].algorithm, Some(GenerationAlgorithm::RejectionV2));
+        }
+
+        let generator = generator_fn(GeneratorType::Password).unwrap();
+        let request = params(&[("policy", "strict")]);
+        let (_, values) =
+            generate_from_params(&request, GeneratorType::Password, generator).unwrap();
+        assert_eq!(values[0].algorithm, Some(GenerationAlgorithm::RejectionV2));
+
+        // Bare policy seeds reproduce without an explicit algorithm
+        let bare = seed_to_base58(&[42u8; 32]);
+        let request = params(&[("policy", "strict"), ("seed", &bare)]);
+        assert!(generate_from_params(&request, GeneratorType::Password, generator).is_ok());
+        let request = params(&[("policy", "strict"), ("algorithm", "1")]);
+        assert!(generate_from_params(&request, GeneratorType::Password, generator).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+//! Standard identifier endpoint with SignedResponse
+//!
+//! Provides GET and POST endpoints for RFC 9562 UUIDv4/v7, ULID and KSUID with:
+//! - JWT authentication and Ed25519 signature validation
+//! - Seeded random components (reproducible from seed + time_ms)
+//! - Request time embedded in time-ordered formats and reported back
+
+use crate::types::{GeneratorType, IdentifierFormat};
+use crate::utils::generation::{GeneratedValue, create_generation_signed_response};
+use crate::utils::identifier::generate_identifier;
+use crate::utils::{handle_signed_get_request, handle_signed_post_request};
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+/// Handle identifier requests (GET and POST)
+pub async fn handle_identifier_request(req: Request) -> anyhow::Result<Response> {
+    info!("🆔 Request to /api/identifier endpoint");
+    match req.method() {
+        Method::Get => handle_signed_get_request(&req, generate_identifier_signed),
+        Method::Post => handle_signed_post_request(&req, generate_identifier_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Generate identifier(s) and return SignedResponse (DRY implementation)
+///
+/// Fixes `time_ms` to the request time when not provided, so every item of a
+/// batch shares it and the response can report it for reproduction.
+fn generate_identifier_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let mut params = params.clone();
+    if !params.contains_key("time_ms") {
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
+            .as_millis();
+        params.insert("time_ms".to_string(), now_ms.to_string());
+    }
+
+    create_generation_signed_response(
+        &params,
+        crypto_material,
+        GeneratorType::Identifier,
+        generate_identifier_value,
+    )
+}
+
+/// Generate a single identifier from request parameters and seed
+pub fn generate_identifier_value(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
+    // Parse format parameter (integer: 0=uuid-v4, 1=uuid-v7, 2=ulid, 3=ksuid)
+    let format = match params.get("format") {
+        Some(format_str) => format_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| IdentifierFormat::try_from(index).ok())
+            .ok_or_else(|| {
+                "Invalid format parameter. Must be integer 0-3 (0=uuid-v4, 1=uuid-v7, 2=ulid, 3=ksuid)"
+                    .to_string()
+            })?,
+        None => IdentifierFormat::UuidV4, // Default
+    };
+
+    let time_ms = match params.get("time_ms") {
+        Some(time_str) => time_str.parse::<u64>().map_err(|_| {
+            "Invalid time_ms parameter. Must be milliseconds since Unix epoch".to_string()
+        })?,
+        None => 0,
+    };
+
+    let identifier = generate_identifier(format, seed_32, time_ms)?;
+    let value = GeneratedValue::new(identifier).with_entropy_bits(format.random_bits() as f64);
+
+    Ok(if format.is_time_ordered() {
+        value.with_time_ms(time_ms)
+    } else {
+        value
+    })
+}
@@ -1,9 +1,18 @@
 pub mod api_key;
 pub mod custom;
+pub mod draw;
+pub mod from_seed;
+pub mod identifier;
+pub mod keypair;
 pub mod login;
 pub mod mnemonic;
+pub mod otp;
+pub mod passphrase;
 pub mod password;
+pub mod pronounceable;
 pub mod shared_secret;
+pub mod slip39;
+pub mod symmetric_key;
 pub mod user_keys;
 pub mod version;
 
@@ -11,13 +20,23 @@ pub mod version;
 #[cfg(feature = "dev-mode")]
 pub mod test;
 
-pub use api_key::handle_api_key_request;
+pub use api_key::{handle_api_key_request, handle_api_key_verify_request};
+pub use draw::handle_draw_request;
+pub use from_seed::handle_from_seed_request;
+pub use identifier::handle_identifier_request;
+pub use keypair::handle_keypair_request;
 pub use login::handle_login;
-pub use mnemonic::handle_mnemonic_request;
+pub use mnemonic::{handle_mnemonic_request, handle_mnemonic_validate_request};
+pub use otp::{handle_otp_request, handle_otp_verify_request};
+pub use passphrase::handle_passphrase_request;
 pub use password::handle_password_request;
+pub use pronounceable::handle_pronounceable_request;
 pub use shared_secret::{
-    handle_confirm_read, handle_create_secret, handle_delete_secret, handle_retrieve_secret,
+    handle_confirm_read, handle_create_request, handle_create_secret, handle_delete_secret,
+    handle_download_attachment, handle_fulfill_request, handle_get_request, handle_retrieve_secret,
 };
+pub use slip39::{handle_slip39_combine_request, handle_slip39_split_request};
+pub use symmetric_key::handle_symmetric_key_request;
 pub use user_keys::{handle_keys_request, handle_user_keys_request};
 pub use version::handle_version;
 
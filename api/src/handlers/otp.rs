@@ -0,0 +1,462 @@
+//! TOTP/HOTP secret provisioning endpoints with SignedResponse
+//!
+//! Provides secret generation (GET and POST) and code verification (POST) with:
+//! - JWT authentication and Ed25519 signature validation
+//! - Base32 secrets drawn through the seeded generator (reproducible from seed)
+//! - `otpauth://` provisioning URI and SVG QR code
+//! - Drift-window verification (TOTP both ways, HOTP look-ahead)
+
+use crate::types::{
+    GenerationAlgorithm, GeneratorType, OtpAlgorithm, OtpSecretResponse, OtpType,
+    OtpVerifyResponse, StrengthLevel,
+};
+use crate::utils::generation::resolve_seed;
+use crate::utils::otp::{
+    BASE32_ALPHABET, base32_decode, find_matching_counter, otpauth_uri, totp_counter,
+};
+use crate::utils::qr::qr_svg;
+use crate::utils::seed_envelope::SeedEnvelope;
+use crate::utils::{
+    create_client_error_response, create_server_error_response, create_signed_endpoint_response,
+    generate_otp, generate_with_seed, handle_signed_get_request, handle_signed_post_request,
+};
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+/// Secret length bounds in base32 characters (5 bits each, multiples of 8)
+const MIN_SECRET_CHARS: usize = 16;
+const MAX_SECRET_CHARS: usize = 128;
+/// Default secret length: 160 bits, as recommended by RFC 4226
+const DEFAULT_SECRET_CHARS: usize = 32;
+/// TOTP period bounds in seconds
+const MIN_PERIOD: u64 = 15;
+const MAX_PERIOD: u64 = 300;
+/// Maximum verification window in steps
+const MAX_WINDOW: u64 = 10;
+/// Maximum issuer/account label length
+const MAX_LABEL_CHARS: usize = 128;
+
+/// Handle OTP secret requests (GET and POST)
+pub async fn handle_otp_request(req: Request) -> anyhow::Result<Response> {
+    info!("⏱️ Request to /api/otp endpoint");
+    match req.method() {
+        Method::Get => handle_signed_get_request(&req, generate_otp_secret_signed),
+        Method::Post => handle_signed_post_request(&req, generate_otp_secret_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Handle OTP verification requests (POST only, secrets never travel in query strings)
+pub async fn handle_otp_verify_request(req: Request) -> anyhow::Result<Response> {
+    info!("⏱️ Request to /api/otp/verify endpoint");
+    match req.method() {
+        Method::Post => handle_signed_post_request(&req, verify_otp_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Generate an OTP secret with its provisioning URI and return SignedResponse
+fn generate_otp_secret_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let seed_32 = match resolve_seed(params, GeneratorType::Otp) {
+        Ok(seed) => seed,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
+        .as_secs();
+
+    let payload = match generate_otp_secret(params, seed_32, timestamp) {
+        Ok(payload) => payload,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    match create_signed_endpoint_response(payload, crypto_material) {
+        Ok(signed_response) => Ok(signed_response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+/// Verify a submitted code and return SignedResponse
+fn verify_otp_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
+        .as_secs();
+
+    let payload = match verify_otp_code(params, now) {
+        Ok(payload) => payload,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    match create_signed_endpoint_response(payload, crypto_material) {
+        Ok(signed_response) => Ok(signed_response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+/// Generate a secret and provisioning data from request parameters and seed
+pub fn generate_otp_secret(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+    timestamp: u64,
+) -> Result<OtpSecretResponse, String> {
+    let settings = OtpSettings::parse(params)?;
+
+    let secret_chars = match params.get("secret_length") {
+        Some(length_str) => match length_str.parse::<usize>() {
+            Ok(length)
+                if (MIN_SECRET_CHARS..=MAX_SECRET_CHARS).contains(&length)
+                    && length.is_multiple_of(8) =>
+            {
+                length
+            }
+            _ => {
+                return Err(format!(
+                    "Invalid secret_length parameter. Must be a multiple of 8 between {} and {} base32 characters",
+                    MIN_SECRET_CHARS, MAX_SECRET_CHARS
+                ));
+            }
+        },
+        None => DEFAULT_SECRET_CHARS,
+    };
+
+    let account = match params.get("account") {
+        Some(account) if !account.trim().is_empty() => account.trim(),
+        _ => return Err("Missing account parameter".to_string()),
+    };
+    let issuer = params.get("issuer").map(|s| s.trim()).unwrap_or("");
+    for (name, value) in [("account", account), ("issuer", issuer)] {
+        if value.chars().count() > MAX_LABEL_CHARS || value.contains(':') {
+            return Err(format!(
+                "Invalid {} parameter. Must be at most {} characters without ':'",
+                name, MAX_LABEL_CHARS
+            ));
+        }
+    }
+
+    let secret = generate_with_seed(
+        seed_32,
+        secret_chars,
+        &BASE32_ALPHABET,
+        GenerationAlgorithm::RejectionV2,
+    );
+
+    let moving_factor = match settings.otp_type {
+        OtpType::Totp => settings.period,
+        OtpType::Hotp => settings.counter,
+    };
+    let uri = otpauth_uri(
+        settings.otp_type,
+        &secret,
+        issuer,
+        account,
+        settings.algorithm,
+        settings.digits,
+        moving_factor,
+    );
+    let qr_svg = qr_svg(&uri)?;
+
+    let entropy_bits = (secret_chars * 5) as f64;
+
+    Ok(OtpSecretResponse {
+        secret,
+        uri,
+        qr_svg,
+        otp_type: settings.otp_type.into(),
+        algorithm: settings.algorithm.into(),
+        digits: settings.digits,
+        period: (settings.otp_type == OtpType::Totp).then_some(settings.period),
+        counter: (settings.otp_type == OtpType::Hotp).then_some(settings.counter),
+        entropy_bits,
+        strength: StrengthLevel::from_entropy_bits(entropy_bits),
+        seed: SeedEnvelope::new(
+            seed_32,
+            GeneratorType::Otp,
+            Some(GenerationAlgorithm::RejectionV2),
+        )
+        .encode(),
+        otp: generate_otp(seed_32),
+        timestamp,
+    })
+}
+
+/// Check a submitted code against a secret at Unix time `now`
+pub fn verify_otp_code(
+    params: &HashMap<String, String>,
+    now: u64,
+) -> Result<OtpVerifyResponse, String> {
+    let settings = OtpSettings::parse(params)?;
+
+    let secret = match params.get("secret") {
+        Some(secret) if !secret.trim().is_empty() => base32_decode(secret)?,
+        _ => return Err("Missing secret parameter".to_string()),
+    };
+    if secret.len() < MIN_SECRET_CHARS * 5 / 8 {
+        return Err("Secret too short: at least 80 bits required".to_string());
+    }
+
+    let code = match params.get("code") {
+        Some(code) if code.len() == settings.digits as usize => code,
+        _ => {
+            return Err(format!(
+                "Missing or invalid code parameter. Must be {} digits",
+                settings.digits
+            ));
+        }
+    };
+
+    let window = match params.get("window") {
+        Some(window_str) => match window_str.parse::<u64>() {
+            Ok(window) if window <= MAX_WINDOW => window,
+            _ => {
+                return Err(format!(
+                    "Invalid window parameter. Must be integer 0-{}",
+                    MAX_WINDOW
+                ));
+            }
+        },
+        None => 1,
+    };
+
+    Ok(match settings.otp_type {
+        OtpType::Totp => {
+            let current = totp_counter(now, settings.period);
+            let candidates = current.saturating_sub(window)..=current.saturating_add(window);
+            let matched = find_matching_counter(
+                &secret,
+                code,
+                settings.digits,
+                settings.algorithm,
+                candidates,
+            );
+            OtpVerifyResponse {
+                valid: matched.is_some(),
+                drift: matched.map(|step| step as i64 - current as i64),
+                counter: None,
+            }
+        }
+        OtpType::Hotp => {
+            // RFC 4226 look-ahead: counters only move forward
+            let candidates = settings.counter..=settings.counter.saturating_add(window);
+            let matched = find_matching_counter(
+                &secret,
+                code,
+                settings.digits,
+                settings.algorithm,
+                candidates,
+            );
+            OtpVerifyResponse {
+                valid: matched.is_some(),
+                drift: None,
+                counter: matched,
+            }
+        }
+    })
+}
+
+/// Parameters shared by generation and verification
+struct OtpSettings {
+    otp_type: OtpType,
+    algorithm: OtpAlgorithm,
+    digits: u32,
+    period: u64,
+    counter: u64,
+}
+
+impl OtpSettings {
+    fn parse(params: &HashMap<String, String>) -> Result<Self, String> {
+        // Parse type parameter (integer: 0=totp, 1=hotp)
+        let otp_type = match params.get("type") {
+            Some(type_str) => type_str
+                .parse::<u8>()
+                .ok()
+                .and_then(|index| OtpType::try_from(index).ok())
+                .ok_or_else(|| {
+                    "Invalid type parameter. Must be 0 (totp) or 1 (hotp)".to_string()
+                })?,
+            None => OtpType::Totp,
+        };
+
+        // Parse algorithm parameter (integer: 0=sha1, 1=sha256, 2=sha512)
+        let algorithm = match params.get("algorithm") {
+            Some(algorithm_str) => algorithm_str
+                .parse::<u8>()
+                .ok()
+                .and_then(|index| OtpAlgorithm::try_from(index).ok())
+                .ok_or_else(|| {
+                    "Invalid algorithm parameter. Must be integer 0-2 (0=sha1, 1=sha256, 2=sha512)"
+                        .to_string()
+                })?,
+            None => OtpAlgorithm::Sha1,
+        };
+
+        let digits = match params.get("digits") {
+            Some(digits_str) => match digits_str.parse::<u32>() {
+                Ok(digits) if (6..=8).contains(&digits) => digits,
+                _ => return Err("Invalid digits parameter. Must be 6, 7 or 8".to_string()),
+            },
+            None => 6,
+        };
+
+        let period = match params.get("period") {
+            Some(period_str) => match period_str.parse::<u64>() {
+                Ok(period) if (MIN_PERIOD..=MAX_PERIOD).contains(&period) => period,
+                _ => {
+                    return Err(format!(
+                        "Invalid period parameter. Must be {}-{} seconds",
+                        MIN_PERIOD, MAX_PERIOD
+                    ));
+                }
+            },
+            None => 30,
+        };
+
+        let counter = match params.get("counter") {
+            Some(counter_str) => counter_str
+                .parse::<u64>()
+                .map_err(|_| "Invalid counter parameter. Must be a non-negative integer")?,
+            None => 0,
+        };
+
+        Ok(Self {
+            otp_type,
+            algorithm,
+            digits,
+            period,
+            counter,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::otp::hotp;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_generate_totp_secret() {
+        let response = generate_otp_secret(
+            &params(&[("account", "ops@example.com"), ("issuer", "ACME")]),
+            [7u8; 32],
+            0,
+        )
+        .unwrap();
+        assert_eq!(response.secret.len(), 32);
+        assert!(
+            response
+                .secret
+                .chars()
+                .all(|c| BASE32_ALPHABET.contains(&c))
+        );
+        assert_eq!(response.entropy_bits, 160.0);
+        assert_eq!(response.period, Some(30));
+        assert_eq!(response.counter, None);
+        assert!(
+            response
+                .uri
+                .starts_with("otpauth://totp/ACME:ops%40example.com?secret=")
+        );
+        assert!(response.qr_svg.starts_with("<svg"));
+
+        // Same seed, same secret
+        let again = generate_otp_secret(&params(&[("account", "other")]), [7u8; 32], 0).unwrap();
+        assert_eq!(again.secret, response.secret);
+    }
+
+    #[test]
+    fn test_generate_rejects_invalid_parameters() {
+        let seed = [0u8; 32];
+        assert!(generate_otp_secret(&params(&[]), seed, 0).is_err());
+        for (key, value) in [
+            ("secret_length", "20"),
+            ("digits", "9"),
+            ("period", "5"),
+            ("algorithm", "3"),
+            ("issuer", "a:b"),
+        ] {
+            assert!(
+                generate_otp_secret(&params(&[("account", "x"), (key, value)]), seed, 0).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify_totp_with_drift() {
+        // RFC 6238 SHA1 secret, 8 digits
+        let secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+        let request = params(&[("secret", secret), ("code", "94287082"), ("digits", "8")]);
+
+        let exact = verify_otp_code(&request, 59).unwrap();
+        assert!(exact.valid);
+        assert_eq!(exact.drift, Some(0));
+
+        // One step later still accepted with the default window of 1
+        let late = verify_otp_code(&request, 89).unwrap();
+        assert_eq!(late.drift, Some(-1));
+
+        assert!(!verify_otp_code(&request, 150).unwrap().valid);
+        let mut strict = request.clone();
+        strict.insert("window".to_string(), "0".to_string());
+        assert!(!verify_otp_code(&strict, 89).unwrap().valid);
+    }
+
+    #[test]
+    fn test_verify_hotp_look_ahead() {
+        let secret = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+        let code = hotp(b"12345678901234567890", 3, 6, OtpAlgorithm::Sha1);
+        let request = params(&[
+            ("type", "1"),
+            ("secret", secret),
+            ("code", &code),
+            ("counter", "2"),
+            ("window", "2"),
+        ]);
+        let response = verify_otp_code(&request, 0).unwrap();
+        assert!(response.valid);
+        assert_eq!(response.counter, Some(3));
+
+        let mut behind = request.clone();
+        behind.insert("counter".to_string(), "4".to_string());
+        assert!(!verify_otp_code(&behind, 0).unwrap().valid);
+    }
+
+    #[test]
+    fn test_verify_rejects_bad_input() {
+        assert!(verify_otp_code(&params(&[("code", "123456")]), 0).is_err());
+        assert!(
+            verify_otp_code(&params(&[("secret", "GEZDGNBV"), ("code", "123456")]), 0).is_err()
+        );
+        assert!(
+            verify_otp_code(
+                &params(&[("secret", "GEZDGNBVGY3TQOJQ"), ("code", "12345")]),
+                0
+            )
+            .is_err()
+        );
+    }
+}
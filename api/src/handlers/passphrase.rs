@@ -0,0 +1,143 @@
+//! Passphrase generation endpoint with SignedResponse
+//!
+//! Provides GET and POST endpoints for diceware-style passphrases with:
+//! - JWT authentication and Ed25519 signature validation
+//! - Words drawn from the embedded BIP39 word lists (all 10 languages)
+//! - Entropy in bits reported alongside the phrase
+
+use crate::types::{
+    GeneratorType, MnemonicLanguage, PassphraseCapitalization, PassphraseInsertion,
+};
+use crate::utils::generation::{GeneratedValue, create_generation_signed_response};
+use crate::utils::passphrase::{PassphraseOptions, generate_passphrase};
+use crate::utils::{handle_signed_get_request, handle_signed_post_request};
+use bip39::Language;
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use tracing::info;
+
+/// Minimum number of words (44 bits with 2048-word lists)
+const MIN_WORDS: usize = 4;
+/// Maximum number of words
+const MAX_WORDS: usize = 20;
+/// Maximum separator length in characters
+const MAX_SEPARATOR_CHARS: usize = 3;
+
+/// Handle passphrase requests (GET and POST)
+pub async fn handle_passphrase_request(req: Request) -> anyhow::Result<Response> {
+    info!("🗣️ Request to /api/passphrase endpoint");
+    match req.method() {
+        Method::Get => handle_signed_get_request(&req, generate_passphrase_signed),
+        Method::Post => handle_signed_post_request(&req, generate_passphrase_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Generate passphrase(s) and return SignedResponse (DRY implementation)
+fn generate_passphrase_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    create_generation_signed_response(
+        params,
+        crypto_material,
+        GeneratorType::Passphrase,
+        generate_passphrase_value,
+    )
+}
+
+/// Generate a single passphrase from request parameters and seed
+pub fn generate_passphrase_value(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
+    // Parse language parameter (integer: 0=english, 1=spanish, ..., 9=czech)
+    let language = match params.get("language") {
+        Some(lang_str) => match lang_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| MnemonicLanguage::try_from(index).ok())
+        {
+            Some(mnemonic_lang) => Language::from(mnemonic_lang),
+            None => {
+                return Err("Invalid language parameter. Must be integer 0-9 (0=english, 1=spanish, 2=french, 3=portuguese, 4=japanese, 5=chinese-simplified, 6=chinese-traditional, 7=italian, 8=korean, 9=czech)".to_string());
+            }
+        },
+        None => Language::English, // Default
+    };
+
+    // Parse words parameter
+    // Default 6 words: 66 bits of entropy with 2048-word lists
+    let words = match params.get("words") {
+        Some(words_str) => match words_str.parse::<usize>() {
+            Ok(n) if (MIN_WORDS..=MAX_WORDS).contains(&n) => n,
+            _ => {
+                return Err(format!(
+                    "Invalid words parameter. Must be integer {}-{}",
+                    MIN_WORDS, MAX_WORDS
+                ));
+            }
+        },
+        None => 6,
+    };
+
+    // Parse separator (default "-", may be empty)
+    let separator = params
+        .get("separator")
+        .cloned()
+        .unwrap_or_else(|| "-".to_string());
+    if separator.chars().count() > MAX_SEPARATOR_CHARS || separator.chars().any(char::is_control) {
+        return Err(format!(
+            "Separator must be at most {} printable characters",
+            MAX_SEPARATOR_CHARS
+        ));
+    }
+
+    // Parse capitalization (integer 0-3)
+    let capitalization = match params.get("capitalization") {
+        Some(value) => match value
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| PassphraseCapitalization::try_from(index).ok())
+        {
+            Some(capitalization) => capitalization,
+            None => {
+                return Err("Invalid capitalization parameter. Must be integer 0-3 (0=lowercase, 1=title, 2=uppercase, 3=random)".to_string());
+            }
+        },
+        None => PassphraseCapitalization::Lowercase,
+    };
+
+    // Parse insertion (integer 0-3)
+    let insertion = match params.get("insert") {
+        Some(value) => match value
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| PassphraseInsertion::try_from(index).ok())
+        {
+            Some(insertion) => insertion,
+            None => {
+                return Err("Invalid insert parameter. Must be integer 0-3 (0=none, 1=digit, 2=symbol, 3=digit+symbol)".to_string());
+            }
+        },
+        None => PassphraseInsertion::None,
+    };
+
+    // Generate passphrase using seeded generator
+    let passphrase = generate_passphrase(
+        seed_32,
+        &PassphraseOptions {
+            language,
+            words,
+            separator,
+            capitalization,
+            insertion,
+        },
+    );
+
+    Ok(GeneratedValue::new(passphrase.phrase).with_entropy_bits(passphrase.entropy_bits))
+}
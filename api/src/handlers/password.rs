@@ -5,17 +5,19 @@
 //! - SignedResponse for all outputs (enterprise security)
 //! - SOLID/DRY/KISS architecture with <225 lines
 
-use crate::types::{AlphabetType, CustomHashResponse};
+use crate::types::{AlphabetType, GenerationAlgorithm, GeneratorType, PasswordPolicy};
+use crate::utils::entropy::string_entropy_bits;
+use crate::utils::generation::{GeneratedValue, create_generation_signed_response};
+use crate::utils::password_policy::policy_password_entropy_bits;
 use crate::utils::protected_endpoint::{extract_seed_from_payload, payload_to_params};
 use crate::utils::{
     ProtectedEndpointMiddleware, ProtectedEndpointResult, create_error_response,
-    create_signed_endpoint_response, extract_crypto_material_from_request, generate_otp,
-    generate_password_avoiding_patterns, generate_random_seed, handle_signed_get_request,
-    seed_to_base58, validate_length,
+    extract_crypto_material_from_request, generate_password_avoiding_patterns,
+    generate_password_with_policy, handle_signed_get_request, parse_generation_algorithm,
+    validate_length,
 };
 use spin_sdk::http::{Method, Request, Response};
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::info;
 
 /// Main request handler for /api/password endpoint
@@ -59,28 +61,35 @@ pub async fn handle_password_post_signed(req: Request) -> anyhow::Result<Respons
         }
     };
 
-    // Convert payload to parameters and extract seed
-    let mut params = payload_to_params(&result.payload);
-    let provided_seed = match extract_seed_from_payload(&result.payload) {
-        Ok(seed) => seed,
-        Err(e) => return Ok(create_error_response(400, &e)),
-    };
-
-    // Use provided seed or generate fresh one
-    if provided_seed.is_none() && !params.contains_key("seed") {
-        let seed = generate_random_seed();
-        params.insert("seed".to_string(), seed_to_base58(&seed));
+    // Convert payload to parameters and validate a caller-supplied seed
+    // (a missing seed stays missing: the generator draws a fresh one)
+    let params = payload_to_params(&result.payload);
+    if let Err(e) = extract_seed_from_payload(&result.payload) {
+        return Ok(create_error_response(400, &e));
     }
 
     // Generate password and create signed response
     generate_password_signed(&params, &crypto_material)
 }
 
-/// Generate secure password and return SignedResponse (DRY implementation)
+/// Generate secure password(s) and return SignedResponse (DRY implementation)
 fn generate_password_signed(
     params: &HashMap<String, String>,
     crypto_material: &crate::utils::CryptoMaterial,
 ) -> anyhow::Result<Response> {
+    create_generation_signed_response(
+        params,
+        crypto_material,
+        GeneratorType::Password,
+        generate_password_value,
+    )
+}
+
+/// Generate a single password from request parameters and seed
+pub fn generate_password_value(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
     // Parse parameters with password-specific defaults
     // Default length 32: Industry standard for secure passwords (256 bits of entropy)
     // Equivalent to AES-256 key strength with FullWithSymbols alphabet
@@ -99,25 +108,16 @@ fn generate_password_signed(
                     match alphabet {
                         AlphabetType::NoLookAlike | AlphabetType::FullWithSymbols => alphabet,
                         _ => {
-                            return Ok(create_error_response(
-                                400,
-                                "Password alphabet must be 1 (no-look-alike) or 3 (full-with-symbols)",
-                            ));
+                            return Err("Password alphabet must be 1 (no-look-alike) or 3 (full-with-symbols)".to_string());
                         }
                     }
                 }
                 Err(_) => {
-                    return Ok(create_error_response(
-                        400,
-                        "Invalid alphabet index. Valid range for passwords: 1 or 3 (1=no-look-alike, 3=full-with-symbols)",
-                    ));
+                    return Err("Invalid alphabet index. Valid range for passwords: 1 or 3 (1=no-look-alike, 3=full-with-symbols)".to_string());
                 }
             },
             Err(_) => {
-                return Ok(create_error_response(
-                    400,
-                    "Invalid alphabet parameter. Must be integer 1 or 3 (1=no-look-alike, 3=full-with-symbols)",
-                ));
+                return Err("Invalid alphabet parameter. Must be integer 1 or 3 (1=no-look-alike, 3=full-with-symbols)".to_string());
             }
         }
     } else {
@@ -126,41 +126,40 @@ fn generate_password_signed(
 
     // Validate length (21-44 for passwords - security requirement)
     if let Err(e) = validate_length(length, 21, 44) {
-        return Ok(create_error_response(400, &format!("Password {}", e)));
+        return Err(format!("Password {}", e));
     }
 
-    // Get or generate seed
-    let seed_32 = if let Some(seed_str) = params.get("seed") {
-        crate::utils::base58_to_seed(seed_str)
-            .map_err(|e| anyhow::anyhow!("Invalid seed: {}", e))?
-    } else {
-        generate_random_seed()
-    };
+    // Parse generation algorithm version (latest for fresh seeds, legacy for bare seeds)
+    // Policies postdate versioning, so their bare seeds use the latest algorithm
+    let algorithm = parse_generation_algorithm(params, &["policy"])?;
 
-    let seed_base58 = seed_to_base58(&seed_32);
+    // Parse optional composition policy (preset name or JSON policy object)
+    let policy = params
+        .get("policy")
+        .map(|value| PasswordPolicy::parse(value))
+        .transpose()?;
 
-    // Generate password avoiding unwanted patterns
-    let alphabet = alphabet_type.as_chars();
-    let hash = generate_password_avoiding_patterns(length, &alphabet, seed_32);
-
-    // Generate OTP and timestamp
-    let otp = generate_otp(seed_32);
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
-        .as_secs();
-
-    // Create payload directly
-    let payload = CustomHashResponse::new(hash, seed_base58, otp, timestamp);
-
-    // Create signed response using DRY helper
-    match create_signed_endpoint_response(payload, crypto_material) {
-        Ok(signed_response) => Ok(signed_response),
-        Err(e) => Ok(create_error_response(
-            500,
-            &format!("Failed to create signed response: {}", e),
-        )),
+    // Policy generation was introduced with the unbiased algorithm and has no legacy variant
+    if policy.is_some() && algorithm != GenerationAlgorithm::latest() {
+        return Err("Password policies require the latest generation algorithm (2)".to_string());
     }
+
+    // Generate password (policy-driven when requested, otherwise avoiding unwanted patterns)
+    let alphabet = alphabet_type.as_chars();
+    let (hash, entropy_bits) = match &policy {
+        Some(policy) => (
+            generate_password_with_policy(length, &alphabet, policy, seed_32)?,
+            policy_password_entropy_bits(length, &alphabet, policy),
+        ),
+        None => (
+            generate_password_avoiding_patterns(length, &alphabet, seed_32, algorithm),
+            string_entropy_bits(&alphabet, length, "", "", true, algorithm),
+        ),
+    };
+
+    Ok(GeneratedValue::new(hash)
+        .with_algorithm(algorithm)
+        .with_entropy_bits(entropy_bits))
 }
 
 // DELETED: Legacy function handle_password_with_params removed - was completely unused legacy code
@@ -0,0 +1,155 @@
+//! Pronounceable password endpoint with SignedResponse
+//!
+//! Provides GET and POST endpoints for values meant to be read aloud with:
+//! - JWT authentication and Ed25519 signature validation
+//! - Consonant-vowel syllable passwords (default) or proquint identifiers
+//! - Entropy in bits reported alongside the value
+
+use crate::types::{GeneratorType, PronounceableMode};
+use crate::utils::generation::{GeneratedValue, create_generation_signed_response};
+use crate::utils::pronounceable::{SyllableOptions, generate_proquint, generate_syllables};
+use crate::utils::{handle_signed_get_request, handle_signed_post_request, parse_bounded};
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use tracing::info;
+
+/// Syllable count bounds (about 6.3 bits per syllable)
+const MIN_SYLLABLES: usize = 4;
+const MAX_SYLLABLES: usize = 32;
+/// Maximum number of appended digits
+const MAX_DIGITS: usize = 4;
+/// Maximum separator length in characters
+const MAX_SEPARATOR_CHARS: usize = 3;
+/// Proquint byte bounds (2 bytes per quint)
+const MIN_PROQUINT_BYTES: usize = 2;
+const MAX_PROQUINT_BYTES: usize = 32;
+
+/// Handle pronounceable password requests (GET and POST)
+pub async fn handle_pronounceable_request(req: Request) -> anyhow::Result<Response> {
+    info!("📞 Request to /api/pronounceable endpoint");
+    match req.method() {
+        Method::Get => handle_signed_get_request(&req, generate_pronounceable_signed),
+        Method::Post => handle_signed_post_request(&req, generate_pronounceable_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Generate pronounceable value(s) and return SignedResponse (DRY implementation)
+fn generate_pronounceable_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    create_generation_signed_response(
+        params,
+        crypto_material,
+        GeneratorType::Pronounceable,
+        generate_pronounceable_value,
+    )
+}
+
+/// Generate a single pronounceable value from request parameters and seed
+pub fn generate_pronounceable_value(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<GeneratedValue, String> {
+    // Parse mode parameter (integer: 0=syllables, 1=proquint)
+    let mode = match params.get("mode") {
+        Some(mode_str) => mode_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| PronounceableMode::try_from(index).ok())
+            .ok_or_else(|| {
+                "Invalid mode parameter. Must be 0 (syllables) or 1 (proquint)".to_string()
+            })?,
+        None => PronounceableMode::Syllables,
+    };
+
+    let generated = match mode {
+        PronounceableMode::Syllables => {
+            // Default 12 syllables: about 76 bits of entropy
+            let syllables = parse_bounded(params, "syllables", MIN_SYLLABLES, MAX_SYLLABLES, 12)?;
+            let group = parse_bounded(params, "group", 0, MAX_SYLLABLES, 3)?;
+            let digits = parse_bounded(params, "digits", 0, MAX_DIGITS, 0)?;
+
+            let separator = params
+                .get("separator")
+                .cloned()
+                .unwrap_or_else(|| "-".to_string());
+            if separator.chars().count() > MAX_SEPARATOR_CHARS
+                || separator.chars().any(char::is_control)
+            {
+                return Err(format!(
+                    "Separator must be at most {} printable characters",
+                    MAX_SEPARATOR_CHARS
+                ));
+            }
+
+            generate_syllables(
+                seed_32,
+                &SyllableOptions {
+                    syllables,
+                    group,
+                    separator,
+                    digits,
+                },
+            )
+        }
+        PronounceableMode::Proquint => {
+            // Default 8 bytes: four quints, 64 bits
+            let bytes = parse_bounded(params, "bytes", MIN_PROQUINT_BYTES, MAX_PROQUINT_BYTES, 8)?;
+            if !bytes.is_multiple_of(2) {
+                return Err(
+                    "Invalid bytes parameter. Proquints encode 2 bytes each, so it must be even"
+                        .to_string(),
+                );
+            }
+            generate_proquint(seed_32, bytes)
+        }
+    };
+
+    Ok(GeneratedValue::new(generated.value).with_entropy_bits(generated.entropy_bits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_default_syllables() {
+        let value = generate_pronounceable_value(&params(&[]), [5u8; 32]).unwrap();
+        assert_eq!(value.value.len(), 12 * 2 + 3);
+        assert_eq!(
+            value,
+            generate_pronounceable_value(&params(&[]), [5u8; 32]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_proquint_mode() {
+        let value =
+            generate_pronounceable_value(&params(&[("mode", "1"), ("bytes", "4")]), [5u8; 32])
+                .unwrap();
+        assert_eq!(value.value.len(), 11);
+    }
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        let seed = [0u8; 32];
+        for (key, value) in [
+            ("mode", "2"),
+            ("syllables", "3"),
+            ("digits", "5"),
+            ("separator", "----"),
+        ] {
+            assert!(generate_pronounceable_value(&params(&[(key, value)]), seed).is_err());
+        }
+        assert!(
+            generate_pronounceable_value(&params(&[("mode", "1"), ("bytes", "5")]), seed).is_err()
+        );
+    }
+}
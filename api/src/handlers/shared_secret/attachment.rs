@@ -0,0 +1,194 @@
+//! Shared secret attachment download endpoint
+//!
+//! GET /api/shared-secret/attachment?hash={hash}&chunk={index}[&otp={otp}]
+//! Returns one E2E encrypted attachment chunk (decrypted client-side with key_material)
+//! Requires JWT authentication and Ed25519 signature validation
+
+use tracing::info;
+
+use crate::database::operations::{
+    shared_secret_crypto::SharedSecretCrypto,
+    shared_secret_ops::SharedSecretOps,
+    shared_secret_storage::SharedSecretStorage,
+    shared_secret_types::{SecretRole, constants::*},
+};
+use crate::utils::{
+    CryptoMaterial, SignedRequestValidator, create_auth_error_response,
+    create_client_error_response, create_signed_endpoint_response,
+    endpoint_helpers::extract_query_params, extract_crypto_material_from_request,
+};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use serde_json::json;
+use spin_sdk::http::{Request, Response};
+
+/// Handle GET /api/shared-secret/attachment?hash={hash}&chunk={index}
+pub async fn handle_download_attachment(req: Request, hash: &str) -> anyhow::Result<Response> {
+    info!("📎 Request to /api/shared-secret/attachment endpoint");
+    // Extract crypto material from JWT
+    let crypto_material = match extract_crypto_material_from_request(&req) {
+        Ok(material) => material,
+        Err(e) => {
+            return Ok(create_auth_error_response(&format!(
+                "Authentication failed: {}",
+                e
+            )));
+        }
+    };
+
+    // Extract query parameters
+    let mut params = extract_query_params(&req);
+
+    // Validate Ed25519 signature (GET must have signature parameter)
+    if let Err(e) =
+        SignedRequestValidator::validate_query_params(&mut params, &crypto_material.pub_key_hex)
+    {
+        return Ok(create_auth_error_response(&format!(
+            "Signature validation failed: {}",
+            e
+        )));
+    }
+
+    // Parse chunk index (zero-based)
+    let chunk_index = match params.get("chunk").map(|s| s.parse::<u32>()) {
+        Some(Ok(index)) => index,
+        Some(Err(_)) => {
+            return Ok(create_client_error_response(
+                "Invalid chunk parameter. Must be a non-negative integer",
+            ));
+        }
+        None => return Ok(create_client_error_response("Missing chunk parameter")),
+    };
+
+    // Decode hash from Base58 (40 bytes - encrypted with ChaCha20)
+    let encrypted_hash = match decode_hash(hash) {
+        Ok(hash) => hash,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    // Extract user_id from crypto material (JWT)
+    let mut user_id_from_jwt = [0u8; USER_ID_LENGTH];
+    if crypto_material.user_id.len() != USER_ID_LENGTH {
+        return Ok(create_auth_error_response("Invalid user_id length in JWT"));
+    }
+    user_id_from_jwt.copy_from_slice(&crypto_material.user_id);
+
+    // Serve chunk with 3-layer validation
+    match download_chunk_validated(
+        &encrypted_hash,
+        &user_id_from_jwt,
+        chunk_index,
+        params.get("otp").map(|s| s.as_str()),
+        &crypto_material,
+    ) {
+        Ok(response) => Ok(response),
+        // Authorization errors (403), not yet active (425), server errors (500)
+        Err(e) => Ok(super::access_error_response(&e)),
+    }
+}
+
+/// Decode Base58 hash to encrypted 40-byte hash
+fn decode_hash(hash: &str) -> Result<[u8; 40], String> {
+    let decoded = bs58::decode(hash)
+        .into_vec()
+        .map_err(|_| "Invalid Base58 hash".to_string())?;
+
+    if decoded.len() != 40 {
+        return Err(format!(
+            "Invalid hash length: expected 40, got {}",
+            decoded.len()
+        ));
+    }
+
+    let mut encrypted_hash = [0u8; 40];
+    encrypted_hash.copy_from_slice(&decoded);
+    Ok(encrypted_hash)
+}
+
+/// Serve one attachment chunk with 3-layer validation
+fn download_chunk_validated(
+    encrypted_hash: &[u8; 40],
+    user_id_from_jwt: &[u8; USER_ID_LENGTH],
+    chunk_index: u32,
+    provided_otp: Option<&str>,
+    crypto_material: &CryptoMaterial,
+) -> Result<Response, String> {
+    // ============================================================================
+    // 3-LAYER VALIDATION: Checksum → Ownership → Database
+    // ============================================================================
+
+    // Layer 1: Decrypt ChaCha20 hash
+    let decrypted_hash = SharedSecretCrypto::decrypt_url_hash(encrypted_hash)
+        .map_err(|e| format!("Failed to decrypt hash: {}", e))?;
+
+    // Layer 2: Validate checksum + Extract components (reference_hash, user_id, role)
+    let (reference_hash, user_id_from_hash, role) =
+        SharedSecretCrypto::validate_and_extract_hash(&decrypted_hash)
+            .map_err(|e| format!("Invalid hash checksum: {}", e))?;
+
+    // Layer 3: CRITICAL - Validate ownership (user_id from JWT must match user_id from hash)
+    if user_id_from_jwt != &user_id_from_hash {
+        return Err(
+            "FORBIDDEN: Access denied: You cannot download an attachment that doesn't belong to you"
+                .to_string(),
+        );
+    }
+
+    // Generate db_index for database lookup
+    let db_index = SharedSecretCrypto::generate_db_index(&reference_hash, &user_id_from_hash)
+        .map_err(|e| format!("Failed to generate db_index: {}", e))?;
+
+    if !SharedSecretStorage::tracking_exists(&reference_hash)
+        .map_err(|e| format!("Failed to check tracking existence: {}", e))?
+    {
+        return Err(
+            "SECRET_DELETED: Secret no longer available: sender has deleted it".to_string(),
+        );
+    }
+
+    // Receivers cannot download the attachment before the activation time (not_before)
+    if let Some(not_before) = SharedSecretOps::pending_activation(&reference_hash, role)
+        .map_err(|e| format!("Failed to check activation time: {}", e))?
+    {
+        return Err(super::not_yet_active_error(not_before));
+    }
+
+    // Payload holds attachment metadata (chunk_count) and OTP
+    let (payload, _, _, _role_from_db) = SharedSecretOps::read_secret(&db_index, &reference_hash)
+        .map_err(|e| format!("Failed to read secret: {}", e))?;
+
+    // Same OTP rule as retrieval: required for receiver (own OTP), sender bypasses
+    if role == SecretRole::Receiver {
+        let receiver = SharedSecretOps::find_receiver(&payload, &user_id_from_hash)
+            .map_err(|e| format!("Failed to find receiver: {}", e))?;
+        if let Some(stored_otp) = &receiver.otp
+            && provided_otp != Some(stored_otp.as_str())
+        {
+            return Err("FORBIDDEN: Invalid or missing OTP".to_string());
+        }
+    }
+
+    let attachment = payload
+        .attachment
+        .ok_or_else(|| "Secret has no attachment".to_string())?;
+
+    let (encrypted_chunk, pending_downloads) = SharedSecretOps::download_attachment_chunk(
+        &reference_hash,
+        &db_index,
+        chunk_index,
+        attachment.chunk_count,
+        role,
+    )
+    .map_err(|e| format!("Failed to download attachment chunk: {}", e))?;
+
+    let response_json = json!({
+        "encrypted_chunk": BASE64.encode(&encrypted_chunk),
+        "chunk_index": chunk_index,
+        "chunk_count": attachment.chunk_count,
+        "pending_downloads": pending_downloads,
+        "role": role.to_str()
+    });
+
+    // Create signed response
+    create_signed_endpoint_response(&response_json, crypto_material)
+        .map_err(|e| format!("Failed to create signed response: {}", e))
+}
@@ -8,7 +8,12 @@ use tracing::{info, warn};
 use crate::database::operations::{
     shared_secret_crypto::SharedSecretCrypto,
     shared_secret_ops::SharedSecretOps,
-    shared_secret_types::{SecretRole, constants::*},
+    shared_secret_types::{
+        EncryptedAttachment, ExpiryLimits, SecretRole, SharedSecretReceiver, constants::*,
+    },
+};
+use crate::utils::jwt::config::{
+    get_shared_secret_max_expires_seconds, get_shared_secret_min_expires_seconds,
 };
 use crate::utils::{
     CryptoMaterial, ProtectedEndpointMiddleware, ProtectedEndpointResult,
@@ -16,6 +21,7 @@ use crate::utils::{
     extract_crypto_material_from_request, validate_email,
 };
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use spin_sdk::http::{Request, Response};
@@ -34,13 +40,23 @@ use spin_sdk::http::{Request, Response};
 struct CreateSecretRequest {
     sender_email: String,
     receiver_email: String,
+    /// Further receivers of the same secret (each gets its own URL, read counter and OTP)
+    #[serde(default)]
+    additional_receivers: Vec<String>,
     /// ChaCha20-Poly1305 encrypted secret from frontend (base64 encoded)
     encrypted_secret: String,
     /// ECDH encrypted key_material from frontend (base64 encoded, 60 bytes: 44 + 16 MAC)
     /// Encrypted with sender's private key + backend's public key
     encrypted_key_material: String,
-    #[serde(default = "default_expires_hours")]
-    expires_hours: i64,
+    /// Lifetime in hours (older clients; expires_seconds takes precedence)
+    #[serde(default)]
+    expires_hours: Option<i64>,
+    /// Lifetime in seconds, counted from not_before when given
+    #[serde(default)]
+    expires_seconds: Option<i64>,
+    /// Activation time (Unix seconds): receivers cannot read the secret before it
+    #[serde(default)]
+    not_before: Option<i64>,
     #[serde(default = "default_max_reads")]
     max_reads: i64,
     #[serde(default)]
@@ -54,13 +70,74 @@ struct CreateSecretRequest {
     #[serde(default)]
     sender_language: Option<String>,
     ui_host: String, // Required: UI hostname for URL generation
+    /// Optional E2E encrypted file attachment
+    #[serde(default)]
+    attachment: Option<AttachmentUpload>,
+}
+
+/// Encrypted file attachment uploaded with a shared secret
+///
+/// Frontend splits the file into ATTACHMENT_CHUNK_SIZE chunks and encrypts each
+/// with ChaCha20-Poly1305 using key_material (per-chunk nonce, see
+/// `SharedSecretAttachment`). Filename and MIME type are stored inside the
+/// encrypted payload, never in plaintext columns.
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct AttachmentUpload {
+    filename: String,
+    mime_type: String,
+    /// Plaintext file size in bytes
+    size: u64,
+    /// Encrypted chunks in order (base64 encoded)
+    encrypted_chunks: Vec<String>,
+}
+
+impl AttachmentUpload {
+    /// Decode base64 chunks into an EncryptedAttachment
+    pub(super) fn decode(&self) -> Result<EncryptedAttachment, String> {
+        let chunks = self
+            .encrypted_chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                BASE64
+                    .decode(chunk)
+                    .map_err(|e| format!("Failed to decode attachment chunk {}: {}", index, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(EncryptedAttachment {
+            filename: self.filename.clone(),
+            mime_type: self.mime_type.clone(),
+            size: self.size,
+            chunks,
+        })
+    }
+}
+
+/// Requested lifetime in seconds (expires_seconds wins over the legacy expires_hours)
+pub(super) fn requested_expires_seconds(
+    expires_seconds: Option<i64>,
+    expires_hours: Option<i64>,
+) -> i64 {
+    expires_seconds
+        .or(expires_hours.map(|hours| hours.saturating_mul(3600)))
+        .unwrap_or(DEFAULT_EXPIRES_SECONDS)
 }
 
-fn default_expires_hours() -> i64 {
-    DEFAULT_EXPIRES_HOURS
+/// Lifetime bounds from Spin variables (shared_secret_{min,max}_expires_seconds)
+pub(super) fn configured_expiry_limits() -> Result<ExpiryLimits, String> {
+    let limits = ExpiryLimits {
+        min_seconds: get_shared_secret_min_expires_seconds()?,
+        max_seconds: get_shared_secret_max_expires_seconds()?,
+    };
+
+    if limits.min_seconds <= 0 || limits.min_seconds > limits.max_seconds {
+        return Err("Invalid shared secret expiration bounds configuration".to_string());
+    }
+    Ok(limits)
 }
 
-fn default_max_reads() -> i64 {
+pub(super) fn default_max_reads() -> i64 {
     DEFAULT_READS
 }
 
@@ -76,7 +153,7 @@ fn default_max_reads() -> i64 {
 ///
 /// # Returns
 /// Complete URL with protocol (e.g., "http://localhost?shared=abc123")
-fn build_complete_url(ui_host: &str, path: &str) -> String {
+pub(super) fn build_complete_url(ui_host: &str, path: &str) -> String {
     let base_url = ui_host.trim_end_matches('/');
     let clean_path = path.trim_start_matches('/');
 
@@ -99,6 +176,9 @@ fn build_complete_url(ui_host: &str, path: &str) -> String {
 }
 
 /// Response payload for created shared secret
+///
+/// url_receiver and otp belong to the first receiver (receiver_email);
+/// `receivers` lists every receiver in request order.
 #[derive(Debug, Serialize)]
 struct CreateSecretResponse {
     url_sender: String,
@@ -106,6 +186,21 @@ struct CreateSecretResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     otp: Option<String>,
     reference: String,
+    receivers: Vec<CreatedReceiver>,
+    /// Expiration timestamp (Unix seconds)
+    expires_at: i64,
+    /// Activation timestamp for receivers (Unix seconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_before: Option<i64>,
+}
+
+/// Per-receiver URL and OTP in the creation response
+#[derive(Debug, Serialize)]
+struct CreatedReceiver {
+    email: String,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    otp: Option<String>,
 }
 
 /// Handle POST /api/shared-secret/create
@@ -165,21 +260,31 @@ async fn create_shared_secret(
         return Err("Sender email does not match authenticated user".to_string());
     }
 
-    // Validate receiver email
-    if validate_email(&request.receiver_email).is_err() {
-        return Err("Invalid receiver email format".to_string());
+    // Validate receiver emails (first receiver + additional receivers)
+    let receiver_emails: Vec<&str> = std::iter::once(request.receiver_email.as_str())
+        .chain(request.additional_receivers.iter().map(String::as_str))
+        .collect();
+
+    if receiver_emails.len() > MAX_RECEIVERS {
+        return Err(format!("At most {} receivers are allowed", MAX_RECEIVERS));
+    }
+
+    for receiver_email in &receiver_emails {
+        if validate_email(receiver_email).is_err() {
+            return Err(format!("Invalid receiver email format: {}", receiver_email));
+        }
     }
 
     // Note: Encrypted secret validation happens in SharedSecretOps::create_secret_pair
     // Frontend is responsible for validating plaintext before encryption
 
-    // Validate expiration hours
-    if request.expires_hours < MIN_EXPIRES_HOURS || request.expires_hours > MAX_EXPIRES_HOURS {
-        return Err(format!(
-            "Expiration must be between {} and {} hours",
-            MIN_EXPIRES_HOURS, MAX_EXPIRES_HOURS
-        ));
-    }
+    // Validate lifetime against the configured bounds (activation time included)
+    let expires_seconds = requested_expires_seconds(request.expires_seconds, request.expires_hours);
+    let lifetime = configured_expiry_limits()?.lifetime(
+        expires_seconds,
+        request.not_before,
+        Utc::now().timestamp(),
+    )?;
 
     // Validate max reads
     if request.max_reads < MIN_READS || request.max_reads > MAX_READS {
@@ -189,16 +294,14 @@ async fn create_shared_secret(
         ));
     }
 
-    // Calculate receiver user_id
-    let receiver_user_id = SharedSecretCrypto::calculate_user_id(&request.receiver_email)
-        .map_err(|e| format!("Failed to calculate receiver user_id: {}", e))?;
-
-    // Generate OTP if requested
-    let otp = if request.require_otp {
-        Some(SharedSecretCrypto::generate_otp())
-    } else {
-        None
-    };
+    // Generate one OTP per receiver if requested
+    let receivers: Vec<SharedSecretReceiver> = receiver_emails
+        .iter()
+        .map(|email| SharedSecretReceiver {
+            email: email.to_string(),
+            otp: request.require_otp.then(SharedSecretCrypto::generate_otp),
+        })
+        .collect();
 
     // ============================================================================
     // NEW ZERO KNOWLEDGE HASH GENERATION (v2.0)
@@ -215,27 +318,41 @@ async fn create_shared_secret(
     )
     .map_err(|e| format!("Failed to generate sender hash: {}", e))?;
 
-    let receiver_hash_40 = SharedSecretCrypto::generate_shared_secret_hash(
-        &reference_hash,
-        &request.receiver_email,
-        SecretRole::Receiver,
-    )
-    .map_err(|e| format!("Failed to generate receiver hash: {}", e))?;
-
     // Encrypt hashes with ChaCha20
     let sender_encrypted = SharedSecretCrypto::encrypt_url_hash(&sender_hash_40)
         .map_err(|e| format!("Failed to encrypt sender hash: {}", e))?;
 
-    let receiver_encrypted = SharedSecretCrypto::encrypt_url_hash(&receiver_hash_40)
-        .map_err(|e| format!("Failed to encrypt receiver hash: {}", e))?;
-
     // Generate db_index for database storage (PRIMARY KEY)
     let sender_db_index = SharedSecretCrypto::generate_db_index(&reference_hash, sender_user_id)
         .map_err(|e| format!("Failed to generate sender db_index: {}", e))?;
 
-    let receiver_db_index =
-        SharedSecretCrypto::generate_db_index(&reference_hash, &receiver_user_id)
-            .map_err(|e| format!("Failed to generate receiver db_index: {}", e))?;
+    // Per receiver: encrypted URL hash + db_index
+    let mut receiver_entries = Vec::with_capacity(receivers.len());
+    let mut receiver_paths = Vec::with_capacity(receivers.len());
+    for receiver in &receivers {
+        let receiver_hash_40 = SharedSecretCrypto::generate_shared_secret_hash(
+            &reference_hash,
+            &receiver.email,
+            SecretRole::Receiver,
+        )
+        .map_err(|e| format!("Failed to generate receiver hash: {}", e))?;
+
+        let receiver_encrypted = SharedSecretCrypto::encrypt_url_hash(&receiver_hash_40)
+            .map_err(|e| format!("Failed to encrypt receiver hash: {}", e))?;
+
+        let receiver_user_id = SharedSecretCrypto::calculate_user_id(&receiver.email)
+            .map_err(|e| format!("Failed to calculate receiver user_id: {}", e))?;
+
+        let receiver_db_index =
+            SharedSecretCrypto::generate_db_index(&reference_hash, &receiver_user_id)
+                .map_err(|e| format!("Failed to generate receiver db_index: {}", e))?;
+
+        receiver_entries.push((receiver.clone(), receiver_db_index));
+        receiver_paths.push(format!(
+            "?shared={}",
+            bs58::encode(&receiver_encrypted).into_string()
+        ));
+    }
 
     // Decode E2E encrypted data from base64
     let encrypted_secret = BASE64
@@ -246,20 +363,26 @@ async fn create_shared_secret(
         .decode(&request.encrypted_key_material)
         .map_err(|e| format!("Failed to decode encrypted_key_material: {}", e))?;
 
+    // Attachment is validated (size, chunk layout, filename) in create_secret_pair
+    let attachment = request
+        .attachment
+        .as_ref()
+        .map(AttachmentUpload::decode)
+        .transpose()?;
+
     // Create secret pair using SharedSecretOps with E2E encryption
     // Sender's public keys come from JWT (Ed25519 for signatures, X25519 for ECDH)
     let _created_reference = SharedSecretOps::create_secret_pair_with_ecdh(
         &request.sender_email,
-        &request.receiver_email,
+        &receiver_entries,
         &encrypted_secret,
+        attachment.as_ref(),
         &encrypted_key_material,
         &crypto_material.pub_key_hex,        // Ed25519 from JWT
         &crypto_material.x25519_pub_key_hex, // X25519 from JWT
-        otp.clone(),
-        request.expires_hours,
+        &lifetime,
         request.max_reads,
         &sender_db_index,
-        &receiver_db_index,
         &reference_hash,
     )
     .map_err(|e| format!("Failed to create secret with ECDH: {}", e))?;
@@ -270,37 +393,49 @@ async fn create_shared_secret(
     // Generate complete URLs with encrypted hashes (Base58 encoded)
     // Using query parameter format (?shared=hash) for cleaner UX, similar to magic links
     let sender_path = format!("?shared={}", bs58::encode(&sender_encrypted).into_string());
-    let receiver_path = format!(
-        "?shared={}",
-        bs58::encode(&receiver_encrypted).into_string()
-    );
-
     let url_sender = build_complete_url(&request.ui_host, &sender_path);
-    let url_receiver = build_complete_url(&request.ui_host, &receiver_path);
+
+    let created_receivers: Vec<CreatedReceiver> = receivers
+        .into_iter()
+        .zip(&receiver_paths)
+        .map(|(receiver, path)| CreatedReceiver {
+            email: receiver.email,
+            url: build_complete_url(&request.ui_host, path),
+            otp: receiver.otp,
+        })
+        .collect();
 
     // Log shared secret creation with complete URLs and participants
-    info!(
-        "🔐 Shared secret created: {} → {} | Sender URL: {} | Receiver URL: {}",
-        request.sender_email, request.receiver_email, url_sender, url_receiver
-    );
+    for receiver in &created_receivers {
+        info!(
+            "🔐 Shared secret created: {} → {} | Sender URL: {} | Receiver URL: {}",
+            request.sender_email, receiver.email, url_sender, receiver.url
+        );
+    }
 
-    // Send email to receiver (always)
+    // Send email to every receiver (always)
     // NOTE: OTP is NOT sent via email for security reasons
     // Sender must communicate OTP to receiver through a separate channel
-    let receiver_email_result = crate::utils::email::send_shared_secret_receiver_email(
-        &request.receiver_email,
-        &url_receiver,
-        &reference_base58,
-        &request.sender_email,
-        request.expires_hours,
-        request.max_reads,
-        request.receiver_language.as_deref(),
-    )
-    .await;
+    for receiver in &created_receivers {
+        let receiver_email_result = crate::utils::email::send_shared_secret_receiver_email(
+            &receiver.email,
+            &receiver.url,
+            &reference_base58,
+            &request.sender_email,
+            expires_seconds,
+            lifetime.not_before,
+            request.max_reads,
+            request.receiver_language.as_deref(),
+        )
+        .await;
 
-    if let Err(e) = receiver_email_result {
-        warn!("⚠️  Warning: Failed to send receiver email: {}", e);
-        // Don't fail the entire operation, just log the error
+        if let Err(e) = receiver_email_result {
+            warn!(
+                "⚠️  Warning: Failed to send receiver email to {}: {}",
+                receiver.email, e
+            );
+            // Don't fail the entire operation, just log the error
+        }
     }
 
     // Send email to sender (optional)
@@ -309,8 +444,8 @@ async fn create_shared_secret(
             &request.sender_email,
             &url_sender,
             &reference_base58,
-            &request.receiver_email,
-            request.expires_hours,
+            &receiver_emails.join(", "),
+            expires_seconds,
             request.sender_language.as_deref(),
         )
         .await;
@@ -321,12 +456,15 @@ async fn create_shared_secret(
         }
     }
 
-    // Create response
+    // Create response (top-level url_receiver/otp belong to the first receiver)
     let response_data = CreateSecretResponse {
         url_sender,
-        url_receiver,
-        otp: otp.clone(),
+        url_receiver: created_receivers[0].url.clone(),
+        otp: created_receivers[0].otp.clone(),
         reference: reference_base58,
+        receivers: created_receivers,
+        expires_at: lifetime.expires_at,
+        not_before: lifetime.not_before,
     };
 
     let response_json = json!(response_data);
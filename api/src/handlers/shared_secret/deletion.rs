@@ -137,6 +137,15 @@ fn delete_secret_validated(
             SharedSecretStorage::delete_tracking_by_reference_hash(&reference_hash)
                 .map_err(|e| format!("Failed to delete tracking: {}", e))?;
 
+            // 3. Delete per-receiver counters (receiver key_material rows are
+            //    removed lazily when a receiver finds the tracking gone)
+            SharedSecretStorage::delete_receivers_by_reference_hash(&reference_hash)
+                .map_err(|e| format!("Failed to delete receivers: {}", e))?;
+
+            // 4. Delete attachment chunks (no-op if none)
+            SharedSecretStorage::delete_attachment(&reference_hash)
+                .map_err(|e| format!("Failed to delete attachment: {}", e))?;
+
             // Success response
             let response_json = json!({
                 "success": true,
@@ -6,13 +6,71 @@
 //! - POST /api/shared-secret/{hash} - Retrieve secret with OTP validation
 //! - DELETE /api/shared-secret/{hash} - Delete secret
 //! - GET /api/shared-secret/confirm-read?hash={hash} - Confirm read by receiver
+//! - GET /api/shared-secret/attachment?hash={hash}&chunk={index} - Download attachment chunk
+//! - POST /api/shared-secret/request - Request a secret from an invitee
+//! - GET /api/shared-secret/request?hash={hash} - Secret request details (invitee)
+//! - POST /api/shared-secret/request/fulfill - Fulfil a secret request (invitee)
 
+pub mod attachment;
 pub mod creation;
 pub mod deletion;
+pub mod request;
 pub mod retrieval;
 pub mod tracking;
 
+pub use attachment::handle_download_attachment;
 pub use creation::handle_create_secret;
 pub use deletion::handle_delete_secret;
+pub use request::{handle_create_request, handle_fulfill_request, handle_get_request};
 pub use retrieval::handle_retrieve_secret;
 pub use tracking::handle_confirm_read;
+
+use crate::utils::{
+    create_forbidden_response, create_not_yet_active_response, create_server_error_response,
+};
+use spin_sdk::http::Response;
+
+/// Error prefix for receivers arriving before the activation time (not_before)
+const NOT_YET_ACTIVE: &str = "NOT_YET_ACTIVE:";
+
+/// Build the NOT_YET_ACTIVE error for a secret that activates at `not_before`
+fn not_yet_active_error(not_before: i64) -> String {
+    format!(
+        "{} Secret is not available until {}",
+        NOT_YET_ACTIVE, not_before
+    )
+}
+
+/// Map secret access errors: FORBIDDEN to 403, NOT_YET_ACTIVE to 425, the rest to 500
+fn access_error_response(e: &str) -> Response {
+    if let Some(message) = e.strip_prefix("FORBIDDEN:") {
+        return create_forbidden_response(message.trim());
+    }
+    if let Some(not_before) = e
+        .strip_prefix(NOT_YET_ACTIVE)
+        .and_then(|message| message.rsplit(' ').next())
+        .and_then(|ts| ts.parse::<i64>().ok())
+    {
+        return create_not_yet_active_response(e, not_before);
+    }
+    create_server_error_response(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_yet_active_maps_to_425_with_not_before() {
+        let response = access_error_response(&not_yet_active_error(1_700_000_000));
+        assert_eq!(*response.status(), 425);
+        let body: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert_eq!(body["not_before"], 1_700_000_000);
+    }
+
+    #[test]
+    fn test_forbidden_and_other_errors_keep_their_status() {
+        assert_eq!(*access_error_response("FORBIDDEN: nope").status(), 403);
+        assert_eq!(*access_error_response("Database error").status(), 500);
+    }
+}
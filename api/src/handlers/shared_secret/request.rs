@@ -0,0 +1,515 @@
+//! Secret request endpoints ("request a secret" reverse flow)
+//!
+//! POST /api/shared-secret/request - Requester asks an invitee for a secret
+//! GET /api/shared-secret/request?hash={hash} - Upload form details (invitee only)
+//! POST /api/shared-secret/request/fulfill - Invitee encrypts and sends the secret
+//! Requires JWT authentication and Ed25519 signature validation
+//!
+//! The invitee's link carries the sender URL hash of the request's reference_hash.
+//! Fulfilling the request creates a regular shared secret under that reference_hash
+//! (invitee as sender, requester as its only receiver), so afterwards the same hash
+//! opens the secret as its sender via ?shared={hash}.
+
+use tracing::{info, warn};
+
+use super::creation::{
+    AttachmentUpload, build_complete_url, configured_expiry_limits, default_max_reads,
+    requested_expires_seconds,
+};
+use crate::database::operations::{
+    shared_secret_crypto::SharedSecretCrypto,
+    shared_secret_ops::SharedSecretOps,
+    shared_secret_storage::SharedSecretStorage,
+    shared_secret_types::{SecretRequest, SecretRole, SharedSecretReceiver, constants::*},
+};
+use crate::utils::{
+    CryptoMaterial, ProtectedEndpointMiddleware, ProtectedEndpointResult, SignedRequestValidator,
+    create_auth_error_response, create_client_error_response, create_forbidden_response,
+    create_server_error_response, create_signed_endpoint_response,
+    endpoint_helpers::extract_query_params, extract_crypto_material_from_request, validate_email,
+};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use spin_sdk::http::{Request, Response};
+
+/// Request payload for asking an invitee for a secret
+///
+/// expires_seconds (or expires_hours), max_reads and require_otp apply to the secret the invitee
+/// sends; request_expires_hours limits how long the upload link stays valid.
+///
+/// NOTE: invitee_language and requester_language are EXCEPTIONS to the integer
+/// encoding policy (ISO 639-1 strings, as rust_i18n requires).
+#[derive(Debug, Deserialize, Serialize)]
+struct CreateRequestRequest {
+    requester_email: String,
+    invitee_email: String,
+    /// Note shown to the invitee (what is being requested)
+    #[serde(default)]
+    message: String,
+    /// Lifetime in hours (older clients; expires_seconds takes precedence)
+    #[serde(default)]
+    expires_hours: Option<i64>,
+    /// Lifetime of the resulting secret in seconds (counted from fulfilment)
+    #[serde(default)]
+    expires_seconds: Option<i64>,
+    #[serde(default = "default_max_reads")]
+    max_reads: i64,
+    #[serde(default)]
+    require_otp: bool,
+    #[serde(default = "default_request_expires_hours")]
+    request_expires_hours: i64,
+    /// EXCEPTION: Uses ISO string instead of integer (rust_i18n requirement)
+    #[serde(default)]
+    invitee_language: Option<String>,
+    /// EXCEPTION: Uses ISO string instead of integer (rust_i18n requirement)
+    #[serde(default)]
+    requester_language: Option<String>,
+    ui_host: String, // Required: UI hostname for URL generation
+}
+
+/// Request payload for fulfilling a secret request (same E2E encryption as creation)
+#[derive(Debug, Deserialize, Serialize)]
+struct FulfillRequestRequest {
+    /// Request hash from the invitee's link (Base58)
+    hash: String,
+    /// ChaCha20-Poly1305 encrypted secret from frontend (base64 encoded)
+    encrypted_secret: String,
+    /// ECDH encrypted key_material from frontend (base64 encoded, 60 bytes: 44 + 16 MAC)
+    encrypted_key_material: String,
+    /// Optional E2E encrypted file attachment
+    #[serde(default)]
+    attachment: Option<AttachmentUpload>,
+    ui_host: String, // Required: UI hostname for URL generation
+}
+
+fn default_request_expires_hours() -> i64 {
+    MAX_REQUEST_EXPIRES_HOURS
+}
+
+/// Handle POST /api/shared-secret/request
+pub async fn handle_create_request(req: Request) -> anyhow::Result<Response> {
+    info!("📨 Request to /api/shared-secret/request endpoint");
+    let body_bytes = req.body();
+
+    // Validate signed request using protected middleware
+    let result: ProtectedEndpointResult<CreateRequestRequest> =
+        match ProtectedEndpointMiddleware::validate_request(&req, body_bytes).await {
+            Ok(result) => result,
+            Err(error_response) => return Ok(error_response),
+        };
+
+    let crypto_material = match extract_crypto_material_from_request(&req) {
+        Ok(material) => material,
+        Err(e) => {
+            return Ok(create_auth_error_response(&format!(
+                "Crypto extraction failed: {}",
+                e
+            )));
+        }
+    };
+
+    let user_id = match jwt_user_id(&crypto_material) {
+        Ok(user_id) => user_id,
+        Err(response) => return Ok(response),
+    };
+
+    match create_secret_request(&result.payload, &user_id, &crypto_material).await {
+        Ok(response) => Ok(response),
+        Err(e) => Ok(create_server_error_response(&e)),
+    }
+}
+
+/// Handle GET /api/shared-secret/request?hash={hash}
+pub async fn handle_get_request(req: Request, hash: &str) -> anyhow::Result<Response> {
+    info!("📨 Request to /api/shared-secret/request GET endpoint");
+    let crypto_material = match extract_crypto_material_from_request(&req) {
+        Ok(material) => material,
+        Err(e) => {
+            return Ok(create_auth_error_response(&format!(
+                "Authentication failed: {}",
+                e
+            )));
+        }
+    };
+
+    // Validate Ed25519 signature (GET must have signature parameter)
+    let mut params = extract_query_params(&req);
+    if let Err(e) =
+        SignedRequestValidator::validate_query_params(&mut params, &crypto_material.pub_key_hex)
+    {
+        return Ok(create_auth_error_response(&format!(
+            "Signature validation failed: {}",
+            e
+        )));
+    }
+
+    let encrypted_hash = match decode_hash(hash) {
+        Ok(hash) => hash,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    let user_id = match jwt_user_id(&crypto_material) {
+        Ok(user_id) => user_id,
+        Err(response) => return Ok(response),
+    };
+
+    let result = resolve_request(&encrypted_hash, &user_id).and_then(
+        |(reference_hash, _, request, expires_at)| {
+            let response_json = json!({
+                "requester_email": request.requester_email,
+                "message": request.message,
+                "expires_seconds": request.expires_seconds,
+                "max_reads": request.max_reads,
+                "require_otp": request.require_otp,
+                "expires_at": expires_at,
+                "reference": bs58::encode(&reference_hash).into_string()
+            });
+            create_signed_endpoint_response(&response_json, &crypto_material)
+                .map_err(|e| format!("Failed to create signed response: {}", e))
+        },
+    );
+
+    Ok(map_error(result))
+}
+
+/// Handle POST /api/shared-secret/request/fulfill
+pub async fn handle_fulfill_request(req: Request) -> anyhow::Result<Response> {
+    info!("📨 Request to /api/shared-secret/request/fulfill endpoint");
+    let body_bytes = req.body();
+
+    let result: ProtectedEndpointResult<FulfillRequestRequest> =
+        match ProtectedEndpointMiddleware::validate_request(&req, body_bytes).await {
+            Ok(result) => result,
+            Err(error_response) => return Ok(error_response),
+        };
+
+    let crypto_material = match extract_crypto_material_from_request(&req) {
+        Ok(material) => material,
+        Err(e) => {
+            return Ok(create_auth_error_response(&format!(
+                "Crypto extraction failed: {}",
+                e
+            )));
+        }
+    };
+
+    let encrypted_hash = match decode_hash(&result.payload.hash) {
+        Ok(hash) => hash,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    let user_id = match jwt_user_id(&crypto_material) {
+        Ok(user_id) => user_id,
+        Err(response) => return Ok(response),
+    };
+
+    Ok(map_error(
+        fulfill_secret_request(&result.payload, &encrypted_hash, &user_id, &crypto_material).await,
+    ))
+}
+
+/// Extract the 16-byte user_id from JWT crypto material
+fn jwt_user_id(crypto_material: &CryptoMaterial) -> Result<[u8; USER_ID_LENGTH], Response> {
+    crypto_material
+        .user_id
+        .as_slice()
+        .try_into()
+        .map_err(|_| create_auth_error_response("Invalid user_id length in JWT"))
+}
+
+/// Map FORBIDDEN:-prefixed errors to 403, everything else to 500
+fn map_error(result: Result<Response, String>) -> Response {
+    match result {
+        Ok(response) => response,
+        Err(e) if e.starts_with("FORBIDDEN:") => {
+            create_forbidden_response(e.replacen("FORBIDDEN:", "", 1).trim())
+        }
+        Err(e) => create_server_error_response(&e),
+    }
+}
+
+/// Decode Base58 hash to encrypted 40-byte hash
+fn decode_hash(hash: &str) -> Result<[u8; 40], String> {
+    let decoded = bs58::decode(hash)
+        .into_vec()
+        .map_err(|_| "Invalid Base58 hash".to_string())?;
+
+    if decoded.len() != 40 {
+        return Err(format!(
+            "Invalid hash length: expected 40, got {}",
+            decoded.len()
+        ));
+    }
+
+    let mut encrypted_hash = [0u8; 40];
+    encrypted_hash.copy_from_slice(&decoded);
+    Ok(encrypted_hash)
+}
+
+/// Encrypted, Base58 encoded URL hash for a participant of a reference
+fn url_hash(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    email: &str,
+    role: SecretRole,
+) -> Result<String, String> {
+    let hash_40 = SharedSecretCrypto::generate_shared_secret_hash(reference_hash, email, role)
+        .map_err(|e| format!("Failed to generate {} hash: {}", role.to_str(), e))?;
+    let encrypted = SharedSecretCrypto::encrypt_url_hash(&hash_40)
+        .map_err(|e| format!("Failed to encrypt {} hash: {}", role.to_str(), e))?;
+    Ok(bs58::encode(&encrypted).into_string())
+}
+
+/// Database index of a participant of a reference
+fn db_index_for(
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    email: &str,
+) -> Result<[u8; DB_INDEX_LENGTH], String> {
+    let user_id = SharedSecretCrypto::calculate_user_id(email)
+        .map_err(|e| format!("Failed to calculate user_id: {}", e))?;
+    SharedSecretCrypto::generate_db_index(reference_hash, &user_id)
+        .map_err(|e| format!("Failed to generate db_index: {}", e))
+}
+
+/// Validate the requester and store the request, then email the invitee
+async fn create_secret_request(
+    request: &CreateRequestRequest,
+    requester_user_id: &[u8; USER_ID_LENGTH],
+    crypto_material: &CryptoMaterial,
+) -> Result<Response, String> {
+    if validate_email(&request.requester_email).is_err() {
+        return Err("Invalid requester email format".to_string());
+    }
+
+    // Validate that requester_email matches the JWT user_id (Zero Knowledge verification)
+    let calculated_requester_id =
+        SharedSecretCrypto::calculate_user_id(&request.requester_email)
+            .map_err(|e| format!("Failed to calculate requester user_id: {}", e))?;
+    if calculated_requester_id != *requester_user_id {
+        return Err("Requester email does not match authenticated user".to_string());
+    }
+
+    if validate_email(&request.invitee_email).is_err() {
+        return Err("Invalid invitee email format".to_string());
+    }
+
+    // The secret's lifetime starts at fulfilment; check it against the bounds now
+    let expires_seconds = requested_expires_seconds(request.expires_seconds, request.expires_hours);
+    configured_expiry_limits()?.lifetime(expires_seconds, None, Utc::now().timestamp())?;
+
+    let reference_hash = SharedSecretCrypto::generate_reference_hash();
+    let invitee_db_index = db_index_for(&reference_hash, &request.invitee_email)?;
+
+    let secret_request = SecretRequest {
+        requester_email: request.requester_email.clone(),
+        invitee_email: request.invitee_email.clone(),
+        message: request.message.trim().to_string(),
+        expires_seconds,
+        max_reads: request.max_reads,
+        require_otp: request.require_otp,
+        language: request.requester_language.clone(),
+    };
+
+    // Limits are validated here, before anything is stored or sent
+    let expires_at = SharedSecretOps::create_request(
+        &secret_request,
+        &invitee_db_index,
+        request.request_expires_hours,
+    )
+    .map_err(|e| format!("Failed to create secret request: {}", e))?;
+
+    let reference_base58 = bs58::encode(&reference_hash).into_string();
+    let invitee_hash = url_hash(&reference_hash, &request.invitee_email, SecretRole::Sender)?;
+    let requester_hash = url_hash(
+        &reference_hash,
+        &request.requester_email,
+        SecretRole::Receiver,
+    )?;
+
+    let url_request = build_complete_url(&request.ui_host, &format!("?request={}", invitee_hash));
+    // Valid once the invitee has fulfilled the request
+    let url_requester =
+        build_complete_url(&request.ui_host, &format!("?shared={}", requester_hash));
+
+    info!(
+        "📨 Secret request created: {} ← {} | Request URL: {}",
+        request.requester_email, request.invitee_email, url_request
+    );
+
+    let email_result = crate::utils::email::send_shared_secret_request_email(
+        &request.invitee_email,
+        &url_request,
+        &reference_base58,
+        &request.requester_email,
+        &secret_request.message,
+        request.request_expires_hours * 3600,
+        request.invitee_language.as_deref(),
+    )
+    .await;
+
+    if let Err(e) = email_result {
+        warn!(
+            "⚠️  Warning: Failed to send secret request email to {}: {}",
+            request.invitee_email, e
+        );
+        // Don't fail the entire operation, just log the error
+    }
+
+    let response_json = json!({
+        "url_request": url_request,
+        "url_requester": url_requester,
+        "reference": reference_base58,
+        "expires_at": expires_at
+    });
+
+    create_signed_endpoint_response(&response_json, crypto_material)
+        .map_err(|e| format!("Failed to create signed response: {}", e))
+}
+
+/// Resolve a request hash with 3-layer validation (invitee only)
+///
+/// # Returns
+/// * `Result<(reference_hash, invitee_db_index, request, expires_at), String>`
+fn resolve_request(
+    encrypted_hash: &[u8; 40],
+    user_id_from_jwt: &[u8; USER_ID_LENGTH],
+) -> Result<
+    (
+        [u8; REFERENCE_HASH_LENGTH],
+        [u8; DB_INDEX_LENGTH],
+        SecretRequest,
+        i64,
+    ),
+    String,
+> {
+    // Layer 1: Decrypt ChaCha20 hash
+    let decrypted_hash = SharedSecretCrypto::decrypt_url_hash(encrypted_hash)
+        .map_err(|e| format!("Failed to decrypt hash: {}", e))?;
+
+    // Layer 2: Validate checksum + Extract components (reference_hash, user_id, role)
+    let (reference_hash, user_id_from_hash, role) =
+        SharedSecretCrypto::validate_and_extract_hash(&decrypted_hash)
+            .map_err(|e| format!("Invalid hash checksum: {}", e))?;
+
+    // Layer 3: CRITICAL - only the invitee (future sender) may open the request
+    if role != SecretRole::Sender || user_id_from_jwt != &user_id_from_hash {
+        return Err(
+            "FORBIDDEN: Access denied: This secret request is not addressed to you".to_string(),
+        );
+    }
+
+    let db_index = SharedSecretCrypto::generate_db_index(&reference_hash, &user_id_from_hash)
+        .map_err(|e| format!("Failed to generate db_index: {}", e))?;
+
+    let (request, expires_at) = SharedSecretOps::read_request(&db_index)
+        .map_err(|e| format!("Failed to read secret request: {}", e))?
+        .ok_or_else(|| {
+            "REQUEST_NOT_FOUND: Secret request not found, expired or already fulfilled".to_string()
+        })?;
+
+    Ok((reference_hash, db_index, request, expires_at))
+}
+
+/// Create the requested secret (invitee → requester) and notify the requester
+async fn fulfill_secret_request(
+    body: &FulfillRequestRequest,
+    encrypted_hash: &[u8; 40],
+    user_id_from_jwt: &[u8; USER_ID_LENGTH],
+    crypto_material: &CryptoMaterial,
+) -> Result<Response, String> {
+    let (reference_hash, invitee_db_index, request, _) =
+        resolve_request(encrypted_hash, user_id_from_jwt)?;
+
+    let requester = SharedSecretReceiver {
+        email: request.requester_email.clone(),
+        otp: request.require_otp.then(SharedSecretCrypto::generate_otp),
+    };
+    let requester_db_index = db_index_for(&reference_hash, &requester.email)?;
+
+    // Bounds may have changed since the request was made
+    let lifetime = configured_expiry_limits()?.lifetime(
+        request.expires_seconds,
+        None,
+        Utc::now().timestamp(),
+    )?;
+
+    let encrypted_secret = BASE64
+        .decode(&body.encrypted_secret)
+        .map_err(|e| format!("Failed to decode encrypted_secret: {}", e))?;
+    let encrypted_key_material = BASE64
+        .decode(&body.encrypted_key_material)
+        .map_err(|e| format!("Failed to decode encrypted_key_material: {}", e))?;
+    let attachment = body
+        .attachment
+        .as_ref()
+        .map(AttachmentUpload::decode)
+        .transpose()?;
+
+    // Invitee's keys come from the JWT, exactly as for a regular sender.
+    // The tracking row (reference_hash PRIMARY KEY) makes a second fulfilment fail.
+    SharedSecretOps::create_secret_pair_with_ecdh(
+        &request.invitee_email,
+        &[(requester.clone(), requester_db_index)],
+        &encrypted_secret,
+        attachment.as_ref(),
+        &encrypted_key_material,
+        &crypto_material.pub_key_hex,
+        &crypto_material.x25519_pub_key_hex,
+        &lifetime,
+        request.max_reads,
+        &invitee_db_index,
+        &reference_hash,
+    )
+    .map_err(|e| format!("Failed to create secret with ECDH: {}", e))?;
+
+    if let Err(e) = SharedSecretStorage::delete_request(&invitee_db_index) {
+        warn!(
+            "⚠️  Warning: Failed to delete fulfilled secret request: {}",
+            e
+        );
+    }
+
+    let reference_base58 = bs58::encode(&reference_hash).into_string();
+    let url_sender = build_complete_url(&body.ui_host, &format!("?shared={}", body.hash));
+    let requester_hash = url_hash(&reference_hash, &requester.email, SecretRole::Receiver)?;
+    let url_receiver = build_complete_url(&body.ui_host, &format!("?shared={}", requester_hash));
+
+    info!(
+        "🔐 Secret request fulfilled: {} → {} | Receiver URL: {}",
+        request.invitee_email, request.requester_email, url_receiver
+    );
+
+    // NOTE: OTP is NOT sent via email (the invitee passes it on through another channel)
+    let email_result = crate::utils::email::send_shared_secret_receiver_email(
+        &request.requester_email,
+        &url_receiver,
+        &reference_base58,
+        &request.invitee_email,
+        request.expires_seconds,
+        None,
+        request.max_reads,
+        request.language.as_deref(),
+    )
+    .await;
+
+    if let Err(e) = email_result {
+        warn!(
+            "⚠️  Warning: Failed to send receiver email to {}: {}",
+            request.requester_email, e
+        );
+    }
+
+    let mut response_json = json!({
+        "url_sender": url_sender,
+        "url_receiver": url_receiver,
+        "receiver_email": request.requester_email,
+        "reference": reference_base58
+    });
+    if let Some(otp) = requester.otp {
+        response_json["otp"] = json!(otp);
+    }
+
+    create_signed_endpoint_response(&response_json, crypto_material)
+        .map_err(|e| format!("Failed to create signed response: {}", e))
+}
@@ -10,12 +10,11 @@ use crate::database::operations::{
     shared_secret_crypto::SharedSecretCrypto,
     shared_secret_ops::SharedSecretOps,
     shared_secret_storage::SharedSecretStorage,
-    shared_secret_types::{SecretRole, constants::*},
+    shared_secret_types::{SecretRole, SharedSecretPayload, constants::*},
 };
 use crate::utils::{
     CryptoMaterial, ProtectedEndpointMiddleware, ProtectedEndpointResult, SignedRequestValidator,
-    create_auth_error_response, create_client_error_response, create_forbidden_response,
-    create_server_error_response, create_signed_endpoint_response,
+    create_auth_error_response, create_client_error_response, create_signed_endpoint_response,
     crypto::{encrypt_with_ecdh, get_backend_x25519_private_key},
     endpoint_helpers::extract_query_params,
     extract_crypto_material_from_request,
@@ -46,12 +45,48 @@ struct RetrieveSecretResponse {
     pending_reads: i64,
     max_reads: i64,
     expires_at: i64,
+    /// Activation timestamp for receivers (seconds), None if readable immediately
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_before: Option<i64>,
     reference: String,
     role: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     otp: Option<String>, // Only included for sender role
     #[serde(skip_serializing_if = "Option::is_none")]
     read_at: Option<i64>, // Timestamp in seconds, None if not yet read
+    /// Per-receiver read status (sender only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    receivers: Option<Vec<ReceiverStatusInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachment: Option<AttachmentInfo>,
+}
+
+/// Read status of one receiver, as shown on the sender URL
+#[derive(Debug, Serialize)]
+struct ReceiverStatusInfo {
+    email: String,
+    pending_reads: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    read_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    otp: Option<String>,
+    /// Remaining attachment downloads (only when the secret has an attachment)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pending_downloads: Option<i64>,
+}
+
+/// Attachment metadata for the client (chunks fetched via /api/shared-secret/attachment)
+#[derive(Debug, Serialize)]
+struct AttachmentInfo {
+    filename: String,
+    mime_type: String,
+    /// Plaintext size in bytes
+    size: u64,
+    chunk_count: u32,
+    /// Plaintext bytes per chunk (last chunk may be shorter)
+    chunk_size: u64,
+    /// Remaining receiver downloads (sender downloads are unlimited)
+    pending_downloads: i64,
 }
 
 /// Main handler for GET/POST /api/shared-secret/{hash}
@@ -117,16 +152,8 @@ async fn handle_retrieve_secret_get(req: Request, hash: &str) -> anyhow::Result<
         &crypto_material,
     ) {
         Ok(response) => Ok(response),
-        Err(e) => {
-            // Detect authorization errors (403 Forbidden) vs server errors (500)
-            if e.starts_with("FORBIDDEN:") {
-                Ok(create_forbidden_response(
-                    e.replacen("FORBIDDEN:", "", 1).trim(),
-                ))
-            } else {
-                Ok(create_server_error_response(&e))
-            }
-        }
+        // Authorization errors (403), not yet active (425), server errors (500)
+        Err(e) => Ok(super::access_error_response(&e)),
     }
 }
 
@@ -175,16 +202,8 @@ async fn handle_retrieve_secret_post(req: Request, hash: &str) -> anyhow::Result
         &crypto_material,
     ) {
         Ok(response) => Ok(response),
-        Err(e) => {
-            // Detect authorization errors (403 Forbidden) vs server errors (500)
-            if e.starts_with("FORBIDDEN:") {
-                Ok(create_forbidden_response(
-                    e.replacen("FORBIDDEN:", "", 1).trim(),
-                ))
-            } else {
-                Ok(create_server_error_response(&e))
-            }
-        }
+        // Authorization errors (403), not yet active (425), server errors (500)
+        Err(e) => Ok(super::access_error_response(&e)),
     }
 }
 
@@ -254,16 +273,41 @@ fn retrieve_and_respond(
         );
     }
 
+    // Receivers cannot open the secret before its activation time (not_before)
+    if let Some(not_before) = SharedSecretOps::pending_activation(&reference_hash, role)
+        .map_err(|e| format!("Failed to check activation time: {}", e))?
+    {
+        return Err(super::not_yet_active_error(not_before));
+    }
+
     // Read secret from database (no decrement - that happens in confirm-read endpoint)
     // v3: Pass reference_hash for centralized payload retrieval
     let (payload, pending_reads, expires_at, _role_from_db) =
         SharedSecretOps::read_secret(&db_index, &reference_hash)
             .map_err(|e| format!("Failed to read secret: {}", e))?;
 
+    // Entry outlives the last read only while attachment downloads remain
+    if role == SecretRole::Receiver && pending_reads <= 0 {
+        return Err("Secret has been consumed: no reads remaining".to_string());
+    }
+
+    let not_before = SharedSecretStorage::get_not_before_from_tracking(&reference_hash)
+        .map_err(|e| format!("Failed to read activation time: {}", e))?;
+
     // Note: We use 'role' from hash (validated via checksum), not from database
 
+    // Receiver sees only its own entry (email + OTP); sender sees all receivers
+    let own_receiver = match role {
+        SecretRole::Receiver => Some(
+            SharedSecretOps::find_receiver(&payload, &user_id_from_hash)
+                .map_err(|e| format!("Failed to find receiver: {}", e))?,
+        ),
+        SecretRole::Sender => None,
+    };
+    let stored_otp = own_receiver.and_then(|receiver| receiver.otp.as_ref());
+
     // Validate OTP if present (only for receiver - sender bypasses OTP)
-    if payload.otp.is_some() && provided_otp.is_none() && role == SecretRole::Receiver {
+    if stored_otp.is_some() && provided_otp.is_none() {
         // OTP required but not provided (receiver only)
         let error_json = json!({
             "error": "OTP_REQUIRED",
@@ -273,7 +317,7 @@ fn retrieve_and_respond(
             .map_err(|e| format!("Failed to create error response: {}", e));
     }
 
-    if let Some(stored_otp) = &payload.otp
+    if let Some(stored_otp) = stored_otp
         && let Some(provided) = provided_otp
         && stored_otp != provided
     {
@@ -298,8 +342,13 @@ fn retrieve_and_respond(
         .map_err(|e| format!("Failed to get read_at: {}", e))?;
 
     // Include OTP and read_at only for sender (role from hash, not DB)
+    // Top-level otp/receiver_email refer to the first receiver for the sender
+    let first_receiver = payload
+        .receivers
+        .first()
+        .ok_or_else(|| "Payload has no receivers".to_string())?;
     let otp_for_response = if role == SecretRole::Sender {
-        payload.otp.clone()
+        first_receiver.otp.clone()
     } else {
         None
     };
@@ -308,6 +357,41 @@ fn retrieve_and_respond(
     } else {
         None
     };
+    let receiver_email = own_receiver.unwrap_or(first_receiver).email.clone();
+
+    // Per-receiver read status (sender only)
+    let receivers_for_response = if role == SecretRole::Sender {
+        Some(receiver_statuses(
+            &payload,
+            &reference_hash,
+            pending_reads,
+            read_at,
+        )?)
+    } else {
+        None
+    };
+
+    // Attachment metadata comes from the encrypted payload; counter is per receiver
+    let attachment = match &payload.attachment {
+        Some(metadata) => {
+            let pending_downloads = match role {
+                SecretRole::Sender => UNLIMITED_READS,
+                SecretRole::Receiver => SharedSecretStorage::get_receiver_status(&db_index)
+                    .map_err(|e| format!("Failed to get pending_downloads: {}", e))?
+                    .and_then(|status| status.pending_downloads)
+                    .unwrap_or(0),
+            };
+            Some(AttachmentInfo {
+                filename: metadata.filename.clone(),
+                mime_type: metadata.mime_type.clone(),
+                size: metadata.size,
+                chunk_count: metadata.chunk_count,
+                chunk_size: ATTACHMENT_CHUNK_SIZE,
+                pending_downloads,
+            })
+        }
+        None => None,
+    };
 
     // ============================================================================
     // E2E ENCRYPTION: Encrypt key_material with ECDH for requester
@@ -367,14 +451,17 @@ fn retrieve_and_respond(
         encrypted_secret: encrypted_secret_base64,
         encrypted_key_material: encrypted_key_material_base64,
         sender_email: payload.sender_email,
-        receiver_email: payload.receiver_email,
+        receiver_email,
         pending_reads,
         max_reads: payload.max_reads,
         expires_at,
+        not_before,
         reference: reference_base58,
         role: role.to_str().to_string(),
         otp: otp_for_response,
         read_at: read_at_for_response,
+        receivers: receivers_for_response,
+        attachment,
     };
 
     let response_json = json!(response_data);
@@ -383,3 +470,42 @@ fn retrieve_and_respond(
     create_signed_endpoint_response(&response_json, crypto_material)
         .map_err(|e| format!("Failed to create signed response: {}", e))
 }
+
+/// Build per-receiver read status for the sender view
+///
+/// Receivers are matched to their shared_secrets_receivers rows by db_index.
+/// Secrets created before per-receiver counters have a single receiver whose
+/// status is the tracking record itself.
+fn receiver_statuses(
+    payload: &SharedSecretPayload,
+    reference_hash: &[u8; REFERENCE_HASH_LENGTH],
+    tracking_pending_reads: i64,
+    tracking_read_at: Option<i64>,
+) -> Result<Vec<ReceiverStatusInfo>, String> {
+    let statuses = SharedSecretStorage::list_receiver_statuses(reference_hash)
+        .map_err(|e| format!("Failed to list receivers: {}", e))?;
+
+    payload
+        .receivers
+        .iter()
+        .map(|receiver| {
+            let user_id = SharedSecretCrypto::calculate_user_id(&receiver.email)
+                .map_err(|e| format!("Failed to calculate receiver user_id: {}", e))?;
+            let db_index = SharedSecretCrypto::generate_db_index(reference_hash, &user_id)
+                .map_err(|e| format!("Failed to generate receiver db_index: {}", e))?;
+
+            let status = statuses
+                .iter()
+                .find(|(index, _)| *index == db_index)
+                .map(|(_, status)| *status);
+
+            Ok(ReceiverStatusInfo {
+                email: receiver.email.clone(),
+                pending_reads: status.map_or(tracking_pending_reads, |s| s.pending_reads),
+                read_at: status.map_or(tracking_read_at, |s| s.read_at),
+                otp: receiver.otp.clone(),
+                pending_downloads: status.and_then(|s| s.pending_downloads),
+            })
+        })
+        .collect()
+}
@@ -9,12 +9,11 @@ use tracing::{info, warn};
 use crate::database::operations::{
     shared_secret_crypto::SharedSecretCrypto,
     shared_secret_ops::SharedSecretOps,
-    shared_secret_storage::SharedSecretStorage,
     shared_secret_types::{SecretRole, constants::*},
 };
 use crate::utils::{
     CryptoMaterial, SignedRequestValidator, create_auth_error_response,
-    create_client_error_response, create_server_error_response, create_signed_endpoint_response,
+    create_client_error_response, create_signed_endpoint_response,
     endpoint_helpers::extract_query_params, extract_crypto_material_from_request,
 };
 use serde_json::json;
@@ -63,7 +62,7 @@ pub async fn handle_confirm_read(req: Request, hash: &str) -> anyhow::Result<Res
     // Confirm read with 3-layer validation
     match confirm_read_validated(&encrypted_hash, &user_id_from_jwt, &crypto_material) {
         Ok(response) => Ok(response),
-        Err(e) => Ok(create_server_error_response(&e)),
+        Err(e) => Ok(super::access_error_response(&e)),
     }
 }
 
@@ -121,20 +120,24 @@ fn confirm_read_validated(
     let db_index = SharedSecretCrypto::generate_db_index(&reference_hash, &user_id_from_hash)
         .map_err(|e| format!("Failed to generate db_index: {}", e))?;
 
+    // Reads cannot be confirmed before the activation time (not_before)
+    if let Some(not_before) = SharedSecretOps::pending_activation(&reference_hash, role)
+        .map_err(|e| format!("Failed to check activation time: {}", e))?
+    {
+        return Err(super::not_yet_active_error(not_before));
+    }
+
     // ============================================================================
     // v3: Use read_secret() for simplified payload retrieval (centralized decryption)
     // ============================================================================
-    let (payload, _, _, _role_from_db) = SharedSecretOps::read_secret(&db_index, &reference_hash)
-        .map_err(|e| format!("Failed to read secret: {}", e))?;
+    // read_secret() returns this receiver's own pending_reads
+    let (payload, current_pending_reads, _, _role_from_db) =
+        SharedSecretOps::read_secret(&db_index, &reference_hash)
+            .map_err(|e| format!("Failed to read secret: {}", e))?;
 
     // No need for manual decryption - read_secret() handles all layers
 
     // VALIDATION: Check for manual DB tampering (pending_reads should never exceed max_reads)
-    let current_pending_reads =
-        SharedSecretStorage::get_pending_reads_from_tracking(&reference_hash)
-            .map_err(|e| format!("Failed to get pending_reads: {}", e))?
-            .unwrap_or(0);
-
     if current_pending_reads > payload.max_reads {
         //     "⚠️  WARNING: Potential DB tampering detected! pending_reads ({}) > max_reads ({})",
         //     current_pending_reads, payload.max_reads
@@ -146,8 +149,8 @@ fn confirm_read_validated(
         // Continue anyway - don't block legitimate users
     }
 
-    // Decrement pending_reads (simple decrement, no idempotency)
-    let new_pending_reads = SharedSecretStorage::decrement_tracking_reads(&reference_hash)
+    // Decrement this receiver's pending_reads (simple decrement, no idempotency)
+    let new_pending_reads = SharedSecretOps::consume_read(&db_index, &reference_hash)
         .map_err(|e| format!("Failed to decrement pending_reads: {}", e))?;
 
     // Update tracking record with read timestamp (always mark timestamp)
@@ -155,9 +158,11 @@ fn confirm_read_validated(
         .map_err(|e| format!("Failed to confirm read: {}", e))?;
 
     // Auto-delete shared_secret if pending_reads reached 0 (consumed)
-    if new_pending_reads == 0 {
-        SharedSecretStorage::delete_secret(&db_index)
-            .map_err(|e| format!("Failed to auto-delete secret: {}", e))?;
+    // Kept while attachment downloads remain: it holds the key material they need
+    if new_pending_reads == 0
+        && SharedSecretOps::release_if_consumed(&db_index)
+            .map_err(|e| format!("Failed to auto-delete secret: {}", e))?
+    {
         info!("🗑️  Auto-deleted shared_secret (pending_reads=0, hash consumed)");
     }
 
@@ -0,0 +1,350 @@
+//! SLIP-39 Shamir backup endpoints with SignedResponse
+//!
+//! Splits BIP39 mnemonic entropy into SLIP-39 share groups and recombines
+//! them:
+//! - POST only, so phrases, shares and passphrases never travel in query strings
+//! - Secret supplied as a BIP39 `mnemonic` or generated from the seed like /api/mnemonic
+//! - Share randomness derived from the seed (reproducible splits)
+
+use crate::types::{GeneratorType, MnemonicLanguage, Slip39CombineResponse, Slip39SplitResponse};
+use crate::utils::generation::resolve_seed;
+use crate::utils::mnemonic::{VALID_WORD_COUNTS, mnemonic_from_seed, parse_mnemonic};
+use crate::utils::seed_envelope::SeedEnvelope;
+use crate::utils::slip39::share::Share;
+use crate::utils::slip39::{GroupSpec, SplitOptions, combine_shares, generate_shares};
+use crate::utils::{
+    create_client_error_response, create_server_error_response, create_signed_endpoint_response,
+    generate_otp, handle_signed_post_request,
+};
+use bip39::{Language, Mnemonic};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+/// Highest PBKDF2 iteration exponent accepted (bounds CPU time per request)
+const MAX_ITERATION_EXPONENT: u8 = 4;
+
+/// Maximum SLIP-39 passphrase length
+const MAX_SLIP39_PASSPHRASE_CHARS: usize = 1024;
+
+/// Key derivation context for share randomness
+const SPLIT_RNG_CONTEXT: &str = "hashrand 2025 slip39 split v1";
+
+/// Handle SLIP-39 split requests (POST only)
+pub async fn handle_slip39_split_request(req: Request) -> anyhow::Result<Response> {
+    info!("🧩 Request to /api/slip39/split endpoint");
+    match req.method() {
+        Method::Post => handle_signed_post_request(&req, split_slip39_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Handle SLIP-39 combine requests (POST only)
+pub async fn handle_slip39_combine_request(req: Request) -> anyhow::Result<Response> {
+    info!("🧩 Request to /api/slip39/combine endpoint");
+    match req.method() {
+        Method::Post => handle_signed_post_request(&req, combine_slip39_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Split a mnemonic into SLIP-39 share groups and return SignedResponse
+fn split_slip39_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let seed_32 = match resolve_seed(params, GeneratorType::Slip39) {
+        Ok(seed) => seed,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    let split = match split_slip39(params, seed_32) {
+        Ok(split) => split,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
+        .as_secs();
+
+    let payload = Slip39SplitResponse {
+        seed: SeedEnvelope::new(seed_32, GeneratorType::Slip39, None).encode(),
+        otp: generate_otp(seed_32),
+        timestamp,
+        ..split
+    };
+
+    match create_signed_endpoint_response(payload, crypto_material) {
+        Ok(signed_response) => Ok(signed_response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+/// Recombine SLIP-39 shares and return SignedResponse
+fn combine_slip39_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let payload = match combine_slip39(params) {
+        Ok(payload) => payload,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    match create_signed_endpoint_response(payload, crypto_material) {
+        Ok(signed_response) => Ok(signed_response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+/// Split the requested secret (seed/OTP/timestamp are filled in by the caller)
+pub fn split_slip39(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+) -> Result<Slip39SplitResponse, String> {
+    let language = parse_language(params)?;
+    let passphrase = parse_passphrase(params)?;
+    let iteration_exponent = parse_iteration_exponent(params)?;
+
+    let extendable = match params.get("extendable").map(String::as_str) {
+        Some("true") | None => true,
+        Some("false") => false,
+        Some(_) => return Err("Invalid extendable parameter. Must be true or false".to_string()),
+    };
+
+    // Groups as [[member_threshold, member_count], ...], default a single 2-of-3 group
+    let groups: Vec<GroupSpec> = match params.get("groups") {
+        Some(groups_str) => serde_json::from_str::<Vec<(u8, u8)>>(groups_str)
+            .map_err(|_| {
+                "Invalid groups parameter. Must be a JSON array of [member_threshold, member_count] pairs, e.g. [[2,3]]".to_string()
+            })?
+            .into_iter()
+            .map(|(member_threshold, member_count)| GroupSpec {
+                member_threshold,
+                member_count,
+            })
+            .collect(),
+        None => vec![GroupSpec {
+            member_threshold: 2,
+            member_count: 3,
+        }],
+    };
+
+    let group_threshold = match params.get("group_threshold") {
+        Some(threshold_str) => threshold_str
+            .parse::<u8>()
+            .map_err(|_| "Invalid group_threshold parameter. Must be integer 1-16".to_string())?,
+        None => 1,
+    };
+
+    // Supplied mnemonic, or one generated from the seed exactly like /api/mnemonic
+    let (master_secret, generated_mnemonic) = match params.get("mnemonic") {
+        Some(phrase) => {
+            let (_, mnemonic) = parse_mnemonic(phrase, language)?;
+            (mnemonic.to_entropy(), None)
+        }
+        None => {
+            let words = match params.get("words") {
+                Some(words_str) => match words_str.parse::<usize>() {
+                    Ok(words) if VALID_WORD_COUNTS.contains(&words) => words,
+                    _ => {
+                        return Err(
+                            "Invalid words parameter. Must be 12, 15, 18, 21 or 24.".to_string()
+                        );
+                    }
+                },
+                None => 12,
+            };
+            let bip39_language = Language::from(language.unwrap_or(MnemonicLanguage::English));
+            let mnemonic = mnemonic_from_seed(bip39_language, seed_32, words)?;
+            (mnemonic.to_entropy(), Some(mnemonic.to_string()))
+        }
+    };
+
+    let mut rng = ChaCha8Rng::from_seed(blake3::derive_key(SPLIT_RNG_CONTEXT, &seed_32));
+    let options = SplitOptions {
+        group_threshold,
+        iteration_exponent,
+        extendable,
+    };
+    let shares = generate_shares(&mut rng, &master_secret, &passphrase, options, &groups)?;
+
+    let identifier = Share::from_mnemonic(&shares[0][0])?.identifier;
+
+    Ok(Slip39SplitResponse {
+        groups: shares,
+        group_threshold,
+        member_thresholds: groups.iter().map(|group| group.member_threshold).collect(),
+        identifier,
+        extendable,
+        iteration_exponent,
+        mnemonic: generated_mnemonic,
+        seed: String::new(),
+        otp: String::new(),
+        timestamp: 0,
+    })
+}
+
+/// Recombine the `shares` parameter into the master secret and its BIP39 phrase
+pub fn combine_slip39(params: &HashMap<String, String>) -> Result<Slip39CombineResponse, String> {
+    let shares_str = match params.get("shares") {
+        Some(shares) if !shares.trim().is_empty() => shares,
+        _ => return Err("Missing shares parameter".to_string()),
+    };
+
+    // JSON array of share mnemonics, or one share per line
+    let shares: Vec<String> = serde_json::from_str(shares_str).unwrap_or_else(|_| {
+        shares_str
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    });
+
+    // Check the PBKDF2 cost before running it
+    let first = Share::from_mnemonic(shares.first().ok_or("Missing shares parameter")?)?;
+    if first.iteration_exponent > MAX_ITERATION_EXPONENT {
+        return Err(format!(
+            "Shares use iteration exponent {}, above the supported maximum {}",
+            first.iteration_exponent, MAX_ITERATION_EXPONENT
+        ));
+    }
+
+    let language = parse_language(params)?.unwrap_or(MnemonicLanguage::English);
+    let passphrase = parse_passphrase(params)?;
+    let recovered = combine_shares(&shares, &passphrase)?;
+
+    let mnemonic = Mnemonic::from_entropy_in(Language::from(language), &recovered.master_secret)
+        .ok()
+        .map(|mnemonic| mnemonic.to_string());
+
+    Ok(Slip39CombineResponse {
+        master_secret: hex::encode(&recovered.master_secret),
+        mnemonic,
+        identifier: recovered.identifier,
+        extendable: recovered.extendable,
+        iteration_exponent: recovered.iteration_exponent,
+        group_threshold: recovered.group_threshold,
+        group_count: recovered.group_count,
+    })
+}
+
+/// Optional language parameter (integer 0-9)
+fn parse_language(params: &HashMap<String, String>) -> Result<Option<MnemonicLanguage>, String> {
+    params
+        .get("language")
+        .map(|lang_str| {
+            lang_str
+                .parse::<u8>()
+                .ok()
+                .and_then(|index| MnemonicLanguage::try_from(index).ok())
+                .ok_or_else(|| "Invalid language parameter. Must be integer 0-9 (0=english, 1=spanish, 2=french, 3=portuguese, 4=japanese, 5=chinese-simplified, 6=chinese-traditional, 7=italian, 8=korean, 9=czech)".to_string())
+        })
+        .transpose()
+}
+
+/// Optional SLIP-39 passphrase ("" when absent)
+fn parse_passphrase(params: &HashMap<String, String>) -> Result<String, String> {
+    let passphrase = params.get("passphrase").cloned().unwrap_or_default();
+    if passphrase.chars().count() > MAX_SLIP39_PASSPHRASE_CHARS {
+        return Err(format!(
+            "Passphrase must be at most {} characters",
+            MAX_SLIP39_PASSPHRASE_CHARS
+        ));
+    }
+    Ok(passphrase)
+}
+
+/// Optional iteration exponent (default 1)
+fn parse_iteration_exponent(params: &HashMap<String, String>) -> Result<u8, String> {
+    match params.get("iteration_exponent") {
+        Some(exponent_str) => match exponent_str.parse::<u8>() {
+            Ok(exponent) if exponent <= MAX_ITERATION_EXPONENT => Ok(exponent),
+            _ => Err(format!(
+                "Invalid iteration_exponent parameter. Must be integer 0-{}",
+                MAX_ITERATION_EXPONENT
+            )),
+        },
+        None => Ok(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_split_generated_mnemonic_and_combine() {
+        let split = split_slip39(
+            &params(&[("iteration_exponent", "0"), ("passphrase", "TREZOR")]),
+            [5u8; 32],
+        )
+        .unwrap();
+        assert_eq!(split.groups.len(), 1);
+        assert_eq!(split.groups[0].len(), 3);
+        assert_eq!(split.member_thresholds, vec![2]);
+
+        let shares = serde_json::to_string(&split.groups[0][1..]).unwrap();
+        let combined =
+            combine_slip39(&params(&[("shares", &shares), ("passphrase", "TREZOR")])).unwrap();
+        assert_eq!(combined.mnemonic, split.mnemonic);
+        assert_eq!(combined.identifier, split.identifier);
+        assert_eq!(combined.master_secret, hex::encode([5u8; 16]));
+    }
+
+    #[test]
+    fn test_split_is_reproducible_from_seed() {
+        let request = params(&[("iteration_exponent", "0"), ("groups", "[[1,1],[2,2]]")]);
+        let first = split_slip39(&request, [1u8; 32]).unwrap();
+        assert_eq!(first, split_slip39(&request, [1u8; 32]).unwrap());
+        assert_ne!(
+            first.groups,
+            split_slip39(&request, [2u8; 32]).unwrap().groups
+        );
+    }
+
+    #[test]
+    fn test_split_supplied_mnemonic_with_lines() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let split = split_slip39(
+            &params(&[
+                ("mnemonic", phrase),
+                ("iteration_exponent", "0"),
+                ("groups", "[[1,1]]"),
+            ]),
+            [0u8; 32],
+        )
+        .unwrap();
+        assert!(split.mnemonic.is_none());
+
+        let combined = combine_slip39(&params(&[("shares", &split.groups[0][0])])).unwrap();
+        assert_eq!(combined.mnemonic.as_deref(), Some(phrase));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(split_slip39(&params(&[("groups", "2-of-3")]), [0u8; 32]).is_err());
+        assert!(split_slip39(&params(&[("iteration_exponent", "9")]), [0u8; 32]).is_err());
+        assert!(split_slip39(&params(&[("group_threshold", "2")]), [0u8; 32]).is_err());
+        assert!(combine_slip39(&params(&[])).is_err());
+    }
+}
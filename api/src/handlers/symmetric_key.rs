@@ -0,0 +1,163 @@
+//! Symmetric key generation endpoint with SignedResponse
+//!
+//! Provides GET and POST endpoints for typed secret material with:
+//! - JWT authentication and Ed25519 signature validation
+//! - Explicit purposes (HMAC, AEAD, salt, nonce, Fernet, JWK oct, age), each with
+//!   its correct length and encoding, labelled in the signed response
+//! - Key bytes drawn from the request seed (random via `generate_random_seed` unless provided)
+
+use crate::types::{
+    ByteEncoding, GeneratorType, StrengthLevel, SymmetricKeyPurpose, SymmetricKeyResponse,
+};
+use crate::utils::digest::random_bytes;
+use crate::utils::generation::resolve_seed;
+use crate::utils::seed_envelope::SeedEnvelope;
+use crate::utils::symmetric_key::encode_symmetric_key;
+use crate::utils::{
+    create_client_error_response, create_server_error_response, create_signed_endpoint_response,
+    generate_otp, handle_signed_get_request, handle_signed_post_request,
+};
+use spin_sdk::http::{Method, Request, Response};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+/// Handle symmetric key requests (GET and POST)
+pub async fn handle_symmetric_key_request(req: Request) -> anyhow::Result<Response> {
+    info!("🔑 Request to /api/symmetric-key endpoint");
+    match req.method() {
+        Method::Get => handle_signed_get_request(&req, generate_symmetric_key_signed),
+        Method::Post => handle_signed_post_request(&req, generate_symmetric_key_signed).await,
+        _ => Ok(Response::builder()
+            .status(405)
+            .header("content-type", "text/plain")
+            .body("Method not allowed")
+            .build()),
+    }
+}
+
+/// Generate key material and return SignedResponse
+fn generate_symmetric_key_signed(
+    params: &HashMap<String, String>,
+    crypto_material: &crate::utils::CryptoMaterial,
+) -> anyhow::Result<Response> {
+    let seed_32 = match resolve_seed(params, GeneratorType::SymmetricKey) {
+        Ok(seed) => seed,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
+        .as_secs();
+
+    let payload = match generate_symmetric_key(params, seed_32, timestamp) {
+        Ok(payload) => payload,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    match create_signed_endpoint_response(payload, crypto_material) {
+        Ok(signed_response) => Ok(signed_response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+/// Generate typed key material from request parameters and seed
+pub fn generate_symmetric_key(
+    params: &HashMap<String, String>,
+    seed_32: [u8; 32],
+    timestamp: u64,
+) -> Result<SymmetricKeyResponse, String> {
+    // Parse purpose parameter (integer 0-7)
+    let purpose = match params.get("purpose") {
+        Some(purpose_str) => purpose_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|index| SymmetricKeyPurpose::try_from(index).ok())
+            .ok_or_else(|| {
+                "Invalid purpose parameter. Must be integer 0-7 (0=hmac-sha256, 1=hmac-sha512, 2=aead-key, 3=salt, 4=nonce, 5=fernet, 6=jwk-oct, 7=age)"
+                    .to_string()
+            })?,
+        None => SymmetricKeyPurpose::HmacSha256,
+    };
+
+    // Optional encoding override (integer: 0=hex, 1=base58, 2=base64, 3=base64url)
+    let encoding = match params.get("encoding") {
+        Some(encoding_str) => Some(
+            encoding_str
+                .parse::<u8>()
+                .ok()
+                .and_then(|index| ByteEncoding::try_from(index).ok())
+                .ok_or_else(|| {
+                    "Invalid encoding parameter. Must be integer 0-3 (0=hex, 1=base58, 2=base64, 3=base64url)"
+                        .to_string()
+                })?,
+        ),
+        None => None,
+    };
+
+    let length_bytes = purpose.key_len();
+    let key = encode_symmetric_key(purpose, &random_bytes(seed_32, length_bytes), encoding)?;
+    let entropy_bits = (length_bytes * 8) as f64;
+
+    Ok(SymmetricKeyResponse {
+        purpose: purpose.into(),
+        label: purpose.label().to_string(),
+        key: key.key,
+        encoding: key.encoding.to_string(),
+        length_bytes,
+        recipient: key.recipient,
+        entropy_bits,
+        strength: StrengthLevel::from_entropy_bits(entropy_bits),
+        seed: SeedEnvelope::new(seed_32, GeneratorType::SymmetricKey, None).encode(),
+        otp: generate_otp(seed_32),
+        timestamp,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_default_hmac_key() {
+        let response = generate_symmetric_key(&params(&[]), [1u8; 32], 0).unwrap();
+        assert_eq!(response.label, "HMAC-SHA256 key");
+        assert_eq!(response.length_bytes, 32);
+        assert_eq!(response.encoding, "base64");
+        assert_eq!(response.entropy_bits, 256.0);
+        assert_eq!(
+            response.key,
+            generate_symmetric_key(&params(&[]), [1u8; 32], 0)
+                .unwrap()
+                .key
+        );
+    }
+
+    #[test]
+    fn test_every_purpose_is_labelled() {
+        for purpose in 0..=7u8 {
+            let response =
+                generate_symmetric_key(&params(&[("purpose", &purpose.to_string())]), [2u8; 32], 0)
+                    .unwrap();
+            assert_eq!(response.purpose, purpose);
+            assert!(!response.label.is_empty());
+            assert_eq!(response.recipient.is_some(), purpose == 7);
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_parameters() {
+        let seed = [0u8; 32];
+        assert!(generate_symmetric_key(&params(&[("purpose", "8")]), seed, 0).is_err());
+        assert!(generate_symmetric_key(&params(&[("encoding", "4")]), seed, 0).is_err());
+        assert!(
+            generate_symmetric_key(&params(&[("purpose", "7"), ("encoding", "0")]), seed, 0)
+                .is_err()
+        );
+    }
+}
@@ -68,8 +68,21 @@ fn init_tracing() {
 /// Supports the following endpoints:
 /// - GET /api/custom - Customizable hash generation
 /// - GET /api/password - Secure password generation
-/// - GET /api/api-key - API key generation with ak_ prefix
+/// - GET /api/api-key - API key generation with ak_ prefix or checksummed structured format
+/// - POST /api/api-key/verify - Offline structure/checksum verification of structured keys
 /// - GET /api/mnemonic - BIP39 mnemonic phrase generation
+/// - POST /api/mnemonic/validate - BIP39 validation, recovery and seed derivation
+/// - GET /api/passphrase - Diceware-style passphrase generation
+/// - GET /api/pronounceable - Consonant-vowel syllable passwords and proquints
+/// - GET /api/otp - TOTP/HOTP secrets with otpauth URI and SVG QR code
+/// - POST /api/otp/verify - TOTP/HOTP code verification with drift window
+/// - POST /api/from-seed - Reproduce a generator endpoint's output from its seed
+/// - POST /api/slip39/split - SLIP-39 Shamir share groups from mnemonic entropy
+/// - POST /api/slip39/combine - SLIP-39 share recombination and verification
+/// - GET /api/identifier - UUIDv4/v7, ULID and KSUID generation
+/// - GET /api/keypair - Ed25519/X25519 keypairs (PEM, OpenSSH, JWK) with encrypted private key
+/// - GET /api/symmetric-key - Typed symmetric keys, salts and nonces (HMAC, AEAD, Fernet, JWK, age)
+/// - GET /api/draw - Verifiable dice rolls, lottery samples and list shuffles
 /// - GET /api/version - Version information
 /// - POST /api/login/ - Magic link generation
 /// - POST /api/login/magiclink/ - Magic link validation
@@ -108,5 +121,10 @@ async fn handle_hashrand_spin(req: Request) -> anyhow::Result<impl IntoResponse>
     let query_params = parse_query_params(query_string);
 
     // Route according to path and method using the modular system
-    route_request_with_req(req, &path, query_params).await
+    let response = route_request_with_req(req, &path, query_params).await;
+
+    // Occasionally purge expired rows (no scheduler in Spin HTTP components)
+    database::operations::expired_cleanup::maybe_cleanup_expired();
+
+    response
 }
@@ -1,4 +1,5 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
+use unicode_general_category::{GeneralCategory, get_general_category};
 
 /// Available alphabet types for hash generation
 /// DRY: Integer mapping defined once using num_enum derive macros
@@ -30,17 +31,92 @@ impl AlphabetType {
     pub fn as_chars(&self) -> Vec<char> {
         self.chars().chars().collect()
     }
+}
 
-    /// Returns the recommended minimum length for this alphabet
-    #[allow(dead_code)]
-    pub fn min_length(&self) -> usize {
-        match self {
-            AlphabetType::Base58 => 21,
-            AlphabetType::NoLookAlike => 24,
-            AlphabetType::Full => 21,
-            AlphabetType::FullWithSymbols => 21,
-            AlphabetType::Numeric => 35, // Higher due to lower entropy (10 vs 58+ chars)
+/// Minimum number of distinct characters accepted in a custom alphabet
+pub const MIN_CUSTOM_ALPHABET_SIZE: usize = 2;
+
+/// Maximum number of distinct characters accepted in a custom alphabet
+pub const MAX_CUSTOM_ALPHABET_SIZE: usize = 256;
+
+/// Target entropy (bits) used for custom alphabet length recommendations
+/// Matches the built-in Base58 default (21 chars ≈ 110 bits)
+const RECOMMENDED_ENTROPY_BITS: f64 = 110.0;
+
+/// Returns true for characters without a glyph of their own
+///
+/// Format characters (Cf, e.g. zero-width space U+200B, joiner U+200D, BOM U+FEFF)
+/// are invisible, combining marks (Mn, Me, e.g. U+0301) merge with the previous
+/// character, and line/paragraph separators (Zl, Zp) break lines.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::Format
+            | GeneralCategory::NonspacingMark
+            | GeneralCategory::EnclosingMark
+            | GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator
+    )
+}
+
+/// Caller-supplied character set for hash generation
+///
+/// Characters are deduplicated (first occurrence wins, order preserved) and
+/// validated to be printable, non-whitespace characters that each render as
+/// a visible glyph of their own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomAlphabet {
+    chars: Vec<char>,
+}
+
+impl CustomAlphabet {
+    /// Parses and validates a custom alphabet definition
+    ///
+    /// # Arguments
+    /// * `raw` - Characters making up the alphabet (duplicates allowed, removed here)
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - Validated alphabet or error message
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut chars: Vec<char> = Vec::new();
+
+        for c in raw.chars() {
+            if c.is_control() || c.is_whitespace() || is_invisible(c) {
+                return Err(
+                    "Custom alphabet must contain only printable, non-whitespace characters"
+                        .to_string(),
+                );
+            }
+            if !chars.contains(&c) {
+                chars.push(c);
+            }
         }
+
+        if chars.len() < MIN_CUSTOM_ALPHABET_SIZE || chars.len() > MAX_CUSTOM_ALPHABET_SIZE {
+            return Err(format!(
+                "Custom alphabet must contain between {} and {} distinct characters (got {})",
+                MIN_CUSTOM_ALPHABET_SIZE,
+                MAX_CUSTOM_ALPHABET_SIZE,
+                chars.len()
+            ));
+        }
+
+        Ok(Self { chars })
+    }
+
+    /// Returns the deduplicated alphabet as a character vector
+    pub fn as_chars(&self) -> Vec<char> {
+        self.chars.clone()
+    }
+
+    /// Returns true if the alphabet contains the given character
+    pub fn contains(&self, c: char) -> bool {
+        self.chars.contains(&c)
+    }
+
+    /// Returns the recommended minimum length for this alphabet (≈110 bits of entropy)
+    pub fn min_length(&self) -> usize {
+        (RECOMMENDED_ENTROPY_BITS / (self.chars.len() as f64).log2()).ceil() as usize
     }
 }
 
@@ -90,4 +166,40 @@ mod tests {
             assert_eq!(u8::from(alphabet), i);
         }
     }
+
+    #[test]
+    fn test_custom_alphabet_deduplicates_preserving_order() {
+        let alphabet = CustomAlphabet::parse("abcabcxyzz").unwrap();
+        assert_eq!(alphabet.as_chars(), vec!['a', 'b', 'c', 'x', 'y', 'z']);
+    }
+
+    #[test]
+    fn test_custom_alphabet_rejects_invalid_input() {
+        // Too few distinct characters
+        assert!(CustomAlphabet::parse("").is_err());
+        assert!(CustomAlphabet::parse("aaaa").is_err());
+        // Whitespace and control characters
+        assert!(CustomAlphabet::parse("ab cd").is_err());
+        assert!(CustomAlphabet::parse("ab\ncd").is_err());
+        assert!(CustomAlphabet::parse("ab\u{7}cd").is_err());
+        // Invisible and combining characters
+        assert!(CustomAlphabet::parse("abc\u{200B}d").is_err());
+        assert!(CustomAlphabet::parse("abc\u{200D}d").is_err());
+        assert!(CustomAlphabet::parse("\u{FEFF}abcd").is_err());
+        assert!(CustomAlphabet::parse("abce\u{301}").is_err());
+        assert!(CustomAlphabet::parse("abc\u{2028}d").is_err());
+        // Precomposed letters are single visible characters
+        assert!(CustomAlphabet::parse("abcé").is_ok());
+    }
+
+    #[test]
+    fn test_custom_alphabet_min_length() {
+        // Hex: 4 bits per char -> 28 chars for ~110 bits
+        let hex = CustomAlphabet::parse("0123456789abcdef").unwrap();
+        assert_eq!(hex.min_length(), 28);
+
+        // Base58 character set: ~5.86 bits per char -> 19 chars
+        let base58 = CustomAlphabet::parse(AlphabetType::Base58.chars()).unwrap();
+        assert_eq!(base58.min_length(), 19);
+    }
 }
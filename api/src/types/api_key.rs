@@ -0,0 +1,43 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Checksum embedded in structured API keys
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum ApiKeyChecksum {
+    None = 0,   // Legacy `ak_` + random characters (default)
+    Crc32 = 1,  // CRC-32 (IEEE), 6 base62 characters
+    Blake3 = 2, // First 48 bits of BLAKE3, 9 base62 characters
+}
+
+impl ApiKeyChecksum {
+    /// Returns the number of base62 characters used by the checksum
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            ApiKeyChecksum::None => 0,
+            ApiKeyChecksum::Crc32 => 6,
+            ApiKeyChecksum::Blake3 => 9,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(ApiKeyChecksum::try_from(0).unwrap(), ApiKeyChecksum::None);
+        assert_eq!(ApiKeyChecksum::try_from(1).unwrap(), ApiKeyChecksum::Crc32);
+        assert_eq!(ApiKeyChecksum::try_from(2).unwrap(), ApiKeyChecksum::Blake3);
+        assert!(ApiKeyChecksum::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_encoded_lengths_fit_checksum_bits() {
+        // 62^6 > 2^32 and 62^9 > 2^48
+        assert!(62u64.pow(6) > 1 << 32);
+        assert!(62u64.pow(9) > 1 << 48);
+        assert_eq!(ApiKeyChecksum::None.encoded_len(), 0);
+    }
+}
@@ -0,0 +1,67 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Output modes for the /api/custom endpoint
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum CustomMode {
+    Alphabet = 0, // Random string over an alphabet (default)
+    Bytes = 1,    // Random bytes or digest of caller input, encoded
+}
+
+/// Digest algorithms for byte mode
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum DigestAlgorithm {
+    Sha256 = 0,   // 32 bytes (default)
+    Sha512 = 1,   // 64 bytes
+    Sha3_256 = 2, // 32 bytes
+    Blake3 = 3,   // Extendable output (XOF), any length
+}
+
+impl DigestAlgorithm {
+    /// Returns the fixed output length in bytes, or None for XOF algorithms
+    pub fn output_len(&self) -> Option<usize> {
+        match self {
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Sha3_256 => Some(32),
+            DigestAlgorithm::Sha512 => Some(64),
+            DigestAlgorithm::Blake3 => None,
+        }
+    }
+}
+
+/// Text encodings for byte mode output
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum ByteEncoding {
+    Hex = 0,       // Lowercase hexadecimal (default)
+    Base58 = 1,    // Bitcoin alphabet
+    Base64 = 2,    // Standard alphabet with padding
+    Base64Url = 3, // URL-safe alphabet without padding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(CustomMode::try_from(1).unwrap(), CustomMode::Bytes);
+        assert!(CustomMode::try_from(2).is_err());
+        assert_eq!(
+            DigestAlgorithm::try_from(3).unwrap(),
+            DigestAlgorithm::Blake3
+        );
+        assert!(DigestAlgorithm::try_from(4).is_err());
+        assert_eq!(ByteEncoding::try_from(3).unwrap(), ByteEncoding::Base64Url);
+        assert!(ByteEncoding::try_from(4).is_err());
+    }
+
+    #[test]
+    fn test_output_len() {
+        assert_eq!(DigestAlgorithm::Sha512.output_len(), Some(64));
+        assert_eq!(DigestAlgorithm::Blake3.output_len(), None);
+    }
+}
@@ -0,0 +1,23 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Draw modes for the /api/draw endpoint
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum DrawMode {
+    Range = 0,   // Integers in [min, max] with replacement, e.g. dice (default)
+    Sample = 1,  // K distinct picks from items or [min, max] (lottery)
+    Shuffle = 2, // Fisher-Yates permutation of caller-supplied items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(DrawMode::try_from(0).unwrap(), DrawMode::Range);
+        assert_eq!(DrawMode::try_from(2).unwrap(), DrawMode::Shuffle);
+        assert!(DrawMode::try_from(3).is_err());
+    }
+}
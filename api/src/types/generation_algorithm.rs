@@ -0,0 +1,53 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Versioned character-selection algorithms for seeded generation
+/// DRY: Integer mapping defined once using num_enum derive macros
+///
+/// Every version is kept side by side so that a seed always reproduces the
+/// value it originally produced, as long as the version travels with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum GenerationAlgorithm {
+    ModuloV1 = 1,    // Legacy: random_byte % alphabet_len (biased for most alphabets)
+    RejectionV2 = 2, // Rejection sampling over u32 draws (uniform for any alphabet)
+}
+
+impl GenerationAlgorithm {
+    /// Returns the algorithm used for new generations when none is requested
+    pub fn latest() -> Self {
+        GenerationAlgorithm::RejectionV2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_valid_indices() {
+        assert_eq!(
+            GenerationAlgorithm::try_from(1).unwrap(),
+            GenerationAlgorithm::ModuloV1
+        );
+        assert_eq!(
+            GenerationAlgorithm::try_from(2).unwrap(),
+            GenerationAlgorithm::RejectionV2
+        );
+    }
+
+    #[test]
+    fn test_try_from_invalid_indices() {
+        assert!(GenerationAlgorithm::try_from(0).is_err());
+        assert!(GenerationAlgorithm::try_from(3).is_err());
+        assert!(GenerationAlgorithm::try_from(255).is_err());
+    }
+
+    #[test]
+    fn test_latest_is_unbiased() {
+        assert_eq!(
+            GenerationAlgorithm::latest(),
+            GenerationAlgorithm::RejectionV2
+        );
+        assert_eq!(u8::from(GenerationAlgorithm::latest()), 2);
+    }
+}
@@ -0,0 +1,83 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Seeded endpoints recorded in seed envelopes
+///
+/// Indices 0-6 are reproducible through /api/from-seed; the others reproduce
+/// only at their own endpoint.
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum GeneratorType {
+    Custom = 0,        // /api/custom
+    Password = 1,      // /api/password
+    ApiKey = 2,        // /api/api-key
+    Mnemonic = 3,      // /api/mnemonic
+    Passphrase = 4,    // /api/passphrase
+    Identifier = 5,    // /api/identifier (time-ordered formats also need time_ms)
+    Pronounceable = 6, // /api/pronounceable
+    Otp = 7,           // /api/otp
+    Keypair = 8,       // /api/keypair
+    SymmetricKey = 9,  // /api/symmetric-key
+    Draw = 10,         // /api/draw
+    Slip39 = 11,       // /api/slip39/split
+}
+
+impl GeneratorType {
+    /// Whether /api/from-seed can reproduce this generator's output
+    pub fn reproducible_from_seed(&self) -> bool {
+        !matches!(
+            self,
+            GeneratorType::Otp
+                | GeneratorType::Keypair
+                | GeneratorType::SymmetricKey
+                | GeneratorType::Draw
+                | GeneratorType::Slip39
+        )
+    }
+
+    /// Returns the endpoint path that issued (and reproduces) this generator's seeds
+    pub fn endpoint(&self) -> &'static str {
+        match self {
+            GeneratorType::Custom => "/api/custom",
+            GeneratorType::Password => "/api/password",
+            GeneratorType::ApiKey => "/api/api-key",
+            GeneratorType::Mnemonic => "/api/mnemonic",
+            GeneratorType::Passphrase => "/api/passphrase",
+            GeneratorType::Identifier => "/api/identifier",
+            GeneratorType::Pronounceable => "/api/pronounceable",
+            GeneratorType::Otp => "/api/otp",
+            GeneratorType::Keypair => "/api/keypair",
+            GeneratorType::SymmetricKey => "/api/symmetric-key",
+            GeneratorType::Draw => "/api/draw",
+            GeneratorType::Slip39 => "/api/slip39/split",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(GeneratorType::try_from(0).unwrap(), GeneratorType::Custom);
+        assert_eq!(GeneratorType::try_from(3).unwrap(), GeneratorType::Mnemonic);
+        assert_eq!(
+            GeneratorType::try_from(5).unwrap(),
+            GeneratorType::Identifier
+        );
+        assert_eq!(
+            GeneratorType::try_from(6).unwrap(),
+            GeneratorType::Pronounceable
+        );
+        assert_eq!(GeneratorType::try_from(7).unwrap(), GeneratorType::Otp);
+        assert_eq!(GeneratorType::try_from(11).unwrap(), GeneratorType::Slip39);
+        assert!(GeneratorType::try_from(12).is_err());
+    }
+
+    #[test]
+    fn test_endpoints() {
+        assert_eq!(GeneratorType::ApiKey.endpoint(), "/api/api-key");
+        assert_eq!(u8::from(GeneratorType::Password), 1);
+    }
+}
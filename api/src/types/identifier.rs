@@ -0,0 +1,55 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Standard identifier formats for the /api/identifier endpoint
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum IdentifierFormat {
+    UuidV4 = 0, // RFC 9562 random UUID (default)
+    UuidV7 = 1, // RFC 9562 time-ordered UUID (48-bit Unix ms)
+    Ulid = 2,   // 48-bit Unix ms + 80 random bits, Crockford base32
+    Ksuid = 3,  // 32-bit seconds since KSUID epoch + 128 random bits, base62
+}
+
+impl IdentifierFormat {
+    /// Returns true if the identifier embeds a timestamp
+    pub fn is_time_ordered(&self) -> bool {
+        !matches!(self, IdentifierFormat::UuidV4)
+    }
+
+    /// Returns the number of random bits in the identifier
+    pub fn random_bits(&self) -> u32 {
+        match self {
+            IdentifierFormat::UuidV4 => 122,
+            IdentifierFormat::UuidV7 => 74,
+            IdentifierFormat::Ulid => 80,
+            IdentifierFormat::Ksuid => 128,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(
+            IdentifierFormat::try_from(0).unwrap(),
+            IdentifierFormat::UuidV4
+        );
+        assert_eq!(
+            IdentifierFormat::try_from(3).unwrap(),
+            IdentifierFormat::Ksuid
+        );
+        assert!(IdentifierFormat::try_from(4).is_err());
+    }
+
+    #[test]
+    fn test_time_ordered_formats() {
+        assert!(!IdentifierFormat::UuidV4.is_time_ordered());
+        assert!(IdentifierFormat::UuidV7.is_time_ordered());
+        assert!(IdentifierFormat::Ulid.is_time_ordered());
+        assert!(IdentifierFormat::Ksuid.is_time_ordered());
+    }
+}
@@ -0,0 +1,54 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Key algorithms for the /api/keypair endpoint
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum KeypairAlgorithm {
+    Ed25519 = 0, // RFC 8032 signatures (default)
+    X25519 = 1,  // RFC 7748 key agreement
+}
+
+impl KeypairAlgorithm {
+    /// Returns the lowercase algorithm name used in responses
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeypairAlgorithm::Ed25519 => "ed25519",
+            KeypairAlgorithm::X25519 => "x25519",
+        }
+    }
+
+    /// Returns the JWK `crv` value (RFC 8037)
+    pub fn jwk_curve(&self) -> &'static str {
+        match self {
+            KeypairAlgorithm::Ed25519 => "Ed25519",
+            KeypairAlgorithm::X25519 => "X25519",
+        }
+    }
+
+    /// Returns the DER-encoded algorithm identifier OID (RFC 8410)
+    pub fn oid_der(&self) -> [u8; 5] {
+        match self {
+            KeypairAlgorithm::Ed25519 => [0x06, 0x03, 0x2B, 0x65, 0x70], // 1.3.101.112
+            KeypairAlgorithm::X25519 => [0x06, 0x03, 0x2B, 0x65, 0x6E],  // 1.3.101.110
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(
+            KeypairAlgorithm::try_from(0).unwrap(),
+            KeypairAlgorithm::Ed25519
+        );
+        assert_eq!(
+            KeypairAlgorithm::try_from(1).unwrap(),
+            KeypairAlgorithm::X25519
+        );
+        assert!(KeypairAlgorithm::try_from(2).is_err());
+    }
+}
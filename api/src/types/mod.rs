@@ -1,7 +1,37 @@
 pub mod alphabet;
+pub mod api_key;
+pub mod digest;
+pub mod draw;
+pub mod generation_algorithm;
+pub mod generator_type;
+pub mod identifier;
+pub mod keypair;
 pub mod mnemonic_language;
+pub mod otp;
+pub mod passphrase;
+pub mod password_policy;
+pub mod pronounceable;
 pub mod responses;
+pub mod strength;
+pub mod symmetric_key;
 
-pub use alphabet::AlphabetType;
+pub use alphabet::{AlphabetType, CustomAlphabet};
+pub use api_key::ApiKeyChecksum;
+pub use digest::{ByteEncoding, CustomMode, DigestAlgorithm};
+pub use draw::DrawMode;
+pub use generation_algorithm::GenerationAlgorithm;
+pub use generator_type::GeneratorType;
+pub use identifier::IdentifierFormat;
+pub use keypair::KeypairAlgorithm;
 pub use mnemonic_language::MnemonicLanguage;
-pub use responses::{CustomHashResponse, VersionResponse};
+pub use otp::{OtpAlgorithm, OtpType};
+pub use passphrase::{PassphraseCapitalization, PassphraseInsertion};
+pub use password_policy::PasswordPolicy;
+pub use pronounceable::PronounceableMode;
+pub use responses::{
+    ApiKeyVerifyResponse, BatchHashResponse, CustomHashResponse, DrawResponse, InvalidMnemonicWord,
+    KeyFormats, KeypairResponse, MnemonicValidationResponse, OtpSecretResponse, OtpVerifyResponse,
+    Slip39CombineResponse, Slip39SplitResponse, SymmetricKeyResponse, VersionResponse,
+};
+pub use strength::StrengthLevel;
+pub use symmetric_key::SymmetricKeyPurpose;
@@ -0,0 +1,61 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// One-time password schemes for the /api/otp endpoints
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum OtpType {
+    Totp = 0, // RFC 6238 time-based (default)
+    Hotp = 1, // RFC 4226 counter-based
+}
+
+/// HMAC algorithms for one-time passwords
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum OtpAlgorithm {
+    Sha1 = 0,   // Default, supported by every authenticator app
+    Sha256 = 1, // RFC 6238 optional
+    Sha512 = 2, // RFC 6238 optional
+}
+
+impl OtpType {
+    /// Returns the otpauth URI type component
+    pub fn uri_name(&self) -> &'static str {
+        match self {
+            OtpType::Totp => "totp",
+            OtpType::Hotp => "hotp",
+        }
+    }
+}
+
+impl OtpAlgorithm {
+    /// Returns the otpauth URI `algorithm` parameter value
+    pub fn uri_name(&self) -> &'static str {
+        match self {
+            OtpAlgorithm::Sha1 => "SHA1",
+            OtpAlgorithm::Sha256 => "SHA256",
+            OtpAlgorithm::Sha512 => "SHA512",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(OtpType::try_from(0).unwrap(), OtpType::Totp);
+        assert_eq!(OtpType::try_from(1).unwrap(), OtpType::Hotp);
+        assert!(OtpType::try_from(2).is_err());
+        assert_eq!(OtpAlgorithm::try_from(2).unwrap(), OtpAlgorithm::Sha512);
+        assert!(OtpAlgorithm::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_uri_names() {
+        assert_eq!(OtpType::Hotp.uri_name(), "hotp");
+        assert_eq!(OtpAlgorithm::Sha256.uri_name(), "SHA256");
+    }
+}
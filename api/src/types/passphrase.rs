@@ -0,0 +1,70 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Word capitalization styles for passphrase generation
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum PassphraseCapitalization {
+    Lowercase = 0, // correct-horse-battery-staple (default)
+    Title = 1,     // Correct-Horse-Battery-Staple
+    Uppercase = 2, // CORRECT-HORSE-BATTERY-STAPLE
+    Random = 3,    // Each word title-cased with 50% probability (+1 bit per word)
+}
+
+/// Extra character insertion for passphrase generation
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum PassphraseInsertion {
+    None = 0,           // Words only (default)
+    Digit = 1,          // One random digit appended to a random word
+    Symbol = 2,         // One random symbol appended to a random word
+    DigitAndSymbol = 3, // One digit and one symbol, each on a random word
+}
+
+impl PassphraseInsertion {
+    /// Returns whether a digit is inserted
+    pub fn digit(&self) -> bool {
+        matches!(
+            self,
+            PassphraseInsertion::Digit | PassphraseInsertion::DigitAndSymbol
+        )
+    }
+
+    /// Returns whether a symbol is inserted
+    pub fn symbol(&self) -> bool {
+        matches!(
+            self,
+            PassphraseInsertion::Symbol | PassphraseInsertion::DigitAndSymbol
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capitalization_try_from() {
+        assert_eq!(
+            PassphraseCapitalization::try_from(0).unwrap(),
+            PassphraseCapitalization::Lowercase
+        );
+        assert_eq!(
+            PassphraseCapitalization::try_from(3).unwrap(),
+            PassphraseCapitalization::Random
+        );
+        assert!(PassphraseCapitalization::try_from(4).is_err());
+    }
+
+    #[test]
+    fn test_insertion_flags() {
+        assert!(!PassphraseInsertion::None.digit());
+        assert!(!PassphraseInsertion::None.symbol());
+        assert!(PassphraseInsertion::Digit.digit());
+        assert!(PassphraseInsertion::Symbol.symbol());
+        assert!(PassphraseInsertion::DigitAndSymbol.digit());
+        assert!(PassphraseInsertion::DigitAndSymbol.symbol());
+        assert!(PassphraseInsertion::try_from(4).is_err());
+    }
+}
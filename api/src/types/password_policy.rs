@@ -0,0 +1,185 @@
+use serde::Deserialize;
+
+/// Declarative password composition policy
+///
+/// Describes the constraints a generated password must satisfy. Built from a
+/// named preset, a JSON policy object, or a preset plus JSON overrides.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PasswordPolicy {
+    /// Minimum number of uppercase ASCII letters
+    pub min_uppercase: usize,
+    /// Minimum number of lowercase ASCII letters
+    pub min_lowercase: usize,
+    /// Minimum number of digits
+    pub min_digits: usize,
+    /// Minimum number of symbols (non-alphanumeric characters)
+    pub min_symbols: usize,
+    /// Maximum run length of the same character repeated consecutively
+    pub max_repeat: Option<usize>,
+    /// Characters that must never appear in the password
+    pub banned_chars: String,
+    /// Whether the first character must be a letter
+    pub start_with_letter: bool,
+}
+
+/// JSON representation of a policy object (all fields optional, applied over `preset`)
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PasswordPolicySpec {
+    preset: Option<String>,
+    min_uppercase: Option<usize>,
+    min_lowercase: Option<usize>,
+    min_digits: Option<usize>,
+    min_symbols: Option<usize>,
+    max_repeat: Option<usize>,
+    banned_chars: Option<String>,
+    start_with_letter: Option<bool>,
+}
+
+/// Names of the built-in policy presets
+pub const PASSWORD_POLICY_PRESETS: [&str; 5] = [
+    "strict",
+    "active-directory",
+    "aws-iam",
+    "oracle",
+    "url-safe",
+];
+
+impl PasswordPolicy {
+    /// Returns a built-in policy preset by name
+    ///
+    /// - `strict`: 2 of each character class, max 2 repeats, starts with a letter
+    /// - `active-directory`: 1 of each character class, max 2 repeats
+    /// - `aws-iam`: 1 of each character class
+    /// - `oracle`: starts with a letter, only `_ $ #` allowed as symbols
+    /// - `url-safe`: only `-` and `_` allowed as symbols (no percent-encoding needed)
+    pub fn preset(name: &str) -> Option<Self> {
+        let every_class = |n: usize| Self {
+            min_uppercase: n,
+            min_lowercase: n,
+            min_digits: n,
+            min_symbols: n,
+            ..Self::default()
+        };
+
+        match name {
+            "strict" => Some(Self {
+                max_repeat: Some(2),
+                start_with_letter: true,
+                ..every_class(2)
+            }),
+            "active-directory" => Some(Self {
+                max_repeat: Some(2),
+                ..every_class(1)
+            }),
+            "aws-iam" => Some(every_class(1)),
+            "oracle" => Some(Self {
+                banned_chars: "-*^@+!?%".to_string(),
+                start_with_letter: true,
+                ..every_class(1)
+            }),
+            "url-safe" => Some(Self {
+                min_uppercase: 1,
+                min_lowercase: 1,
+                min_digits: 1,
+                banned_chars: "*^@#+!?$%".to_string(),
+                ..Self::default()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Parses a `policy` parameter value
+    ///
+    /// Accepts either a preset name (`aws-iam`) or a JSON policy object
+    /// (`{"preset":"aws-iam","min_digits":3,"banned_chars":"$"}`).
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let value = value.trim();
+        if !value.starts_with('{') {
+            return Self::preset(value).ok_or_else(|| Self::unknown_preset_error(value));
+        }
+
+        let spec: PasswordPolicySpec = serde_json::from_str(value)
+            .map_err(|e| format!("Invalid password policy object: {}", e))?;
+
+        let mut policy = match spec.preset.as_deref() {
+            Some(name) => Self::preset(name).ok_or_else(|| Self::unknown_preset_error(name))?,
+            None => Self::default(),
+        };
+
+        if let Some(n) = spec.min_uppercase {
+            policy.min_uppercase = n;
+        }
+        if let Some(n) = spec.min_lowercase {
+            policy.min_lowercase = n;
+        }
+        if let Some(n) = spec.min_digits {
+            policy.min_digits = n;
+        }
+        if let Some(n) = spec.min_symbols {
+            policy.min_symbols = n;
+        }
+        if spec.max_repeat.is_some() {
+            policy.max_repeat = spec.max_repeat;
+        }
+        if let Some(banned) = spec.banned_chars {
+            policy.banned_chars = banned;
+        }
+        if let Some(start) = spec.start_with_letter {
+            policy.start_with_letter = start;
+        }
+
+        Ok(policy)
+    }
+
+    /// Total number of characters reserved by the per-class minimums
+    pub fn required_chars(&self) -> usize {
+        self.min_uppercase + self.min_lowercase + self.min_digits + self.min_symbols
+    }
+
+    fn unknown_preset_error(name: &str) -> String {
+        format!(
+            "Unknown password policy preset '{}'. Valid presets: {}",
+            name,
+            PASSWORD_POLICY_PRESETS.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_presets_exist() {
+        for name in PASSWORD_POLICY_PRESETS {
+            assert!(PasswordPolicy::preset(name).is_some(), "{}", name);
+        }
+        assert!(PasswordPolicy::preset("unknown").is_none());
+    }
+
+    #[test]
+    fn test_parse_preset_name() {
+        let policy = PasswordPolicy::parse("aws-iam").unwrap();
+        assert_eq!(policy.required_chars(), 4);
+        assert!(PasswordPolicy::parse("nope").is_err());
+    }
+
+    #[test]
+    fn test_parse_object_overrides_preset() {
+        let policy = PasswordPolicy::parse(
+            r#"{"preset":"aws-iam","min_digits":3,"banned_chars":"$","max_repeat":1}"#,
+        )
+        .unwrap();
+        assert_eq!(policy.min_uppercase, 1);
+        assert_eq!(policy.min_digits, 3);
+        assert_eq!(policy.banned_chars, "$");
+        assert_eq!(policy.max_repeat, Some(1));
+        assert!(!policy.start_with_letter);
+    }
+
+    #[test]
+    fn test_parse_object_rejects_unknown_fields() {
+        assert!(PasswordPolicy::parse(r#"{"min_digitz":3}"#).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Output modes for the /api/pronounceable endpoint
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum PronounceableMode {
+    Syllables = 0, // Consonant-vowel syllables for passwords read aloud (default)
+    Proquint = 1,  // Proquint encoding of random bytes (5 letters per 16 bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(
+            PronounceableMode::try_from(0).unwrap(),
+            PronounceableMode::Syllables
+        );
+        assert_eq!(
+            PronounceableMode::try_from(1).unwrap(),
+            PronounceableMode::Proquint
+        );
+        assert!(PronounceableMode::try_from(2).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::types::{GenerationAlgorithm, StrengthLevel};
 use serde::Serialize;
 
 // /// Standard response structure for hash generation endpoints
@@ -22,11 +23,26 @@ pub struct CustomHashResponse {
     /// The generated hash/ID
     pub hash: String,
     /// Base58 representation of the 32-byte seed used for generation
-    pub seed: String,
-    /// 9-digit OTP generated using the same seed
-    pub otp: String,
+    /// (absent for digests of caller input, which do not depend on a seed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<String>,
+    /// 9-digit OTP generated using the same seed (absent together with `seed`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub otp: Option<String>,
     /// Generation timestamp in seconds since Unix epoch
     pub timestamp: u64,
+    /// Generation algorithm version (required together with the seed to reproduce the hash)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<u8>,
+    /// Effective entropy of the generated value in bits
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entropy_bits: Option<f64>,
+    /// Strength classification derived from `entropy_bits`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strength: Option<StrengthLevel>,
+    /// Timestamp embedded in time-ordered identifiers (ms since Unix epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_ms: Option<u64>,
 }
 
 // impl HashResponse {
@@ -113,11 +129,254 @@ impl CustomHashResponse {
     pub fn new(hash: String, seed: String, otp: String, timestamp: u64) -> Self {
         Self {
             hash,
+            seed: Some(seed),
+            otp: Some(otp),
+            timestamp,
+            algorithm: None,
+            entropy_bits: None,
+            strength: None,
+            time_ms: None,
+        }
+    }
+
+    /// Drops the seed and OTP of a value that does not derive from the seed
+    pub fn without_seed(mut self) -> Self {
+        self.seed = None;
+        self.otp = None;
+        self
+    }
+
+    /// Attaches the generation algorithm version used to produce the hash
+    pub fn with_algorithm(mut self, algorithm: GenerationAlgorithm) -> Self {
+        self.algorithm = Some(algorithm.into());
+        self
+    }
+
+    /// Attaches the effective entropy (bits) of the generated value and its strength
+    pub fn with_entropy_bits(mut self, entropy_bits: f64) -> Self {
+        self.entropy_bits = Some(round_entropy_bits(entropy_bits));
+        self.strength = Some(StrengthLevel::from_entropy_bits(entropy_bits));
+        self
+    }
+}
+
+/// Response structure for batch generation (`count` > 1)
+///
+/// All values derive from one seed with per-item domain separation, so the
+/// whole batch is reproducible from `seed` (plus the request parameters).
+#[derive(Serialize, Debug)]
+pub struct BatchHashResponse {
+    /// The generated values, in item order
+    pub hashes: Vec<String>,
+    /// Base58 representation of the 32-byte batch seed
+    pub seed: String,
+    /// 9-digit OTP generated from the batch seed
+    pub otp: String,
+    /// Generation timestamp in seconds since Unix epoch
+    pub timestamp: u64,
+    /// Number of values in the batch
+    pub count: usize,
+    /// Generation algorithm version (required together with the seed to reproduce the batch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<u8>,
+    /// Effective entropy in bits of the weakest generated value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entropy_bits: Option<f64>,
+    /// Strength classification derived from `entropy_bits`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strength: Option<StrengthLevel>,
+    /// Timestamp embedded in time-ordered identifiers (ms since Unix epoch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_ms: Option<u64>,
+}
+
+impl BatchHashResponse {
+    /// Creates a new BatchHashResponse
+    ///
+    /// # Arguments
+    /// * `hashes` - The generated values, in item order
+    /// * `seed` - The 32-byte batch seed as base58 string
+    /// * `otp` - The 9-digit OTP generated from the batch seed
+    /// * `timestamp` - Generation timestamp in seconds since Unix epoch
+    pub fn new(hashes: Vec<String>, seed: String, otp: String, timestamp: u64) -> Self {
+        let count = hashes.len();
+        Self {
+            hashes,
             seed,
             otp,
             timestamp,
+            count,
+            algorithm: None,
+            entropy_bits: None,
+            strength: None,
+            time_ms: None,
         }
     }
+
+    /// Attaches the generation algorithm version used to produce the batch
+    pub fn with_algorithm(mut self, algorithm: GenerationAlgorithm) -> Self {
+        self.algorithm = Some(algorithm.into());
+        self
+    }
+
+    /// Attaches the effective entropy (bits) of each value and its strength
+    pub fn with_entropy_bits(mut self, entropy_bits: f64) -> Self {
+        self.entropy_bits = Some(round_entropy_bits(entropy_bits));
+        self.strength = Some(StrengthLevel::from_entropy_bits(entropy_bits));
+        self
+    }
+}
+
+/// Rounds entropy to two decimals for presentation
+fn round_entropy_bits(entropy_bits: f64) -> f64 {
+    (entropy_bits * 100.0).round() / 100.0
+}
+
+/// Word of a mnemonic that is not in the BIP39 word list
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct InvalidMnemonicWord {
+    /// 1-based position of the word in the phrase
+    pub position: usize,
+    /// The word as typed (lowercased, NFKD-normalized)
+    pub word: String,
+    /// Closest word-list entries, best first
+    pub suggestions: Vec<String>,
+}
+
+/// Response structure for the /api/mnemonic/validate endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MnemonicValidationResponse {
+    /// True if the phrase is a complete BIP39 mnemonic with a valid checksum
+    pub valid: bool,
+    /// Language index used for validation (requested or detected)
+    pub language: u8,
+    /// Number of words in the phrase
+    pub word_count: usize,
+    /// Checksum result (absent when words are invalid or the count is unsupported)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum_valid: Option<bool>,
+    /// Words not found in the word list, with suggested corrections
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub invalid_words: Vec<InvalidMnemonicWord>,
+    /// Every final word that completes the phrase with a valid checksum
+    /// (only when exactly one word is missing)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub last_word_candidates: Vec<String>,
+    /// Hex-encoded 64-byte BIP39 seed (PBKDF2 with optional passphrase), only when valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bip39_seed: Option<String>,
+}
+
+/// Response structure for the /api/slip39/split endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Slip39SplitResponse {
+    /// SLIP-39 share mnemonics, one list per group
+    pub groups: Vec<Vec<String>>,
+    /// Number of groups required to recover the secret
+    pub group_threshold: u8,
+    /// Member threshold of each group (same order as `groups`)
+    pub member_thresholds: Vec<u8>,
+    /// 15-bit identifier shared by every share of this split
+    pub identifier: u16,
+    /// Extendable backup flag
+    pub extendable: bool,
+    /// PBKDF2 iteration exponent used for encryption
+    pub iteration_exponent: u8,
+    /// BIP39 phrase that was split (only when generated from the seed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mnemonic: Option<String>,
+    /// Base58 representation of the 32-byte seed used for generation
+    pub seed: String,
+    /// 9-digit OTP generated using the same seed
+    pub otp: String,
+    /// Generation timestamp in seconds since Unix epoch
+    pub timestamp: u64,
+}
+
+/// Response structure for the /api/slip39/combine endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Slip39CombineResponse {
+    /// Hex-encoded recovered master secret
+    pub master_secret: String,
+    /// BIP39 phrase for the master secret (absent when its length has no BIP39 word count)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mnemonic: Option<String>,
+    /// 15-bit identifier shared by the combined shares
+    pub identifier: u16,
+    /// Extendable backup flag
+    pub extendable: bool,
+    /// PBKDF2 iteration exponent used for encryption
+    pub iteration_exponent: u8,
+    /// Number of groups required to recover the secret
+    pub group_threshold: u8,
+    /// Total number of groups in the split
+    pub group_count: u8,
+}
+
+/// Response structure for the /api/otp endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct OtpSecretResponse {
+    /// Base32 shared secret (RFC 4648, no padding)
+    pub secret: String,
+    /// `otpauth://` provisioning URI for authenticator apps
+    pub uri: String,
+    /// SVG QR code of the provisioning URI
+    pub qr_svg: String,
+    /// OTP type index (0=totp, 1=hotp)
+    #[serde(rename = "type")]
+    pub otp_type: u8,
+    /// HMAC algorithm index (0=sha1, 1=sha256, 2=sha512)
+    pub algorithm: u8,
+    /// Number of code digits
+    pub digits: u32,
+    /// Time step in seconds (TOTP only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<u64>,
+    /// Initial counter (HOTP only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counter: Option<u64>,
+    /// Entropy of the secret in bits
+    pub entropy_bits: f64,
+    /// Strength classification derived from `entropy_bits`
+    pub strength: StrengthLevel,
+    /// Base58 representation of the 32-byte seed used for generation
+    pub seed: String,
+    /// 9-digit OTP generated using the same seed
+    pub otp: String,
+    /// Generation timestamp in seconds since Unix epoch
+    pub timestamp: u64,
+}
+
+/// Response structure for the /api/otp/verify endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct OtpVerifyResponse {
+    /// True if the code matches within the drift window
+    pub valid: bool,
+    /// Matching time step relative to now (TOTP only, when valid)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drift: Option<i64>,
+    /// Matching counter (HOTP only, when valid); continue from counter + 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counter: Option<u64>,
+}
+
+/// Response structure for the /api/api-key/verify endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ApiKeyVerifyResponse {
+    /// True if the key is well-formed and its checksum matches
+    pub valid: bool,
+    /// Key prefix (e.g. "ak"), when the key could be parsed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Environment tag (e.g. "live", "test"), when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    /// Matching checksum index (1=crc32, 2=blake3), when valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<u8>,
+    /// Reason the key was rejected, when invalid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 /// Response structure for the /api/version endpoint
@@ -136,3 +395,87 @@ impl VersionResponse {
         }
     }
 }
+
+/// One key serialized in every format returned by /api/keypair
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct KeyFormats {
+    /// PKCS#8 (private) or SubjectPublicKeyInfo (public) PEM
+    pub pem: String,
+    /// OpenSSH format (Ed25519 only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub openssh: Option<String>,
+    /// JSON Web Key (RFC 8037), `kid` is the RFC 7638 thumbprint
+    pub jwk: serde_json::Value,
+    /// Raw 32-byte key as hex
+    pub hex: String,
+    /// Raw 32-byte key as base64
+    pub base64: String,
+}
+
+/// Response structure for the /api/keypair endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct KeypairResponse {
+    /// Key algorithm index (0=ed25519, 1=x25519)
+    pub algorithm: u8,
+    /// Public key formats (plaintext)
+    pub public_key: KeyFormats,
+    /// Private key formats and seed as JSON, ECDH-encrypted to the requester (base64)
+    pub encrypted_private_key: String,
+    /// Ephemeral X25519 public key (hex) to combine with the requester's X25519 private key
+    pub ephemeral_pub_key: String,
+    /// SHA-256 fingerprint in OpenSSH notation
+    pub fingerprint: String,
+    /// 9-digit OTP generated using the same seed
+    pub otp: String,
+    /// Generation timestamp in seconds since Unix epoch
+    pub timestamp: u64,
+}
+
+/// Response structure for the /api/symmetric-key endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SymmetricKeyResponse {
+    /// Purpose index (0=hmac-sha256 ... 7=age)
+    pub purpose: u8,
+    /// Human-readable purpose, e.g. "HMAC-SHA256 key"
+    pub label: String,
+    /// Encoded key material
+    pub key: String,
+    /// Encoding of `key` (hex, base64, base64url, base64url-padded, jwk, bech32, ...)
+    pub encoding: String,
+    /// Raw key length in bytes
+    pub length_bytes: usize,
+    /// Public recipient for the key (age only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+    /// Entropy of the key material in bits
+    pub entropy_bits: f64,
+    /// Strength classification derived from `entropy_bits`
+    pub strength: StrengthLevel,
+    /// Base58 representation of the 32-byte seed used for generation
+    pub seed: String,
+    /// 9-digit OTP generated using the same seed
+    pub otp: String,
+    /// Generation timestamp in seconds since Unix epoch
+    pub timestamp: u64,
+}
+
+/// Response structure for the /api/draw endpoint
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct DrawResponse {
+    /// Draw mode index (0=range, 1=sample, 2=shuffle)
+    pub mode: u8,
+    /// Drawn integers (range mode, and sample mode without items)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<i64>>,
+    /// Picked or shuffled items (sample mode with items, shuffle mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<String>>,
+    /// Normalized parameters that, with `seed`, recompute this exact draw
+    pub parameters: serde_json::Value,
+    /// Base58 representation of the 32-byte seed used for the draw
+    pub seed: String,
+    /// 9-digit OTP generated using the same seed
+    pub otp: String,
+    /// Draw timestamp in seconds since Unix epoch
+    pub timestamp: u64,
+}
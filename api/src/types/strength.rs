@@ -0,0 +1,56 @@
+use serde::Serialize;
+
+/// Strength classification derived from the effective entropy of a value
+///
+/// Thresholds (bits): weak < 64 ≤ fair < 96 ≤ strong < 128 ≤ very-strong
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StrengthLevel {
+    Weak,       // Brute-forceable offline with commodity hardware
+    Fair,       // Acceptable for rate-limited online secrets only
+    Strong,     // Suitable for long-lived secrets (≈ Base58 21-char default)
+    VeryStrong, // 128+ bits, on par with AES-128 keys
+}
+
+impl StrengthLevel {
+    /// Classifies an entropy value in bits
+    pub fn from_entropy_bits(entropy_bits: f64) -> Self {
+        if entropy_bits >= 128.0 {
+            StrengthLevel::VeryStrong
+        } else if entropy_bits >= 96.0 {
+            StrengthLevel::Strong
+        } else if entropy_bits >= 64.0 {
+            StrengthLevel::Fair
+        } else {
+            StrengthLevel::Weak
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thresholds() {
+        assert_eq!(StrengthLevel::from_entropy_bits(0.0), StrengthLevel::Weak);
+        assert_eq!(StrengthLevel::from_entropy_bits(63.9), StrengthLevel::Weak);
+        assert_eq!(StrengthLevel::from_entropy_bits(64.0), StrengthLevel::Fair);
+        assert_eq!(
+            StrengthLevel::from_entropy_bits(110.0),
+            StrengthLevel::Strong
+        );
+        assert_eq!(
+            StrengthLevel::from_entropy_bits(128.0),
+            StrengthLevel::VeryStrong
+        );
+    }
+
+    #[test]
+    fn test_serialization() {
+        assert_eq!(
+            serde_json::to_string(&StrengthLevel::VeryStrong).unwrap(),
+            "\"very-strong\""
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Purposes supported by the /api/symmetric-key endpoint
+/// DRY: Integer mapping defined once using num_enum derive macros
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum SymmetricKeyPurpose {
+    HmacSha256 = 0, // 32-byte HMAC-SHA256 key (default)
+    HmacSha512 = 1, // 64-byte HMAC-SHA512 key
+    AeadKey = 2,    // 32-byte AES-256-GCM / ChaCha20-Poly1305 key
+    Salt = 3,       // 16-byte salt for password hashing and KDFs
+    Nonce = 4,      // 12-byte (96-bit) AEAD nonce
+    Fernet = 5,     // 32-byte Fernet key (signing + encryption halves)
+    JwkOct = 6,     // 32-byte JWK symmetric ("oct") key
+    Age = 7,        // age X25519 identity (AGE-SECRET-KEY-1...)
+}
+
+impl SymmetricKeyPurpose {
+    /// Returns the human-readable label used in responses
+    pub fn label(&self) -> &'static str {
+        match self {
+            SymmetricKeyPurpose::HmacSha256 => "HMAC-SHA256 key",
+            SymmetricKeyPurpose::HmacSha512 => "HMAC-SHA512 key",
+            SymmetricKeyPurpose::AeadKey => "AEAD key (AES-256-GCM / ChaCha20-Poly1305)",
+            SymmetricKeyPurpose::Salt => "Salt",
+            SymmetricKeyPurpose::Nonce => "AEAD nonce (96-bit)",
+            SymmetricKeyPurpose::Fernet => "Fernet key",
+            SymmetricKeyPurpose::JwkOct => "JWK oct key",
+            SymmetricKeyPurpose::Age => "age secret key",
+        }
+    }
+
+    /// Returns the key length in bytes
+    pub fn key_len(&self) -> usize {
+        match self {
+            SymmetricKeyPurpose::HmacSha512 => 64,
+            SymmetricKeyPurpose::Salt => 16,
+            SymmetricKeyPurpose::Nonce => 12,
+            _ => 32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_indices() {
+        assert_eq!(
+            SymmetricKeyPurpose::try_from(0).unwrap(),
+            SymmetricKeyPurpose::HmacSha256
+        );
+        assert_eq!(
+            SymmetricKeyPurpose::try_from(7).unwrap(),
+            SymmetricKeyPurpose::Age
+        );
+        assert!(SymmetricKeyPurpose::try_from(8).is_err());
+    }
+
+    #[test]
+    fn test_key_lengths() {
+        assert_eq!(SymmetricKeyPurpose::HmacSha512.key_len(), 64);
+        assert_eq!(SymmetricKeyPurpose::Nonce.key_len(), 12);
+        assert_eq!(SymmetricKeyPurpose::Fernet.key_len(), 32);
+    }
+}
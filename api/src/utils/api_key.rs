@@ -0,0 +1,195 @@
+//! Structured, self-identifying API keys
+//!
+//! Layout: `{prefix}_{environment}_{random}{checksum}` (environment optional),
+//! where the checksum covers everything before it and is encoded as fixed-width
+//! base62. Secret scanners can match the prefix, and typos or truncation are
+//! detected offline without a database lookup.
+
+use crate::types::ApiKeyChecksum;
+
+/// Base62 alphabet used for checksum characters
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Maximum length of the prefix and environment labels
+pub const MAX_LABEL_CHARS: usize = 16;
+
+/// Checksum algorithms tried when verifying a key
+const CHECKSUMS: [ApiKeyChecksum; 2] = [ApiKeyChecksum::Crc32, ApiKeyChecksum::Blake3];
+
+/// Components of a verified structured key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyParts {
+    pub prefix: String,
+    pub environment: Option<String>,
+    pub checksum: ApiKeyChecksum,
+}
+
+/// Validate a prefix (2-16 chars, starts with a letter) or environment label
+/// (1-16 chars); both are lowercase ASCII letters and digits only
+pub fn validate_label(name: &str, value: &str) -> Result<(), String> {
+    let min_length = if name == "prefix" { 2 } else { 1 };
+    let valid = (min_length..=MAX_LABEL_CHARS).contains(&value.len())
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        && (name != "prefix" || value.starts_with(|c: char| c.is_ascii_lowercase()));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid {} '{}'. Must be {}-{} lowercase letters or digits{}",
+            name,
+            value,
+            min_length,
+            MAX_LABEL_CHARS,
+            if name == "prefix" {
+                ", starting with a letter"
+            } else {
+                ""
+            }
+        ))
+    }
+}
+
+/// Assemble a structured key from its labels and random part
+pub fn build_api_key(
+    prefix: &str,
+    environment: Option<&str>,
+    random_part: &str,
+    checksum: ApiKeyChecksum,
+) -> String {
+    let mut key = match environment {
+        Some(environment) => format!("{}_{}_{}", prefix, environment, random_part),
+        None => format!("{}_{}", prefix, random_part),
+    };
+    let checksum_chars = compute_checksum(checksum, key.as_bytes());
+    key.push_str(&checksum_chars);
+    key
+}
+
+/// Verify the structure and checksum of a key
+///
+/// # Returns
+/// * `Result<ApiKeyParts, String>` - Parsed components, or why the key is invalid
+pub fn verify_api_key(key: &str) -> Result<ApiKeyParts, String> {
+    let key = key.trim();
+    let parts: Vec<&str> = key.split('_').collect();
+    let (prefix, environment, tail) = match parts.as_slice() {
+        [prefix, tail] => (*prefix, None, *tail),
+        [prefix, environment, tail] => (*prefix, Some(*environment), *tail),
+        _ => {
+            return Err(
+                "Invalid API key structure. Expected prefix_[environment_]random".to_string(),
+            );
+        }
+    };
+
+    validate_label("prefix", prefix)?;
+    if let Some(environment) = environment {
+        validate_label("environment", environment)?;
+    }
+    if !tail.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err("Invalid characters in API key".to_string());
+    }
+
+    CHECKSUMS
+        .iter()
+        .find(|checksum| {
+            let checksum_length = checksum.encoded_len();
+            tail.len() > checksum_length
+                && compute_checksum(**checksum, &key.as_bytes()[..key.len() - checksum_length])
+                    == tail[tail.len() - checksum_length..]
+        })
+        .map(|checksum| ApiKeyParts {
+            prefix: prefix.to_string(),
+            environment: environment.map(str::to_string),
+            checksum: *checksum,
+        })
+        .ok_or_else(|| "Checksum mismatch: the key is mistyped or truncated".to_string())
+}
+
+/// Fixed-width base62 checksum of `data`
+pub fn compute_checksum(checksum: ApiKeyChecksum, data: &[u8]) -> String {
+    let value = match checksum {
+        ApiKeyChecksum::None => return String::new(),
+        ApiKeyChecksum::Crc32 => crc32fast::hash(data) as u64,
+        ApiKeyChecksum::Blake3 => {
+            let hash = blake3::hash(data);
+            let mut bytes = [0u8; 8];
+            bytes[2..].copy_from_slice(&hash.as_bytes()[..6]);
+            u64::from_be_bytes(bytes)
+        }
+    };
+    encode_base62(value, checksum.encoded_len())
+}
+
+/// Zero-padded base62 encoding of `value` with `width` characters
+fn encode_base62(mut value: u64, width: usize) -> String {
+    let mut digits = vec![b'0'; width];
+    for digit in digits.iter_mut().rev() {
+        *digit = BASE62[(value % 62) as usize];
+        value /= 62;
+    }
+    digits.iter().map(|b| *b as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_check_value() {
+        // CRC-32 (IEEE 802.3) check values, base62 encoded
+        let crc32 = |data: &[u8]| compute_checksum(ApiKeyChecksum::Crc32, data);
+        assert_eq!(crc32(b"123456789"), encode_base62(0xCBF43926, 6));
+        assert_eq!(crc32(b""), encode_base62(0, 6));
+    }
+
+    #[test]
+    fn test_base62_fixed_width() {
+        assert_eq!(encode_base62(0, 6), "000000");
+        assert_eq!(encode_base62(61, 6), "00000z");
+        assert_eq!(encode_base62(62, 3), "010");
+    }
+
+    #[test]
+    fn test_build_and_verify_roundtrip() {
+        for checksum in CHECKSUMS {
+            let key = build_api_key("acme", Some("live"), "Ab3dEf9hIjKlMnOp", checksum);
+            assert!(key.starts_with("acme_live_Ab3dEf9hIjKlMnOp"));
+            assert_eq!(key.len(), 26 + checksum.encoded_len());
+            let parts = verify_api_key(&key).unwrap();
+            assert_eq!(parts.prefix, "acme");
+            assert_eq!(parts.environment.as_deref(), Some("live"));
+            assert_eq!(parts.checksum, checksum);
+        }
+
+        let key = build_api_key("ak", None, "Zz9", ApiKeyChecksum::Crc32);
+        assert_eq!(verify_api_key(&key).unwrap().environment, None);
+    }
+
+    #[test]
+    fn test_detects_typos_and_truncation() {
+        let key = build_api_key(
+            "ak",
+            Some("test"),
+            "Ab3dEf9hIjKlMnOp",
+            ApiKeyChecksum::Crc32,
+        );
+        assert!(verify_api_key(&key[..key.len() - 1]).is_err());
+        assert!(verify_api_key(&key.replacen("Ab3", "Ab4", 1)).is_err());
+        assert!(verify_api_key(&key.replacen("test", "live", 1)).is_err());
+        assert!(verify_api_key("ak_live_x_y").is_err());
+        assert!(verify_api_key("AK_abc").is_err());
+    }
+
+    #[test]
+    fn test_validate_label() {
+        assert!(validate_label("prefix", "ak").is_ok());
+        assert!(validate_label("prefix", "a").is_err());
+        assert!(validate_label("prefix", "1ak").is_err());
+        assert!(validate_label("environment", "live").is_ok());
+        assert!(validate_label("environment", "Live").is_err());
+        assert!(validate_label("environment", &"x".repeat(17)).is_err());
+    }
+}
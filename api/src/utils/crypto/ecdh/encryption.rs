@@ -65,3 +65,27 @@ pub fn encrypt_with_ecdh(
     );
     Ok(encrypted)
 }
+
+/// Encrypt data to a recipient with a one-time (ephemeral) sender key
+///
+/// The KDF derives the nonce from the shared secret alone, so every message
+/// between the same two static keys would reuse it. A fresh sender key per
+/// message keeps the cipher key and nonce unique; the recipient decrypts with
+/// `decrypt_with_ecdh(encrypted, my_private, ephemeral_public)`.
+///
+/// # Arguments
+/// * `data` - Data to encrypt
+/// * `their_public` - Recipient's X25519 public key
+/// * `ephemeral_secret` - 32 fresh random bytes, never reused
+///
+/// # Returns
+/// * `Result<(Vec<u8>, X25519PublicKey), SqliteError>` - Encrypted data and the ephemeral public key
+pub fn encrypt_with_ephemeral_ecdh(
+    data: &[u8],
+    their_public: &X25519PublicKey,
+    ephemeral_secret: [u8; 32],
+) -> Result<(Vec<u8>, X25519PublicKey), SqliteError> {
+    let ephemeral_private = X25519PrivateKey::from(ephemeral_secret);
+    let encrypted = encrypt_with_ecdh(data, &ephemeral_private, their_public)?;
+    Ok((encrypted, X25519PublicKey::from(&ephemeral_private)))
+}
@@ -15,7 +15,7 @@ mod kdf;
 
 // Re-export public API (maintains backwards compatibility)
 pub use decryption::decrypt_with_ecdh;
-pub use encryption::encrypt_with_ecdh;
+pub use encryption::{encrypt_with_ecdh, encrypt_with_ephemeral_ecdh};
 
 #[cfg(test)]
 mod tests {
@@ -43,6 +43,22 @@ mod tests {
         assert_eq!(encrypted.len(), 44 + 16); // plaintext + Poly1305 tag
     }
 
+    #[test]
+    fn test_ephemeral_ecdh_roundtrip() {
+        let bob_secret = X25519PrivateKey::from([2u8; 32]);
+        let bob_public = X25519PublicKey::from(&bob_secret);
+
+        let (encrypted, ephemeral_public) =
+            encrypt_with_ephemeral_ecdh(b"private key", &bob_public, [21u8; 32]).unwrap();
+        let decrypted = decrypt_with_ecdh(&encrypted, &bob_secret, &ephemeral_public).unwrap();
+        assert_eq!(decrypted, b"private key");
+
+        // A different ephemeral key gives an unrelated ciphertext
+        let (other, _) =
+            encrypt_with_ephemeral_ecdh(b"private key", &bob_public, [22u8; 32]).unwrap();
+        assert_ne!(encrypted, other);
+    }
+
     #[test]
     fn test_ecdh_wrong_key_fails() {
         let alice_secret = X25519PrivateKey::from([1u8; 32]);
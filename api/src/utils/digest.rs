@@ -0,0 +1,94 @@
+//! Byte-oriented generation for /api/custom byte mode
+//!
+//! Produces seeded random bytes or digests of caller-supplied input
+//! (SHA-256, SHA-512, SHA3-256, BLAKE3 XOF) and encodes them as text.
+
+use crate::types::{ByteEncoding, DigestAlgorithm};
+use base64::Engine as _;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use sha2::{Digest, Sha256, Sha512};
+use sha3::Sha3_256;
+
+/// Deterministic random bytes from a 32-byte seed
+pub fn random_bytes(seed: [u8; 32], len: usize) -> Vec<u8> {
+    let mut rng = ChaCha8Rng::from_seed(seed);
+    let mut bytes = vec![0u8; len];
+    rng.fill_bytes(&mut bytes);
+    bytes
+}
+
+/// Digest `input` with the selected algorithm
+///
+/// # Arguments
+/// * `algorithm` - Digest algorithm
+/// * `input` - Data to digest
+/// * `xof_len` - Output length for XOF algorithms (ignored by fixed-size digests)
+pub fn digest_bytes(algorithm: DigestAlgorithm, input: &[u8], xof_len: usize) -> Vec<u8> {
+    match algorithm {
+        DigestAlgorithm::Sha256 => Sha256::digest(input).to_vec(),
+        DigestAlgorithm::Sha512 => Sha512::digest(input).to_vec(),
+        DigestAlgorithm::Sha3_256 => Sha3_256::digest(input).to_vec(),
+        DigestAlgorithm::Blake3 => {
+            let mut output = vec![0u8; xof_len];
+            blake3::Hasher::new()
+                .update(input)
+                .finalize_xof()
+                .fill(&mut output);
+            output
+        }
+    }
+}
+
+/// Encode bytes with the selected text encoding
+pub fn encode_bytes(bytes: &[u8], encoding: ByteEncoding) -> String {
+    match encoding {
+        ByteEncoding::Hex => hex::encode(bytes),
+        ByteEncoding::Base58 => bs58::encode(bytes).into_string(),
+        ByteEncoding::Base64 => STANDARD.encode(bytes),
+        ByteEncoding::Base64Url => URL_SAFE_NO_PAD.encode(bytes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_digests() {
+        assert_eq!(
+            hex::encode(digest_bytes(DigestAlgorithm::Sha256, b"abc", 0)),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex::encode(digest_bytes(DigestAlgorithm::Sha3_256, b"abc", 0)),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        assert_eq!(digest_bytes(DigestAlgorithm::Sha512, b"abc", 0).len(), 64);
+    }
+
+    #[test]
+    fn test_blake3_xof_extends_default_output() {
+        let short = digest_bytes(DigestAlgorithm::Blake3, b"abc", 32);
+        let long = digest_bytes(DigestAlgorithm::Blake3, b"abc", 100);
+        assert_eq!(short, blake3::hash(b"abc").as_bytes().to_vec());
+        assert_eq!(long.len(), 100);
+        assert_eq!(&long[..32], &short[..]);
+    }
+
+    #[test]
+    fn test_encodings() {
+        let bytes = [0xfb, 0xff, 0x00];
+        assert_eq!(encode_bytes(&bytes, ByteEncoding::Hex), "fbff00");
+        assert_eq!(encode_bytes(&bytes, ByteEncoding::Base64), "+/8A");
+        assert_eq!(encode_bytes(&bytes, ByteEncoding::Base64Url), "-_8A");
+        assert_eq!(encode_bytes(&[0, 1], ByteEncoding::Base58), "12");
+    }
+
+    #[test]
+    fn test_random_bytes_deterministic() {
+        assert_eq!(random_bytes([3u8; 32], 64), random_bytes([3u8; 32], 64));
+        assert_eq!(random_bytes([3u8; 32], 16).len(), 16);
+    }
+}
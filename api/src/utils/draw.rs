@@ -0,0 +1,132 @@
+//! Verifiable random draws for /api/draw
+//!
+//! Every draw runs on `ChaCha8Rng::from_seed(seed)`, so publishing the seed
+//! together with the normalized parameters lets anyone recompute the result:
+//! - Range: each value is `min + below(span)`, where `below(n)` takes
+//!   `next_u64()` values and rejects those above the largest multiple of `n`
+//! - Sample: partial Fisher-Yates over the population; step `i` swaps
+//!   position `i` with `i + random_index(n - i)` and the first `k` are kept
+//! - Shuffle: Fisher-Yates from the end; for `i = n-1..1` swap position `i`
+//!   with `random_index(i + 1)`
+//!
+//! `random_index` is the unbiased `next_u32()` rejection sampler shared with
+//! the other generators.
+
+use crate::utils::random_generator::random_index;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Draw `draws` integers in `[min, max]` with replacement
+pub fn draw_range(seed: [u8; 32], min: i64, max: i64, draws: usize) -> Vec<i64> {
+    debug_assert!(min <= max);
+    let mut rng = ChaCha8Rng::from_seed(seed);
+    // Span minus one fits in u64 even for the full i64 range
+    let span_minus_one = max.wrapping_sub(min) as u64;
+    (0..draws)
+        .map(|_| {
+            let offset = if span_minus_one == u64::MAX {
+                rng.next_u64()
+            } else {
+                random_below(&mut rng, span_minus_one + 1)
+            };
+            min.wrapping_add(offset as i64)
+        })
+        .collect()
+}
+
+/// Pick `k` distinct elements of `population` in draw order
+pub fn sample<T: Clone>(seed: [u8; 32], population: &[T], k: usize) -> Vec<T> {
+    debug_assert!(k <= population.len());
+    let mut rng = ChaCha8Rng::from_seed(seed);
+    let mut pool = population.to_vec();
+    for i in 0..k {
+        let j = i + random_index(&mut rng, pool.len() - i);
+        pool.swap(i, j);
+    }
+    pool.truncate(k);
+    pool
+}
+
+/// Uniformly permute `items` (Fisher-Yates)
+pub fn shuffle<T: Clone>(seed: [u8; 32], items: &[T]) -> Vec<T> {
+    let mut rng = ChaCha8Rng::from_seed(seed);
+    let mut shuffled = items.to_vec();
+    for i in (1..shuffled.len()).rev() {
+        let j = random_index(&mut rng, i + 1);
+        shuffled.swap(i, j);
+    }
+    shuffled
+}
+
+/// Unbiased value in `[0, upper)` from 64-bit draws
+fn random_below<R: RngCore>(rng: &mut R, upper: u64) -> u64 {
+    assert!(upper > 0, "random_below upper bound must be non-zero");
+    // Largest multiple of `upper` that fits in u64 range: values above it are rejected
+    let zone = u64::MAX - (u64::MAX - upper + 1) % upper;
+    loop {
+        let value = rng.next_u64();
+        if value <= zone {
+            return value % upper;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_bounds_and_determinism() {
+        let rolls = draw_range([1u8; 32], 1, 6, 600);
+        assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+        for face in 1..=6 {
+            assert!(rolls.contains(&face));
+        }
+        assert_eq!(rolls, draw_range([1u8; 32], 1, 6, 600));
+        assert_ne!(rolls, draw_range([2u8; 32], 1, 6, 600));
+
+        assert_eq!(draw_range([0u8; 32], -5, -5, 3), vec![-5, -5, -5]);
+        // Full i64 span must not overflow
+        assert_eq!(draw_range([3u8; 32], i64::MIN, i64::MAX, 4).len(), 4);
+    }
+
+    #[test]
+    fn test_random_below_is_uniform_enough() {
+        let mut rng = ChaCha8Rng::from_seed([7u8; 32]);
+        let mut counts = [0usize; 3];
+        for _ in 0..30_000 {
+            counts[random_below(&mut rng, 3) as usize] += 1;
+        }
+        assert!(counts.iter().all(|count| (9_000..11_000).contains(count)));
+    }
+
+    #[test]
+    fn test_sample_is_distinct_prefix_of_population() {
+        let population: Vec<i64> = (1..=49).collect();
+        let picks = sample([4u8; 32], &population, 6);
+        assert_eq!(picks.len(), 6);
+        let mut sorted = picks.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 6);
+        assert!(picks.iter().all(|pick| population.contains(pick)));
+        assert_eq!(picks, sample([4u8; 32], &population, 6));
+    }
+
+    #[test]
+    fn test_shuffle_is_permutation() {
+        let items: Vec<String> = ["alice", "bob", "carol", "dave", "erin"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let shuffled = shuffle([5u8; 32], &items);
+        let mut sorted = shuffled.clone();
+        sorted.sort();
+        assert_eq!(sorted, items);
+        assert_eq!(shuffled, shuffle([5u8; 32], &items));
+        assert!(shuffle([5u8; 32], &[] as &[String]).is_empty());
+
+        // A full sample is a (different) uniform permutation as well
+        assert_eq!(sample([5u8; 32], &items, items.len()).len(), items.len());
+    }
+}
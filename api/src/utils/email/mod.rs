@@ -8,7 +8,10 @@ mod shared_secret;
 
 // Re-export public API (maintains backwards compatibility)
 pub use magic_link::send_magic_link_email;
-pub use shared_secret::{send_shared_secret_receiver_email, send_shared_secret_sender_email};
+pub use shared_secret::{
+    send_shared_secret_receiver_email, send_shared_secret_request_email,
+    send_shared_secret_sender_email,
+};
 
 // Dev-mode only exports
 #[cfg(feature = "dev-mode")]
@@ -15,19 +15,22 @@ use super::dry_run::is_email_dry_run_enabled;
 /// * `secret_url` - The full secret URL for the receiver
 /// * `reference` - The reference hash (Base58)
 /// * `sender_email` - Email of the sender
-/// * `expires_hours` - Expiration time in hours
+/// * `expires_seconds` - Lifetime of the secret in seconds
+/// * `not_before` - Activation timestamp in seconds (None = readable immediately)
 /// * `max_reads` - Maximum number of reads allowed
 /// * `language` - Optional language code for email template (e.g., "es", "en")
 ///
 /// # Returns
 /// * `Ok(())` if the email was sent successfully
 /// * `Err(anyhow::Error)` if there was an error sending the email
+#[allow(clippy::too_many_arguments)]
 pub async fn send_shared_secret_receiver_email(
     recipient_email: &str,
     secret_url: &str,
     reference: &str,
     sender_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
+    not_before: Option<i64>,
     max_reads: i64,
     language: Option<&str>,
 ) -> Result<()> {
@@ -38,7 +41,8 @@ pub async fn send_shared_secret_receiver_email(
         secret_url,
         reference,
         sender_email,
-        expires_hours,
+        expires_seconds,
+        not_before,
         max_reads,
         language.unwrap_or("en"),
     );
@@ -151,7 +155,7 @@ pub async fn send_shared_secret_receiver_email(
 /// * `secret_url` - The full secret URL for the sender
 /// * `reference` - The reference hash (Base58)
 /// * `receiver_email` - Email of the receiver
-/// * `expires_hours` - Expiration time in hours
+/// * `expires_seconds` - Lifetime of the secret in seconds
 /// * `language` - Optional language code for email template (e.g., "es", "en")
 ///
 /// # Returns
@@ -162,7 +166,7 @@ pub async fn send_shared_secret_sender_email(
     secret_url: &str,
     reference: &str,
     receiver_email: &str,
-    expires_hours: i64,
+    expires_seconds: i64,
     language: Option<&str>,
 ) -> Result<()> {
     use crate::email_templates::shared_secret::render_shared_secret_sender_email;
@@ -172,7 +176,7 @@ pub async fn send_shared_secret_sender_email(
         secret_url,
         reference,
         receiver_email,
-        expires_hours,
+        expires_seconds,
         language.unwrap_or("en"),
     );
 
@@ -273,3 +277,136 @@ pub async fn send_shared_secret_sender_email(
         ))
     }
 }
+
+/// Sends a secret request email to the invitee using Mailtrap REST API
+///
+/// # Arguments
+/// * `invitee_email` - The invitee email address
+/// * `request_url` - The full upload form URL for the invitee
+/// * `reference` - The reference hash (Base58)
+/// * `requester_email` - Email of the requester
+/// * `message` - Note from the requester (may be empty)
+/// * `expires_seconds` - Time left to fulfil the request in seconds
+/// * `language` - Optional language code for email template (e.g., "es", "en")
+///
+/// # Returns
+/// * `Ok(())` if the email was sent successfully
+/// * `Err(anyhow::Error)` if there was an error sending the email
+pub async fn send_shared_secret_request_email(
+    invitee_email: &str,
+    request_url: &str,
+    reference: &str,
+    requester_email: &str,
+    message: &str,
+    expires_seconds: i64,
+    language: Option<&str>,
+) -> Result<()> {
+    use crate::email_templates::shared_secret::render_shared_secret_request_email;
+
+    // Render email template (needed for both dry-run and real sending)
+    let (subject, html_content, text_content) = render_shared_secret_request_email(
+        request_url,
+        reference,
+        requester_email,
+        message,
+        expires_seconds,
+        language.unwrap_or("en"),
+    );
+
+    // DEV-MODE ONLY: Check dry-run flag before sending
+    // Production builds: this entire block is removed, email always sent
+    #[cfg(feature = "dev-mode")]
+    {
+        if is_email_dry_run_enabled() {
+            info!(
+                "📧 [DRY-RUN] Secret request email NOT sent → {}",
+                request_url
+            );
+
+            return Ok(());
+        }
+    }
+
+    // ALWAYS executed in production, only if dry-run OFF in development
+    let config = EmailConfig::from_environment()?;
+
+    // Validate email format
+    if invitee_email.is_empty() || !invitee_email.contains('@') {
+        return Err(anyhow!("Invalid invitee email address: {}", invitee_email));
+    }
+
+    // Generate unique Message-ID
+    let message_id = format!(
+        "<{}.{}@mailer.hashrand.com>",
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_else(|| {
+            chrono::Utc::now()
+                .timestamp_millis()
+                .checked_mul(1_000_000)
+                .unwrap_or(0)
+        }),
+        nanoid::nanoid!(8)
+    );
+
+    // Create email payload
+    let email_payload = json!({
+        "from": {
+            "email": config.from_email,
+            "name": "HashRand"
+        },
+        "to": [
+            {
+                "email": invitee_email,
+                "name": invitee_email.split('@').next().unwrap_or("User")
+            }
+        ],
+        "subject": subject,
+        "text": text_content,
+        "html": html_content,
+        "category": "Shared Secret",
+        "headers": {
+            "Message-ID": message_id,
+            "X-Priority": "1"
+        }
+    });
+
+    // Build full URL - same logic as send_magic_link_email
+    let full_url = if config.api_url.contains("send.api.mailtrap.io") {
+        // Custom domain - use URL as-is without inbox ID
+        config.api_url.clone()
+    } else {
+        // Sandbox - append inbox ID
+        format!("{}/{}", config.api_url, config.inbox_id)
+    };
+
+    // Create HTTP request
+    let request = Request::builder()
+        .method(Method::Post)
+        .uri(&full_url)
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", config.api_token))
+        .header("Accept", "application/json")
+        .body(email_payload.to_string())
+        .build();
+
+    // Send HTTP request
+    let response: Response = spin_sdk::http::send(request)
+        .await
+        .map_err(|e| anyhow!("Failed to send HTTP request to Mailtrap API: {}", e))?;
+
+    let status = response.status();
+    if *status >= 200 && *status < 300 {
+        info!(
+            "📧 Secret request email sent to {} → {}",
+            invitee_email, request_url
+        );
+        Ok(())
+    } else {
+        let body_bytes = response.body();
+        let body_str = String::from_utf8_lossy(body_bytes);
+        Err(anyhow!(
+            "Mailtrap API returned error status {}: {}",
+            status,
+            body_str
+        ))
+    }
+}
@@ -1,11 +1,12 @@
 //! Shared helper functions for endpoint handlers
 //!
 //! Contains common functionality used across all generation endpoint handlers:
-//! - Query parameter extraction
+//! - Query parameter extraction and bounded integer parsing
 //! - Error response creation
 //! - Pattern validation for security
 //! - Pattern-avoiding generation logic
 
+use crate::types::GenerationAlgorithm;
 use crate::utils::auth::ErrorResponse;
 use crate::utils::generate_with_seed;
 use spin_sdk::http::{Request, Response};
@@ -66,11 +67,31 @@ pub fn create_forbidden_response(message: &str) -> Response {
     create_error_response(403, message)
 }
 
+/// Create too-early response (425) for a shared secret before its activation time
+///
+/// Carries `not_before` (Unix seconds) so clients can tell users when to come back.
+pub fn create_not_yet_active_response(message: &str, not_before: i64) -> Response {
+    Response::builder()
+        .status(425)
+        .header("content-type", "application/json")
+        .body(
+            serde_json::json!({
+                "error": message,
+                "not_before": not_before,
+            })
+            .to_string(),
+        )
+        .build()
+}
+
 /// Helper function to check unwanted patterns for security
 pub fn contains_unwanted_patterns(s: &str) -> bool {
     s.contains("--") || s.contains("__")
 }
 
+/// Candidates tried by pattern avoidance before falling back to an unfiltered value
+pub const PATTERN_AVOIDANCE_ATTEMPTS: usize = 50;
+
 /// Generate hash avoiding unwanted patterns using seeded generator
 /// Used for passwords and custom hashes with FullWithSymbols alphabet
 pub fn generate_avoiding_unwanted_patterns(
@@ -79,19 +100,19 @@ pub fn generate_avoiding_unwanted_patterns(
     prefix: &str,
     suffix: &str,
     seed: [u8; 32],
+    algorithm: GenerationAlgorithm,
 ) -> String {
-    const MAX_ATTEMPTS: usize = 50;
-    for attempt in 1..=MAX_ATTEMPTS {
+    for attempt in 1..=PATTERN_AVOIDANCE_ATTEMPTS {
         let mut attempt_seed = seed;
         attempt_seed[0] = attempt_seed[0].wrapping_add(attempt as u8);
-        let hash = generate_with_seed(attempt_seed, length, alphabet);
+        let hash = generate_with_seed(attempt_seed, length, alphabet, algorithm);
         let result = format!("{}{}{}", prefix, hash, suffix);
         if !contains_unwanted_patterns(&result) {
             return result;
         }
     }
     // Fallback: return result even with unwanted patterns
-    let hash = generate_with_seed(seed, length, alphabet);
+    let hash = generate_with_seed(seed, length, alphabet, algorithm);
     format!("{}{}{}", prefix, hash, suffix)
 }
 
@@ -100,8 +121,59 @@ pub fn generate_password_avoiding_patterns(
     length: usize,
     alphabet: &[char],
     seed: [u8; 32],
+    algorithm: GenerationAlgorithm,
 ) -> String {
-    generate_avoiding_unwanted_patterns(length, alphabet, "", "", seed)
+    generate_avoiding_unwanted_patterns(length, alphabet, "", "", seed, algorithm)
+}
+
+/// Parse the optional `algorithm` parameter (DRY helper)
+///
+/// Fresh seeds default to the latest algorithm. A caller-supplied seed without
+/// an algorithm (explicit or recorded in its envelope) is a bare version 0 seed,
+/// issued before versioning existed, so it defaults to `ModuloV1`. That default
+/// only applies when none of the endpoint's `post_versioning` parameters are
+/// present: requests using them have no legacy output to reproduce.
+pub fn parse_generation_algorithm(
+    params: &HashMap<String, String>,
+    post_versioning: &[&str],
+) -> Result<GenerationAlgorithm, String> {
+    match params.get("algorithm") {
+        Some(algorithm_str) => algorithm_str
+            .parse::<u8>()
+            .ok()
+            .and_then(|version| GenerationAlgorithm::try_from(version).ok())
+            .ok_or_else(|| {
+                "Invalid algorithm parameter. Must be 1 (legacy modulo) or 2 (unbiased)".to_string()
+            }),
+        None if params.contains_key("seed")
+            && !post_versioning
+                .iter()
+                .any(|name| params.contains_key(*name)) =>
+        {
+            Ok(GenerationAlgorithm::ModuloV1)
+        }
+        None => Ok(GenerationAlgorithm::latest()),
+    }
+}
+
+/// Parse an optional integer parameter within `min..=max`
+pub fn parse_bounded(
+    params: &HashMap<String, String>,
+    name: &str,
+    min: usize,
+    max: usize,
+    default: usize,
+) -> Result<usize, String> {
+    match params.get(name) {
+        Some(value) => match value.parse::<usize>() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n),
+            _ => Err(format!(
+                "Invalid {} parameter. Must be integer {}-{}",
+                name, min, max
+            )),
+        },
+        None => Ok(default),
+    }
 }
 
 /// Universal handler for GET requests with Ed25519 signature validation + SignedResponse
@@ -142,12 +214,47 @@ where
         )));
     }
 
-    // Generate fresh seed if not provided
-    if !validated_params.contains_key("seed") {
-        let seed = crate::utils::generate_random_seed();
-        validated_params.insert("seed".to_string(), crate::utils::seed_to_base58(&seed));
+    // Call the specific generation function
+    generate_signed_fn(&validated_params, &crypto_material)
+}
+
+/// Universal handler for POST requests with SignedRequest validation + SignedResponse
+/// Validates JWT + Ed25519 signature, converts the payload to parameters and
+/// calls the generator (which draws a fresh seed when none is provided)
+pub async fn handle_signed_post_request<F>(
+    req: &Request,
+    generate_signed_fn: F,
+) -> anyhow::Result<Response>
+where
+    F: FnOnce(&HashMap<String, String>, &crate::utils::CryptoMaterial) -> anyhow::Result<Response>,
+{
+    use crate::utils::protected_endpoint::{extract_seed_from_payload, payload_to_params};
+
+    // Validate signed request using protected middleware
+    let result: crate::utils::ProtectedEndpointResult<serde_json::Value> =
+        match crate::utils::ProtectedEndpointMiddleware::validate_request(req, req.body()).await {
+            Ok(result) => result,
+            Err(error_response) => return Ok(error_response),
+        };
+
+    // Extract crypto material for signing response
+    let crypto_material = match crate::utils::extract_crypto_material_from_request(req) {
+        Ok(material) => material,
+        Err(e) => {
+            return Ok(create_auth_error_response(&format!(
+                "Crypto extraction failed: {}",
+                e
+            )));
+        }
+    };
+
+    // Convert payload to parameters and validate a caller-supplied seed
+    // (a missing seed stays missing: the generator draws a fresh one)
+    let params = payload_to_params(&result.payload);
+    if let Err(e) = extract_seed_from_payload(&result.payload) {
+        return Ok(create_client_error_response(&e));
     }
 
     // Call the specific generation function
-    generate_signed_fn(&validated_params, &crypto_material)
+    generate_signed_fn(&params, &crypto_material)
 }
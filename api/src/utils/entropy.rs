@@ -0,0 +1,212 @@
+//! Effective entropy estimation for generated values
+//!
+//! Entropy is measured on the output distribution, not the seed:
+//! - Per-character entropy depends on alphabet size and algorithm version
+//!   (the legacy modulo algorithm is biased and yields slightly less)
+//! - Fixed prefixes/suffixes contribute nothing
+//! - Pattern avoidance (`--`/`__` retries) removes part of the output space
+
+use crate::types::GenerationAlgorithm;
+use crate::utils::endpoint_helpers::{PATTERN_AVOIDANCE_ATTEMPTS, contains_unwanted_patterns};
+use std::collections::HashMap;
+
+/// Upper bound accepted for the `min_entropy` parameter (bits)
+const MAX_MIN_ENTROPY_BITS: f64 = 1024.0;
+
+/// Entropy in bits contributed by one character drawn from an alphabet
+///
+/// # Arguments
+/// * `alphabet_size` - Number of distinct characters (2-256)
+/// * `algorithm` - Generation algorithm version used to draw the character
+pub fn bits_per_char(alphabet_size: usize, algorithm: GenerationAlgorithm) -> f64 {
+    match algorithm {
+        GenerationAlgorithm::ModuloV1 => {
+            // random_byte % n: the first 256 % n symbols are drawn once more often
+            let quotient = 256 / alphabet_size;
+            let remainder = 256 % alphabet_size;
+            let shannon = |count: usize, probability: f64| {
+                if probability > 0.0 {
+                    -(count as f64) * probability * probability.log2()
+                } else {
+                    0.0
+                }
+            };
+            shannon(remainder, (quotient + 1) as f64 / 256.0)
+                + shannon(alphabet_size - remainder, quotient as f64 / 256.0)
+        }
+        GenerationAlgorithm::RejectionV2 => (alphabet_size as f64).log2(),
+    }
+}
+
+/// Effective entropy of `prefix + random(length) + suffix`
+///
+/// # Arguments
+/// * `alphabet` - Alphabet the random part is drawn from
+/// * `length` - Number of random characters
+/// * `prefix`/`suffix` - Fixed text around the random part (adds no entropy)
+/// * `avoid_patterns` - Whether `generate_avoiding_unwanted_patterns` was used
+/// * `algorithm` - Generation algorithm version
+pub fn string_entropy_bits(
+    alphabet: &[char],
+    length: usize,
+    prefix: &str,
+    suffix: &str,
+    avoid_patterns: bool,
+    algorithm: GenerationAlgorithm,
+) -> f64 {
+    let raw_bits = length as f64 * bits_per_char(alphabet.len(), algorithm);
+    if !avoid_patterns {
+        return raw_bits;
+    }
+    (raw_bits - pattern_avoidance_penalty(alphabet, length, prefix, suffix)).max(0.0)
+}
+
+/// Entropy (bits) lost by retrying candidates that contain `--` or `__`
+///
+/// Models the retry loop exactly for uniform draws: up to
+/// `PATTERN_AVOIDANCE_ATTEMPTS` independent candidates, then an unconstrained
+/// fallback. With acceptance probability `p` and `q = (1-p)^attempts`:
+/// `loss = (1-q+pq)·log2((1-q)/p + q) + (1-p)·q·log2(q)`
+fn pattern_avoidance_penalty(alphabet: &[char], length: usize, prefix: &str, suffix: &str) -> f64 {
+    // Fixed parts that already contain a pattern make every attempt fail
+    if contains_unwanted_patterns(prefix) || contains_unwanted_patterns(suffix) {
+        return 0.0;
+    }
+
+    let p = acceptance_probability(alphabet, length, prefix, suffix);
+    if p >= 1.0 {
+        return 0.0;
+    }
+
+    let attempts = PATTERN_AVOIDANCE_ATTEMPTS as f64;
+    let log_q = attempts * (-p).ln_1p();
+    let q = log_q.exp();
+    let one_minus_q = -log_q.exp_m1();
+
+    (one_minus_q + p * q) * (one_minus_q / p + q).log2()
+        + (1.0 - p) * q * (log_q / std::f64::consts::LN_2)
+}
+
+/// Probability that a uniform random string avoids `--`/`__` once framed
+/// by `prefix` and `suffix`
+fn acceptance_probability(alphabet: &[char], length: usize, prefix: &str, suffix: &str) -> f64 {
+    // States: last character is '-', '_' or anything else
+    const DASH: usize = 0;
+    const UNDERSCORE: usize = 1;
+    const OTHER: usize = 2;
+    let state_of = |c: Option<char>| match c {
+        Some('-') => DASH,
+        Some('_') => UNDERSCORE,
+        _ => OTHER,
+    };
+
+    let size = alphabet.len() as f64;
+    let dash = alphabet.iter().filter(|c| **c == '-').count() as f64 / size;
+    let underscore = alphabet.iter().filter(|c| **c == '_').count() as f64 / size;
+    let other = 1.0 - dash - underscore;
+
+    let mut states = [0.0f64; 3];
+    states[state_of(prefix.chars().last())] = 1.0;
+
+    for _ in 0..length {
+        let total: f64 = states.iter().sum();
+        states = [
+            (total - states[DASH]) * dash,
+            (total - states[UNDERSCORE]) * underscore,
+            total * other,
+        ];
+    }
+
+    // The suffix's first character must not extend a trailing '-' or '_'
+    match state_of(suffix.chars().next()) {
+        OTHER => states.iter().sum(),
+        blocked => states.iter().sum::<f64>() - states[blocked],
+    }
+}
+
+/// Parse the optional `min_entropy` floor (bits)
+pub fn parse_min_entropy(params: &HashMap<String, String>) -> Result<Option<f64>, String> {
+    match params.get("min_entropy") {
+        Some(value) => match value.parse::<f64>() {
+            Ok(bits) if bits.is_finite() && (0.0..=MAX_MIN_ENTROPY_BITS).contains(&bits) => {
+                Ok(Some(bits))
+            }
+            _ => Err(format!(
+                "Invalid min_entropy parameter. Must be a number of bits between 0 and {}",
+                MAX_MIN_ENTROPY_BITS
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AlphabetType;
+
+    const V1: GenerationAlgorithm = GenerationAlgorithm::ModuloV1;
+    const V2: GenerationAlgorithm = GenerationAlgorithm::RejectionV2;
+
+    #[test]
+    fn test_bits_per_char() {
+        assert!((bits_per_char(58, V2) - 58f64.log2()).abs() < 1e-12);
+        // Power-of-two alphabets are unbiased even with the legacy algorithm
+        assert!((bits_per_char(64, V1) - 6.0).abs() < 1e-12);
+        assert!(bits_per_char(58, V1) < bits_per_char(58, V2));
+        assert!(bits_per_char(58, V1) > 5.8);
+    }
+
+    #[test]
+    fn test_base58_default_entropy() {
+        let alphabet = AlphabetType::Base58.as_chars();
+        let bits = string_entropy_bits(&alphabet, 21, "pre", "post", false, V2);
+        assert!((bits - 21.0 * 58f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pattern_avoidance_penalty_is_small_for_symbols() {
+        let alphabet = AlphabetType::FullWithSymbols.as_chars();
+        let raw = 32.0 * 73f64.log2();
+        let bits = string_entropy_bits(&alphabet, 32, "", "", true, V2);
+        assert!(bits < raw);
+        assert!(raw - bits < 0.1);
+    }
+
+    #[test]
+    fn test_acceptance_probability_matches_enumeration() {
+        // Brute force every string of length 4 over "-_a" framed by "x-"/"_y"
+        let alphabet = ['-', '_', 'a'];
+        let mut accepted = 0;
+        for i in 0..81 {
+            let s: String = (0..4).map(|k| alphabet[(i / 3usize.pow(k)) % 3]).collect();
+            if !contains_unwanted_patterns(&format!("x-{}_y", s)) {
+                accepted += 1;
+            }
+        }
+        let expected = accepted as f64 / 81.0;
+        assert!((acceptance_probability(&alphabet, 4, "x-", "_y") - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_unavoidable_patterns_fall_back_to_raw_entropy() {
+        let alphabet = ['-', '_'];
+        let raw = 64.0;
+        // Only 2 of 2^64 strings alternate: nearly every request hits the fallback
+        let bits = string_entropy_bits(&alphabet, 64, "", "", true, V2);
+        assert!((raw - bits).abs() < 1e-6);
+        // A 50% acceptance rate costs about one bit
+        let bits = string_entropy_bits(&['-', 'a'], 1, "-", "", true, V2);
+        assert!((bits - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_min_entropy() {
+        let params = |value: &str| HashMap::from([("min_entropy".to_string(), value.to_string())]);
+        assert_eq!(parse_min_entropy(&HashMap::new()).unwrap(), None);
+        assert_eq!(parse_min_entropy(&params("80")).unwrap(), Some(80.0));
+        assert!(parse_min_entropy(&params("-1")).is_err());
+        assert!(parse_min_entropy(&params("NaN")).is_err());
+        assert!(parse_min_entropy(&params("lots")).is_err());
+    }
+}
@@ -0,0 +1,395 @@
+//! Shared single/batch generation pipeline for generator endpoints
+//!
+//! Every generator endpoint (custom, password, api-key, mnemonic, passphrase)
+//! provides a pure `GeneratorFn` that turns request parameters plus a 32-byte
+//! seed into one value. This module wraps it with:
+//! - Seed resolution and OTP/timestamp generation
+//! - Versioned seed envelopes (generator + algorithm travel with the seed)
+//! - Batch generation (`count` parameter) with per-item seed derivation
+//! - Entropy floor enforcement (`min_entropy` parameter)
+//! - SignedResponse creation
+
+use crate::types::{BatchHashResponse, CustomHashResponse, GenerationAlgorithm, GeneratorType};
+use crate::utils::entropy::parse_min_entropy;
+use crate::utils::seed_envelope::{SeedEnvelope, unwrap_seed_envelope};
+use crate::utils::{
+    CryptoMaterial, create_client_error_response, create_server_error_response,
+    create_signed_endpoint_response, derive_batch_item_seed, generate_otp, generate_random_seed,
+};
+use spin_sdk::http::Response;
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum number of values returned by a single batch request
+pub const MAX_BATCH_COUNT: usize = 500;
+
+/// Value produced by a generator for a single seed
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedValue {
+    /// The generated hash/password/key/phrase
+    pub value: String,
+    /// Generation algorithm version, for generators that depend on it
+    pub algorithm: Option<GenerationAlgorithm>,
+    /// Effective entropy in bits, when known
+    pub entropy_bits: Option<f64>,
+    /// Timestamp embedded in time-ordered identifiers (ms since Unix epoch)
+    pub time_ms: Option<u64>,
+    /// False when the value does not derive from the seed (digest of caller input)
+    pub seeded: bool,
+}
+
+impl GeneratedValue {
+    /// Creates a GeneratedValue without algorithm/entropy metadata
+    pub fn new(value: String) -> Self {
+        Self {
+            value,
+            algorithm: None,
+            entropy_bits: None,
+            time_ms: None,
+            seeded: true,
+        }
+    }
+
+    /// Attaches the generation algorithm version
+    pub fn with_algorithm(mut self, algorithm: GenerationAlgorithm) -> Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Attaches the effective entropy in bits
+    pub fn with_entropy_bits(mut self, entropy_bits: f64) -> Self {
+        self.entropy_bits = Some(entropy_bits);
+        self
+    }
+
+    /// Attaches the timestamp embedded in the value
+    pub fn with_time_ms(mut self, time_ms: u64) -> Self {
+        self.time_ms = Some(time_ms);
+        self
+    }
+
+    /// Marks a value that does not derive from the seed: no seed or OTP is returned
+    pub fn unseeded(mut self) -> Self {
+        self.seeded = false;
+        self
+    }
+}
+
+/// Pure generator: request parameters + seed → value (or a 400 error message)
+pub type GeneratorFn = fn(&HashMap<String, String>, [u8; 32]) -> Result<GeneratedValue, String>;
+
+/// Parse the optional `count` parameter (defaults to 1, bounded by MAX_BATCH_COUNT)
+pub fn parse_batch_count(params: &HashMap<String, String>) -> Result<usize, String> {
+    match params.get("count") {
+        Some(count_str) => match count_str.parse::<usize>() {
+            Ok(count) if (1..=MAX_BATCH_COUNT).contains(&count) => Ok(count),
+            _ => Err(format!(
+                "Invalid count parameter. Must be integer 1-{}",
+                MAX_BATCH_COUNT
+            )),
+        },
+        None => Ok(1),
+    }
+}
+
+/// Get the request seed (bare base58 seed or seed envelope) or generate a fresh one
+///
+/// An envelope issued by a different generator than `generator_type` is rejected.
+pub fn resolve_seed(
+    params: &HashMap<String, String>,
+    generator_type: GeneratorType,
+) -> Result<[u8; 32], String> {
+    let Some(seed_str) = params.get("seed") else {
+        return Ok(generate_random_seed());
+    };
+    let envelope = SeedEnvelope::decode(seed_str).map_err(|e| format!("Invalid seed: {}", e))?;
+    match envelope.generator {
+        Some(recorded) if recorded != generator_type => Err(generator_mismatch(recorded)),
+        _ => Ok(envelope.seed),
+    }
+}
+
+/// Error for a seed envelope presented to an endpoint other than its issuer
+pub fn generator_mismatch(recorded: GeneratorType) -> String {
+    if recorded.reproducible_from_seed() {
+        format!(
+            "Seed was issued by {}; reproduce it there or via /api/from-seed",
+            recorded.endpoint()
+        )
+    } else {
+        format!(
+            "Seed was issued by {}; reproduce it there",
+            recorded.endpoint()
+        )
+    }
+}
+
+/// Generate `count` values from one seed
+///
+/// A single value uses the seed directly (unchanged single-value behaviour);
+/// batches use `derive_batch_item_seed(seed, i)` for item `i`.
+pub fn generate_values(
+    generator: GeneratorFn,
+    params: &HashMap<String, String>,
+    seed: [u8; 32],
+    count: usize,
+) -> Result<Vec<GeneratedValue>, String> {
+    if count == 1 {
+        return Ok(vec![generator(params, seed)?]);
+    }
+
+    (0..count as u32)
+        .map(|index| generator(params, derive_batch_item_seed(seed, index)))
+        .collect()
+}
+
+/// Check a generated value against the optional `min_entropy` floor
+///
+/// Values without an entropy estimate are rejected whenever a floor is set.
+pub fn check_min_entropy(value: &GeneratedValue, min_entropy: Option<f64>) -> Result<(), String> {
+    let Some(floor) = min_entropy else {
+        return Ok(());
+    };
+    match value.entropy_bits {
+        Some(bits) if bits >= floor => Ok(()),
+        Some(bits) => Err(format!(
+            "Requested parameters provide {:.2} bits of entropy, below min_entropy {}",
+            bits, floor
+        )),
+        None => Err("Entropy cannot be estimated for these parameters".to_string()),
+    }
+}
+
+/// Batch-level `(algorithm, entropy_bits, time_ms)` metadata
+///
+/// Generators derive `algorithm` and `time_ms` from the request parameters only
+/// (the identifier endpoint pins `time_ms` before the batch runs), so every item
+/// agrees on them. Entropy may differ per item, so the weakest item is reported
+/// (`None` if any item has no estimate).
+pub fn batch_metadata(
+    values: &[GeneratedValue],
+) -> (Option<GenerationAlgorithm>, Option<f64>, Option<u64>) {
+    let first = &values[0];
+    debug_assert!(
+        values
+            .iter()
+            .all(|v| v.algorithm == first.algorithm && v.time_ms == first.time_ms),
+        "batch items must share algorithm and time_ms"
+    );
+    let entropy_bits = values
+        .iter()
+        .map(|v| v.entropy_bits)
+        .try_fold(f64::INFINITY, |min, bits| bits.map(|bits| min.min(bits)));
+    (first.algorithm, entropy_bits, first.time_ms)
+}
+
+/// Resolve the seed and run a generator for every requested value
+///
+/// Unwraps a seed envelope (rejecting one issued by another generator),
+/// applies `count` and enforces `min_entropy`.
+///
+/// # Returns
+/// * `Result<([u8; 32], Vec<GeneratedValue>), String>` - Seed and generated value(s)
+pub fn generate_from_params(
+    params: &HashMap<String, String>,
+    generator_type: GeneratorType,
+    generator: GeneratorFn,
+) -> Result<([u8; 32], Vec<GeneratedValue>), String> {
+    let mut params = params.clone();
+    match unwrap_seed_envelope(&mut params)? {
+        Some(recorded) if recorded != generator_type => return Err(generator_mismatch(recorded)),
+        _ => {}
+    }
+    let params = &params;
+
+    let count = parse_batch_count(params)?;
+    let min_entropy = parse_min_entropy(params)?;
+    let seed_32 = resolve_seed(params, generator_type)?;
+    let values = generate_values(generator, params, seed_32, count)?;
+
+    // Reject parameter combinations weaker than the requested entropy floor
+    for value in &values {
+        check_min_entropy(value, min_entropy)?;
+    }
+
+    Ok((seed_32, values))
+}
+
+/// Run a generator with the request parameters and return a SignedResponse
+///
+/// Returns a `CustomHashResponse` for single values and a `BatchHashResponse`
+/// when `count` > 1. The returned `seed` is a `SeedEnvelope` recording
+/// `generator_type` and the algorithm version that produced the value(s).
+pub fn create_generation_signed_response(
+    params: &HashMap<String, String>,
+    crypto_material: &CryptoMaterial,
+    generator_type: GeneratorType,
+    generator: GeneratorFn,
+) -> anyhow::Result<Response> {
+    let (seed_32, mut values) = match generate_from_params(params, generator_type, generator) {
+        Ok(generated) => generated,
+        Err(e) => return Ok(create_client_error_response(&e)),
+    };
+
+    // Generate OTP and timestamp
+    let seed_base58 = SeedEnvelope::new(seed_32, generator_type, values[0].algorithm).encode();
+    let otp = generate_otp(seed_32);
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| anyhow::anyhow!("Time error: {}", e))?
+        .as_secs();
+
+    // Create payload and signed response using DRY helper
+    let signed_response = if values.len() == 1 {
+        let value = values.remove(0);
+        let mut payload = CustomHashResponse::new(value.value, seed_base58, otp, timestamp);
+        if let Some(algorithm) = value.algorithm {
+            payload = payload.with_algorithm(algorithm);
+        }
+        if let Some(entropy_bits) = value.entropy_bits {
+            payload = payload.with_entropy_bits(entropy_bits);
+        }
+        payload.time_ms = value.time_ms;
+        if !value.seeded {
+            payload = payload.without_seed();
+        }
+        create_signed_endpoint_response(payload, crypto_material)
+    } else {
+        let (algorithm, entropy_bits, time_ms) = batch_metadata(&values);
+        let hashes = values.into_iter().map(|v| v.value).collect();
+        let mut payload = BatchHashResponse::new(hashes, seed_base58, otp, timestamp);
+        payload.time_ms = time_ms;
+        if let Some(algorithm) = algorithm {
+            payload = payload.with_algorithm(algorithm);
+        }
+        if let Some(entropy_bits) = entropy_bits {
+            payload = payload.with_entropy_bits(entropy_bits);
+        }
+        create_signed_endpoint_response(payload, crypto_material)
+    };
+
+    match signed_response {
+        Ok(response) => Ok(response),
+        Err(e) => Ok(create_server_error_response(&format!(
+            "Failed to create signed response: {}",
+            e
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::seed_to_base58;
+    use crate::utils::test_utils::params;
+
+    fn echo_seed(
+        _params: &HashMap<String, String>,
+        seed: [u8; 32],
+    ) -> Result<GeneratedValue, String> {
+        Ok(GeneratedValue::new(seed_to_base58(&seed)))
+    }
+
+    #[test]
+    fn test_parse_batch_count() {
+        assert_eq!(parse_batch_count(&params(&[])).unwrap(), 1);
+        assert_eq!(
+            parse_batch_count(&params(&[("count", "500")])).unwrap(),
+            500
+        );
+        assert!(parse_batch_count(&params(&[("count", "0")])).is_err());
+        assert!(parse_batch_count(&params(&[("count", "501")])).is_err());
+        assert!(parse_batch_count(&params(&[("count", "ten")])).is_err());
+    }
+
+    #[test]
+    fn test_single_value_uses_seed_directly() {
+        let seed = [11u8; 32];
+        let values = generate_values(echo_seed, &params(&[]), seed, 1).unwrap();
+        assert_eq!(values, vec![GeneratedValue::new(seed_to_base58(&seed))]);
+    }
+
+    #[test]
+    fn test_batch_values_are_distinct_and_reproducible() {
+        let seed = [11u8; 32];
+        let first = generate_values(echo_seed, &params(&[]), seed, 50).unwrap();
+        let second = generate_values(echo_seed, &params(&[]), seed, 50).unwrap();
+        assert_eq!(first, second);
+
+        let mut unique: Vec<String> = first.iter().map(|v| v.value.clone()).collect();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), 50);
+        assert_eq!(
+            first[7].value,
+            seed_to_base58(&derive_batch_item_seed(seed, 7))
+        );
+    }
+
+    #[test]
+    fn test_check_min_entropy() {
+        let value = GeneratedValue::new("x".to_string()).with_entropy_bits(80.0);
+        assert!(check_min_entropy(&value, None).is_ok());
+        assert!(check_min_entropy(&value, Some(80.0)).is_ok());
+        assert!(check_min_entropy(&value, Some(96.0)).is_err());
+        assert!(check_min_entropy(&GeneratedValue::new("x".to_string()), Some(1.0)).is_err());
+    }
+
+    fn seed_dependent_entropy(
+        _params: &HashMap<String, String>,
+        seed: [u8; 32],
+    ) -> Result<GeneratedValue, String> {
+        Ok(GeneratedValue::new(seed_to_base58(&seed)).with_entropy_bits(f64::from(seed[0] % 64)))
+    }
+
+    #[test]
+    fn test_batch_min_entropy_checks_every_item() {
+        let seed = [3u8; 32];
+        let values = generate_values(seed_dependent_entropy, &params(&[]), seed, 20).unwrap();
+        let (_, weakest, _) = batch_metadata(&values);
+        let weakest = weakest.unwrap();
+        assert!(values.iter().any(|v| v.entropy_bits.unwrap() > weakest));
+
+        // A floor above the weakest item fails even if the first item passes
+        let request = params(&[
+            ("seed", &seed_to_base58(&seed)),
+            ("count", "20"),
+            ("min_entropy", &(weakest + 1.0).to_string()),
+        ]);
+        assert!(
+            generate_from_params(&request, GeneratorType::Custom, seed_dependent_entropy).is_err()
+        );
+        let request = params(&[
+            ("seed", &seed_to_base58(&seed)),
+            ("count", "20"),
+            ("min_entropy", &weakest.to_string()),
+        ]);
+        assert!(
+            generate_from_params(&request, GeneratorType::Custom, seed_dependent_entropy).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_batch_metadata_without_estimate() {
+        let values = vec![
+            GeneratedValue::new("a".to_string()).with_entropy_bits(40.0),
+            GeneratedValue::new("b".to_string()),
+        ];
+        assert_eq!(batch_metadata(&values), (None, None, None));
+    }
+
+    #[test]
+    fn test_resolve_seed_rejects_foreign_envelope() {
+        let seed = [5u8; 32];
+        let bare = params(&[("seed", &seed_to_base58(&seed))]);
+        assert_eq!(resolve_seed(&bare, GeneratorType::Draw).unwrap(), seed);
+
+        let envelope = SeedEnvelope::new(seed, GeneratorType::Draw, None).encode();
+        let enveloped = params(&[("seed", &envelope)]);
+        assert_eq!(resolve_seed(&enveloped, GeneratorType::Draw).unwrap(), seed);
+        assert_eq!(
+            resolve_seed(&enveloped, GeneratorType::Keypair).unwrap_err(),
+            "Seed was issued by /api/draw; reproduce it there"
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Standard identifier generation (UUIDv4/v7, ULID, KSUID)
+//!
+//! Random components come from the seeded ChaCha8 generator, so an identifier
+//! is reproducible from its seed plus the embedded timestamp (`time_ms`).
+
+use crate::types::IdentifierFormat;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// KSUID epoch (2014-05-13T16:53:20Z) in seconds since the Unix epoch
+const KSUID_EPOCH_SECS: u64 = 1_400_000_000;
+
+/// Largest timestamp representable in 48 bits (UUIDv7, ULID)
+pub const MAX_TIME_MS_48: u64 = (1 << 48) - 1;
+
+/// Crockford base32 alphabet (ULID)
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Base62 alphabet (KSUID)
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Generate an identifier in its canonical encoding
+///
+/// # Arguments
+/// * `format` - Identifier format
+/// * `seed` - 32-byte seed for ChaCha8Rng (random component)
+/// * `time_ms` - Unix time in milliseconds (ignored by UUIDv4)
+///
+/// # Returns
+/// * `Result<String, String>` - Identifier or error if `time_ms` does not fit the format
+pub fn generate_identifier(
+    format: IdentifierFormat,
+    seed: [u8; 32],
+    time_ms: u64,
+) -> Result<String, String> {
+    let mut rng = ChaCha8Rng::from_seed(seed);
+
+    match format {
+        IdentifierFormat::UuidV4 => {
+            let mut bytes = [0u8; 16];
+            rng.fill_bytes(&mut bytes);
+            Ok(format_uuid(set_uuid_version(bytes, 4)))
+        }
+        IdentifierFormat::UuidV7 => {
+            let mut bytes = [0u8; 16];
+            rng.fill_bytes(&mut bytes[6..]);
+            bytes[..6].copy_from_slice(&time_ms_48(time_ms)?);
+            Ok(format_uuid(set_uuid_version(bytes, 7)))
+        }
+        IdentifierFormat::Ulid => {
+            let mut bytes = [0u8; 16];
+            rng.fill_bytes(&mut bytes[6..]);
+            bytes[..6].copy_from_slice(&time_ms_48(time_ms)?);
+            Ok(encode_ulid(u128::from_be_bytes(bytes)))
+        }
+        IdentifierFormat::Ksuid => {
+            let secs = (time_ms / 1000)
+                .checked_sub(KSUID_EPOCH_SECS)
+                .and_then(|secs| u32::try_from(secs).ok())
+                .ok_or("time_ms is outside the KSUID range (2014-05-13 to 2150-06-19)")?;
+            let mut bytes = [0u8; 20];
+            bytes[..4].copy_from_slice(&secs.to_be_bytes());
+            rng.fill_bytes(&mut bytes[4..]);
+            Ok(encode_ksuid(bytes))
+        }
+    }
+}
+
+/// Big-endian 48-bit timestamp
+fn time_ms_48(time_ms: u64) -> Result<[u8; 6], String> {
+    if time_ms > MAX_TIME_MS_48 {
+        return Err("time_ms does not fit in 48 bits".to_string());
+    }
+    let bytes = time_ms.to_be_bytes();
+    Ok([bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+}
+
+/// Set the RFC 9562 version nibble and variant bits
+fn set_uuid_version(mut bytes: [u8; 16], version: u8) -> [u8; 16] {
+    bytes[6] = (bytes[6] & 0x0F) | (version << 4);
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    bytes
+}
+
+/// Canonical 8-4-4-4-12 lowercase hex encoding
+fn format_uuid(bytes: [u8; 16]) -> String {
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// 26-character Crockford base32 encoding of a 128-bit ULID
+fn encode_ulid(value: u128) -> String {
+    (0..26)
+        .rev()
+        .map(|i| CROCKFORD_BASE32[((value >> (i * 5)) & 0x1F) as usize] as char)
+        .collect()
+}
+
+/// 27-character zero-padded base62 encoding of a 20-byte KSUID
+fn encode_ksuid(bytes: [u8; 20]) -> String {
+    // Repeated long division of the big-endian number by 62
+    let mut number = bytes.to_vec();
+    let mut digits = Vec::with_capacity(27);
+    while number.iter().any(|b| *b != 0) {
+        let mut remainder = 0u32;
+        for byte in number.iter_mut() {
+            let accumulator = (remainder << 8) | *byte as u32;
+            *byte = (accumulator / 62) as u8;
+            remainder = accumulator % 62;
+        }
+        digits.push(BASE62[remainder as usize]);
+    }
+    digits.resize(27, b'0');
+    digits.iter().rev().map(|b| *b as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIME_MS: u64 = 1_700_000_000_123;
+
+    #[test]
+    fn test_uuid_v4_layout() {
+        let id = generate_identifier(IdentifierFormat::UuidV4, [1u8; 32], 0).unwrap();
+        assert_eq!(id.len(), 36);
+        assert_eq!(&id[14..15], "4");
+        assert!("89ab".contains(&id[19..20]));
+        assert_eq!(
+            id,
+            generate_identifier(IdentifierFormat::UuidV4, [1u8; 32], TIME_MS).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_uuid_v7_embeds_time() {
+        let id = generate_identifier(IdentifierFormat::UuidV7, [1u8; 32], TIME_MS).unwrap();
+        assert_eq!(&id[14..15], "7");
+        assert!("89ab".contains(&id[19..20]));
+        let time_hex = format!("{}{}", &id[0..8], &id[9..13]);
+        assert_eq!(u64::from_str_radix(&time_hex, 16).unwrap(), TIME_MS);
+    }
+
+    #[test]
+    fn test_ulid_encoding() {
+        let id = generate_identifier(IdentifierFormat::Ulid, [1u8; 32], TIME_MS).unwrap();
+        assert_eq!(id.len(), 26);
+        assert!(id.bytes().all(|b| CROCKFORD_BASE32.contains(&b)));
+        // First 10 characters encode the 48-bit timestamp
+        assert_eq!(&id[..10], &encode_ulid((TIME_MS as u128) << 80)[..10]);
+        assert_eq!(encode_ulid(u128::MAX), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    }
+
+    #[test]
+    fn test_ksuid_encoding() {
+        // Reference values from the KSUID specification
+        assert_eq!(encode_ksuid([0u8; 20]), "000000000000000000000000000");
+        assert_eq!(encode_ksuid([0xFF; 20]), "aWgEPTl1tmebfsQzFP4bxwgy80V");
+
+        let id = generate_identifier(IdentifierFormat::Ksuid, [1u8; 32], TIME_MS).unwrap();
+        assert_eq!(id.len(), 27);
+        assert!(generate_identifier(IdentifierFormat::Ksuid, [1u8; 32], 0).is_err());
+    }
+
+    #[test]
+    fn test_time_out_of_range() {
+        assert!(generate_identifier(IdentifierFormat::UuidV7, [1u8; 32], 1 << 48).is_err());
+        assert!(generate_identifier(IdentifierFormat::Ulid, [1u8; 32], MAX_TIME_MS_48).is_ok());
+    }
+}
@@ -184,6 +184,34 @@ pub fn get_refresh_token_duration_minutes() -> Result<u64, String> {
         .map_err(|_| "REFRESH_TOKEN_DURATION_MINUTES must be a valid number".to_string())
 }
 
+/// Get shortest allowed shared secret lifetime in seconds from Spin variables
+pub fn get_shared_secret_min_expires_seconds() -> Result<i64, String> {
+    let seconds_str = variables::get("shared_secret_min_expires_seconds").map_err(|e| {
+        format!(
+            "Failed to get shared_secret_min_expires_seconds variable: {}",
+            e
+        )
+    })?;
+
+    seconds_str
+        .parse::<i64>()
+        .map_err(|_| "SHARED_SECRET_MIN_EXPIRES_SECONDS must be a valid number".to_string())
+}
+
+/// Get longest allowed shared secret lifetime in seconds from Spin variables
+pub fn get_shared_secret_max_expires_seconds() -> Result<i64, String> {
+    let seconds_str = variables::get("shared_secret_max_expires_seconds").map_err(|e| {
+        format!(
+            "Failed to get shared_secret_max_expires_seconds variable: {}",
+            e
+        )
+    })?;
+
+    seconds_str
+        .parse::<i64>()
+        .map_err(|_| "SHARED_SECRET_MAX_EXPIRES_SECONDS must be a valid number".to_string())
+}
+
 // Shared Secret Security Keys
 
 /// Get shared secret URL cipher key from Spin variables as bytes (64 bytes required)
@@ -25,7 +25,19 @@ pub fn requires_authentication(path: &str) -> bool {
         p if p.ends_with("/api/generate") => true,
         p if p.ends_with("/api/password") => true,
         p if p.ends_with("/api/api-key") => true,
+        p if p.ends_with("/api/api-key/verify") => true,
         p if p.ends_with("/api/mnemonic") => true,
+        p if p.ends_with("/api/mnemonic/validate") => true,
+        p if p.ends_with("/api/passphrase") => true,
+        p if p.ends_with("/api/keypair") => true,
+        p if p.ends_with("/api/symmetric-key") => true,
+        p if p.ends_with("/api/draw") => true,
+        p if p.ends_with("/api/pronounceable") => true,
+        p if p.ends_with("/api/otp") => true,
+        p if p.ends_with("/api/otp/verify") => true,
+        p if p.ends_with("/api/slip39/split") => true,
+        p if p.ends_with("/api/slip39/combine") => true,
+        p if p.ends_with("/api/identifier") => true,
         p if p.ends_with("/api/from-seed") => true,
         p if p.starts_with("/api/users") => true,
 
@@ -0,0 +1,319 @@
+//! BIP39 mnemonic validation, recovery and seed derivation
+//!
+//! Checks user-typed phrases against the BIP39 word lists of every
+//! `MnemonicLanguage`:
+//! - Unknown words with suggested corrections (prefix + edit distance)
+//! - Checksum validation and last-word completion when one word is missing
+//! - 64-byte BIP39 seed derivation with an optional passphrase
+
+use crate::types::{InvalidMnemonicWord, MnemonicLanguage, MnemonicValidationResponse};
+use bip39::{Language, Mnemonic};
+use std::borrow::Cow;
+
+/// Word counts accepted by BIP39
+pub const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// Maximum suggestions returned per invalid word
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Maximum edit distance for typo suggestions
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// BIP39 words are unique in their first four characters
+const UNIQUE_PREFIX_CHARS: usize = 4;
+
+/// Entropy bytes behind a BIP39 word count (ENT = words * 32 / 3 bits)
+///
+/// # Returns
+/// * `Option<usize>` - 16, 20, 24, 28 or 32 bytes, None for unsupported counts
+pub fn entropy_bytes_for_words(words: usize) -> Option<usize> {
+    VALID_WORD_COUNTS
+        .contains(&words)
+        .then_some(words * 32 / 3 / 8)
+}
+
+/// Generate a mnemonic deterministically from a 32-byte seed
+///
+/// Uses the first `entropy_bytes_for_words(words)` bytes of the seed, so
+/// 12-word (first 16 bytes) and 24-word (full seed) phrases stay unchanged.
+pub fn mnemonic_from_seed(
+    language: Language,
+    seed: [u8; 32],
+    words: usize,
+) -> Result<Mnemonic, String> {
+    let entropy_bytes = entropy_bytes_for_words(words)
+        .ok_or_else(|| format!("Unsupported mnemonic word count: {}", words))?;
+    Mnemonic::from_entropy_in(language, &seed[..entropy_bytes])
+        .map_err(|e| format!("Failed to generate {}-word mnemonic: {}", words, e))
+}
+
+/// Validate a mnemonic phrase and derive its seed when valid
+///
+/// # Arguments
+/// * `phrase` - User-typed phrase (any case, any whitespace)
+/// * `language` - Word list to use, or None to detect it from the words
+/// * `passphrase` - Optional BIP39 passphrase ("" when absent)
+///
+/// # Returns
+/// * `MnemonicValidationResponse` - Validation report
+pub fn validate_mnemonic(
+    phrase: &str,
+    language: Option<MnemonicLanguage>,
+    passphrase: &str,
+) -> MnemonicValidationResponse {
+    let mut normalized = Cow::Owned(phrase.to_lowercase());
+    Mnemonic::normalize_utf8_cow(&mut normalized);
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+
+    let mnemonic_language = language.unwrap_or_else(|| detect_language(&words));
+    let bip39_language = Language::from(mnemonic_language);
+
+    let invalid_words: Vec<InvalidMnemonicWord> = words
+        .iter()
+        .enumerate()
+        .filter(|(_, word)| bip39_language.find_word(word).is_none())
+        .map(|(index, word)| InvalidMnemonicWord {
+            position: index + 1,
+            word: word.to_string(),
+            suggestions: suggest_words(bip39_language, word),
+        })
+        .collect();
+
+    let mut response = MnemonicValidationResponse {
+        valid: false,
+        language: mnemonic_language.into(),
+        word_count: words.len(),
+        checksum_valid: None,
+        invalid_words,
+        last_word_candidates: Vec::new(),
+        bip39_seed: None,
+    };
+
+    if !response.invalid_words.is_empty() {
+        return response;
+    }
+
+    if VALID_WORD_COUNTS.contains(&words.len()) {
+        match Mnemonic::parse_in_normalized(bip39_language, &words.join(" ")) {
+            Ok(mnemonic) => {
+                response.valid = true;
+                response.checksum_valid = Some(true);
+                response.bip39_seed = Some(hex::encode(mnemonic.to_seed(passphrase)));
+            }
+            Err(_) => response.checksum_valid = Some(false),
+        }
+    } else if VALID_WORD_COUNTS.contains(&(words.len() + 1)) {
+        response.last_word_candidates = last_word_candidates(bip39_language, &words);
+    }
+
+    response
+}
+
+/// Parse a complete BIP39 phrase, normalizing case and whitespace
+///
+/// # Returns
+/// * `Result<(MnemonicLanguage, Mnemonic), String>` - Language used and parsed mnemonic
+pub fn parse_mnemonic(
+    phrase: &str,
+    language: Option<MnemonicLanguage>,
+) -> Result<(MnemonicLanguage, Mnemonic), String> {
+    let mut normalized = Cow::Owned(phrase.to_lowercase());
+    Mnemonic::normalize_utf8_cow(&mut normalized);
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+
+    let mnemonic_language = language.unwrap_or_else(|| detect_language(&words));
+    Mnemonic::parse_in_normalized(Language::from(mnemonic_language), &words.join(" "))
+        .map(|mnemonic| (mnemonic_language, mnemonic))
+        .map_err(|e| format!("Invalid mnemonic: {}", e))
+}
+
+/// Detect the language whose word list contains the most words of the phrase
+///
+/// Ties resolve to the lowest language index (English first).
+pub fn detect_language(words: &[&str]) -> MnemonicLanguage {
+    (0..=9u8)
+        .filter_map(|index| MnemonicLanguage::try_from(index).ok())
+        .max_by_key(|language| {
+            let bip39_language = Language::from(*language);
+            let found = words
+                .iter()
+                .filter(|word| bip39_language.find_word(word).is_some())
+                .count();
+            // Reverse index so max_by_key prefers the lowest index on ties
+            (found, std::cmp::Reverse(u8::from(*language)))
+        })
+        .unwrap_or(MnemonicLanguage::English)
+}
+
+/// Suggest corrections for a word missing from the word list
+///
+/// Words sharing the first four characters come first (BIP39 guarantees they
+/// identify a single word), then words within a small edit distance.
+pub fn suggest_words(language: Language, word: &str) -> Vec<String> {
+    let mut suggestions: Vec<String> = Vec::new();
+
+    let prefix: String = word.chars().take(UNIQUE_PREFIX_CHARS).collect();
+    if prefix.chars().count() == UNIQUE_PREFIX_CHARS {
+        suggestions.extend(
+            language
+                .words_by_prefix(&prefix)
+                .iter()
+                .map(|w| w.to_string()),
+        );
+    }
+
+    let mut close: Vec<(usize, &str)> = language
+        .word_list()
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    close.sort();
+
+    for (_, candidate) in close {
+        if !suggestions.iter().any(|s| s == candidate) {
+            suggestions.push(candidate.to_string());
+        }
+    }
+
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+/// Every word that completes `words` into a phrase with a valid checksum
+pub fn last_word_candidates(language: Language, words: &[&str]) -> Vec<String> {
+    let base = words.join(" ");
+    language
+        .word_list()
+        .iter()
+        .filter(|candidate| {
+            Mnemonic::parse_in_normalized(language, &format!("{} {}", base, candidate)).is_ok()
+        })
+        .map(|candidate| candidate.to_string())
+        .collect()
+}
+
+/// Levenshtein distance between two strings (by characters)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b_chars.len() + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b_chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP39 test vector: entropy 0x00 * 16
+    const VALID_12: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_valid_phrase_and_seed_vector() {
+        let response = validate_mnemonic(VALID_12, None, "TREZOR");
+        assert!(response.valid);
+        assert_eq!(response.language, 0);
+        assert_eq!(response.checksum_valid, Some(true));
+        // Official BIP39 test vector (passphrase "TREZOR")
+        assert_eq!(
+            response.bip39_seed.unwrap(),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[test]
+    fn test_case_and_whitespace_are_normalized() {
+        let messy = format!("  {}  ", VALID_12.to_uppercase().replace(' ', " \t "));
+        assert!(validate_mnemonic(&messy, None, "").valid);
+    }
+
+    #[test]
+    fn test_bad_checksum() {
+        let phrase = VALID_12.replace("about", "abandon");
+        let response = validate_mnemonic(&phrase, None, "");
+        assert!(!response.valid);
+        assert_eq!(response.checksum_valid, Some(false));
+        assert!(response.bip39_seed.is_none());
+    }
+
+    #[test]
+    fn test_invalid_word_suggestions() {
+        let phrase = VALID_12.replace("about", "abuot");
+        let response = validate_mnemonic(&phrase, Some(MnemonicLanguage::English), "");
+        assert!(!response.valid);
+        assert_eq!(response.checksum_valid, None);
+        assert_eq!(response.invalid_words.len(), 1);
+        assert_eq!(response.invalid_words[0].position, 12);
+        assert!(
+            response.invalid_words[0]
+                .suggestions
+                .contains(&"about".to_string())
+        );
+
+        // Four-character prefix identifies the word uniquely
+        assert_eq!(suggest_words(Language::English, "abandonn")[0], "abandon");
+    }
+
+    #[test]
+    fn test_missing_last_word() {
+        let eleven: Vec<&str> = VALID_12.split(' ').take(11).collect();
+        let response = validate_mnemonic(&eleven.join(" "), None, "");
+        assert!(!response.valid);
+        // 7 of the last word's 11 bits are entropy: 2^7 candidates
+        assert_eq!(response.last_word_candidates.len(), 128);
+        assert!(response.last_word_candidates.contains(&"about".to_string()));
+    }
+
+    #[test]
+    fn test_detects_other_languages() {
+        let entropy = [7u8; 16];
+        let spanish = Mnemonic::from_entropy_in(Language::Spanish, &entropy).unwrap();
+        let response = validate_mnemonic(&spanish.to_string(), None, "");
+        assert!(response.valid);
+        assert_eq!(response.language, u8::from(MnemonicLanguage::Spanish));
+    }
+
+    #[test]
+    fn test_all_word_counts_from_seed() {
+        let seed = [0u8; 32];
+        for (words, bytes) in [(12, 16), (15, 20), (18, 24), (21, 28), (24, 32)] {
+            assert_eq!(entropy_bytes_for_words(words), Some(bytes));
+            let mnemonic = mnemonic_from_seed(Language::English, seed, words).unwrap();
+            assert_eq!(mnemonic.word_count(), words);
+            assert_eq!(mnemonic.to_entropy(), seed[..bytes].to_vec());
+            assert!(validate_mnemonic(&mnemonic.to_string(), None, "").valid);
+        }
+        assert_eq!(
+            mnemonic_from_seed(Language::English, seed, 12)
+                .unwrap()
+                .to_string(),
+            VALID_12
+        );
+        assert_eq!(entropy_bytes_for_words(13), None);
+        assert!(mnemonic_from_seed(Language::English, seed, 13).is_err());
+    }
+
+    #[test]
+    fn test_parse_mnemonic() {
+        let (language, mnemonic) = parse_mnemonic(&VALID_12.to_uppercase(), None).unwrap();
+        assert_eq!(language, MnemonicLanguage::English);
+        assert_eq!(mnemonic.to_entropy(), vec![0u8; 16]);
+        assert!(parse_mnemonic(&VALID_12.replace("about", "abandon"), None).is_err());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("about", "about"), 0);
+        assert_eq!(edit_distance("abuot", "about"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+}
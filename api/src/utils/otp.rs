@@ -0,0 +1,274 @@
+//! HOTP/TOTP one-time passwords (RFC 4226 / RFC 6238)
+//!
+//! Provides the pieces behind the /api/otp endpoints:
+//! - RFC 4648 base32 secrets (generated through `generate_with_seed`)
+//! - HMAC-SHA1/SHA256/SHA512 code computation with dynamic truncation
+//! - Drift-window verification and `otpauth://` provisioning URIs
+
+use crate::types::{OtpAlgorithm, OtpType};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+/// RFC 4648 base32 alphabet (no padding in otpauth secrets)
+pub const BASE32_ALPHABET: [char; 32] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '2', '3', '4', '5', '6', '7',
+];
+
+/// Decode a base32 secret, ignoring case, spaces and trailing padding
+pub fn base32_decode(secret: &str) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::with_capacity(secret.len() * 5 / 8);
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    for c in secret
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .filter(|c| *c != '=')
+    {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a == c)
+            .ok_or_else(|| format!("Invalid base32 character in secret: '{}'", c))?;
+        accumulator = (accumulator << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((accumulator >> bits) as u8);
+            accumulator &= (1 << bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
+
+/// Compute the HOTP value for `counter` (RFC 4226 section 5.3)
+///
+/// # Returns
+/// * `String` - Zero-padded code of `digits` digits
+pub fn hotp(secret: &[u8], counter: u64, digits: u32, algorithm: OtpAlgorithm) -> String {
+    let message = counter.to_be_bytes();
+    let hash = match algorithm {
+        OtpAlgorithm::Sha1 => {
+            let mut mac = <Hmac<Sha1> as Mac>::new_from_slice(secret)
+                .expect("HMAC accepts keys of any length");
+            mac.update(&message);
+            mac.finalize().into_bytes().to_vec()
+        }
+        OtpAlgorithm::Sha256 => {
+            let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(secret)
+                .expect("HMAC accepts keys of any length");
+            mac.update(&message);
+            mac.finalize().into_bytes().to_vec()
+        }
+        OtpAlgorithm::Sha512 => {
+            let mut mac = <Hmac<Sha512> as Mac>::new_from_slice(secret)
+                .expect("HMAC accepts keys of any length");
+            mac.update(&message);
+            mac.finalize().into_bytes().to_vec()
+        }
+    };
+
+    // Dynamic truncation: low nibble of the last byte selects a 31-bit window
+    let offset = (hash[hash.len() - 1] & 0x0F) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7F,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(digits),
+        width = digits as usize
+    )
+}
+
+/// Find the counter in `candidates` whose code matches `code`
+///
+/// Every candidate is checked (no early exit) and codes are compared in
+/// constant time, so timing does not reveal which step matched.
+pub fn find_matching_counter(
+    secret: &[u8],
+    code: &str,
+    digits: u32,
+    algorithm: OtpAlgorithm,
+    candidates: impl Iterator<Item = u64>,
+) -> Option<u64> {
+    let mut matched = None;
+    for counter in candidates {
+        let expected = hotp(secret, counter, digits, algorithm);
+        if constant_time_eq(expected.as_bytes(), code.as_bytes()) && matched.is_none() {
+            matched = Some(counter);
+        }
+    }
+    matched
+}
+
+/// TOTP time step for a Unix time (RFC 6238, T0 = 0)
+pub fn totp_counter(unix_time: u64, period: u64) -> u64 {
+    unix_time / period
+}
+
+/// Build an `otpauth://` provisioning URI (Key Uri Format)
+///
+/// # Arguments
+/// * `moving_factor` - Period in seconds for TOTP, initial counter for HOTP
+pub fn otpauth_uri(
+    otp_type: OtpType,
+    secret_base32: &str,
+    issuer: &str,
+    account: &str,
+    algorithm: OtpAlgorithm,
+    digits: u32,
+    moving_factor: u64,
+) -> String {
+    let label = if issuer.is_empty() {
+        urlencoding::encode(account).into_owned()
+    } else {
+        format!(
+            "{}:{}",
+            urlencoding::encode(issuer),
+            urlencoding::encode(account)
+        )
+    };
+
+    let mut uri = format!(
+        "otpauth://{}/{}?secret={}",
+        otp_type.uri_name(),
+        label,
+        secret_base32
+    );
+    if !issuer.is_empty() {
+        uri.push_str(&format!("&issuer={}", urlencoding::encode(issuer)));
+    }
+    uri.push_str(&format!(
+        "&algorithm={}&digits={}",
+        algorithm.uri_name(),
+        digits
+    ));
+    match otp_type {
+        OtpType::Totp => uri.push_str(&format!("&period={}", moving_factor)),
+        OtpType::Hotp => uri.push_str(&format!("&counter={}", moving_factor)),
+    }
+    uri
+}
+
+/// Compare two byte strings without data-dependent early exit
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RFC_SECRET_SHA1: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn test_hotp_rfc4226_vectors() {
+        let expected = [
+            "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583",
+            "399871", "520489",
+        ];
+        for (counter, code) in expected.iter().enumerate() {
+            assert_eq!(
+                hotp(RFC_SECRET_SHA1, counter as u64, 6, OtpAlgorithm::Sha1),
+                *code
+            );
+        }
+    }
+
+    #[test]
+    fn test_totp_rfc6238_vectors() {
+        let counter = totp_counter(59, 30);
+        assert_eq!(
+            hotp(RFC_SECRET_SHA1, counter, 8, OtpAlgorithm::Sha1),
+            "94287082"
+        );
+        assert_eq!(
+            hotp(
+                b"12345678901234567890123456789012",
+                counter,
+                8,
+                OtpAlgorithm::Sha256
+            ),
+            "46119246"
+        );
+        assert_eq!(
+            hotp(
+                b"1234567890123456789012345678901234567890123456789012345678901234",
+                counter,
+                8,
+                OtpAlgorithm::Sha512
+            ),
+            "90693936"
+        );
+        assert_eq!(
+            hotp(
+                RFC_SECRET_SHA1,
+                totp_counter(1111111109, 30),
+                8,
+                OtpAlgorithm::Sha1
+            ),
+            "07081804"
+        );
+    }
+
+    #[test]
+    fn test_base32_decode() {
+        // "12345678901234567890" in base32
+        assert_eq!(
+            base32_decode("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ").unwrap(),
+            RFC_SECRET_SHA1
+        );
+        assert_eq!(
+            base32_decode("gezd gnbv gy3t qojq gezd gnbv gy3t qojq").unwrap(),
+            RFC_SECRET_SHA1
+        );
+        assert_eq!(base32_decode("MZXW6===").unwrap(), b"foo");
+        assert!(base32_decode("GEZD1").is_err());
+    }
+
+    #[test]
+    fn test_find_matching_counter() {
+        let code = hotp(RFC_SECRET_SHA1, 41, 6, OtpAlgorithm::Sha1);
+        assert_eq!(
+            find_matching_counter(RFC_SECRET_SHA1, &code, 6, OtpAlgorithm::Sha1, 39..=43),
+            Some(41)
+        );
+        assert_eq!(
+            find_matching_counter(RFC_SECRET_SHA1, &code, 6, OtpAlgorithm::Sha1, 42..=43),
+            None
+        );
+    }
+
+    #[test]
+    fn test_otpauth_uri() {
+        assert_eq!(
+            otpauth_uri(
+                OtpType::Totp,
+                "JBSWY3DPEHPK3PXP",
+                "ACME Co",
+                "ops@example.com",
+                OtpAlgorithm::Sha1,
+                6,
+                30
+            ),
+            "otpauth://totp/ACME%20Co:ops%40example.com?secret=JBSWY3DPEHPK3PXP&issuer=ACME%20Co&algorithm=SHA1&digits=6&period=30"
+        );
+        assert_eq!(
+            otpauth_uri(
+                OtpType::Hotp,
+                "JBSWY3DPEHPK3PXP",
+                "",
+                "svc",
+                OtpAlgorithm::Sha256,
+                8,
+                5
+            ),
+            "otpauth://hotp/svc?secret=JBSWY3DPEHPK3PXP&algorithm=SHA256&digits=8&counter=5"
+        );
+    }
+}
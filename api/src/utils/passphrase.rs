@@ -0,0 +1,183 @@
+//! Diceware-style passphrase generation
+//!
+//! Picks words uniformly from the embedded BIP39 word lists (2048 words per
+//! language, 11 bits per word) using the seeded ChaCha8 generator, then applies
+//! capitalization and optional digit/symbol insertion. Entropy is computed from
+//! every random choice so it can be reported honestly.
+
+use crate::types::{PassphraseCapitalization, PassphraseInsertion};
+use crate::utils::random_generator::random_index;
+use bip39::Language;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Symbols used for passphrase symbol insertion
+pub const PASSPHRASE_SYMBOLS: &str = "!@#$%^&*?+";
+
+/// Passphrase generation options
+#[derive(Debug, Clone)]
+pub struct PassphraseOptions {
+    pub language: Language,
+    pub words: usize,
+    pub separator: String,
+    pub capitalization: PassphraseCapitalization,
+    pub insertion: PassphraseInsertion,
+}
+
+/// Generated passphrase with its entropy
+#[derive(Debug, Clone, PartialEq)]
+pub struct Passphrase {
+    pub phrase: String,
+    pub entropy_bits: f64,
+}
+
+/// Generate a passphrase deterministically from `seed`
+///
+/// # Arguments
+/// * `seed` - 32-byte seed for ChaCha8Rng
+/// * `options` - Word count, language, separator, capitalization and insertion
+///
+/// # Returns
+/// * `Passphrase` - Phrase plus entropy in bits
+pub fn generate_passphrase(seed: [u8; 32], options: &PassphraseOptions) -> Passphrase {
+    let mut rng = ChaCha8Rng::from_seed(seed);
+    let word_list = options.language.word_list();
+
+    let mut words: Vec<String> = (0..options.words)
+        .map(|_| word_list[random_index(&mut rng, word_list.len())].to_string())
+        .collect();
+    let mut entropy_bits = options.words as f64 * (word_list.len() as f64).log2();
+
+    // Capitalization (only the random style adds entropy, one bit per word whose
+    // title case differs: caseless scripts such as Japanese or Korean add nothing)
+    for word in words.iter_mut() {
+        *word = match options.capitalization {
+            PassphraseCapitalization::Lowercase => word.clone(),
+            PassphraseCapitalization::Title => title_case(word),
+            PassphraseCapitalization::Uppercase => word.to_uppercase(),
+            PassphraseCapitalization::Random => {
+                let titled = title_case(word);
+                if titled != *word {
+                    entropy_bits += 1.0;
+                }
+                if random_index(&mut rng, 2) == 1 {
+                    titled
+                } else {
+                    word.clone()
+                }
+            }
+        };
+    }
+
+    // Optional digit/symbol appended to a random word each
+    let digits: Vec<char> = ('0'..='9').collect();
+    let symbols: Vec<char> = PASSPHRASE_SYMBOLS.chars().collect();
+    for (enabled, charset) in [
+        (options.insertion.digit(), &digits),
+        (options.insertion.symbol(), &symbols),
+    ] {
+        if enabled {
+            let position = random_index(&mut rng, words.len());
+            let extra = charset[random_index(&mut rng, charset.len())];
+            words[position].push(extra);
+            entropy_bits += (charset.len() as f64).log2() + (words.len() as f64).log2();
+        }
+    }
+
+    Passphrase {
+        phrase: words.join(&options.separator),
+        entropy_bits,
+    }
+}
+
+/// Uppercase the first character of a word
+fn title_case(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(words: usize) -> PassphraseOptions {
+        PassphraseOptions {
+            language: Language::English,
+            words,
+            separator: "-".to_string(),
+            capitalization: PassphraseCapitalization::Lowercase,
+            insertion: PassphraseInsertion::None,
+        }
+    }
+
+    #[test]
+    fn test_passphrase_is_deterministic() {
+        let first = generate_passphrase([5u8; 32], &options(6));
+        let second = generate_passphrase([5u8; 32], &options(6));
+        assert_eq!(first, second);
+        assert_ne!(first, generate_passphrase([6u8; 32], &options(6)));
+    }
+
+    #[test]
+    fn test_passphrase_words_and_entropy() {
+        let passphrase = generate_passphrase([5u8; 32], &options(6));
+        let words: Vec<&str> = passphrase.phrase.split('-').collect();
+        assert_eq!(words.len(), 6);
+        for word in words {
+            assert!(Language::English.find_word(word).is_some());
+        }
+        assert_eq!(passphrase.entropy_bits, 66.0);
+    }
+
+    #[test]
+    fn test_passphrase_capitalization_and_insertion() {
+        let mut opts = options(5);
+        opts.capitalization = PassphraseCapitalization::Title;
+        opts.insertion = PassphraseInsertion::DigitAndSymbol;
+        opts.separator = " ".to_string();
+
+        let passphrase = generate_passphrase([8u8; 32], &opts);
+        assert!(
+            passphrase
+                .phrase
+                .split(' ')
+                .all(|w| w.chars().next().unwrap().is_uppercase())
+        );
+        assert_eq!(
+            passphrase
+                .phrase
+                .chars()
+                .filter(char::is_ascii_digit)
+                .count(),
+            1
+        );
+        assert_eq!(
+            passphrase
+                .phrase
+                .chars()
+                .filter(|c| PASSPHRASE_SYMBOLS.contains(*c))
+                .count(),
+            1
+        );
+
+        // 5 words * 11 bits + digit (log2 10 + log2 5) + symbol (log2 10 + log2 5)
+        let expected = 55.0 + 2.0 * (10f64.log2() + 5f64.log2());
+        assert!((passphrase.entropy_bits - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_random_capitalization_entropy_ignores_caseless_words() {
+        let mut opts = options(6);
+        opts.capitalization = PassphraseCapitalization::Random;
+        assert_eq!(
+            generate_passphrase([5u8; 32], &opts).entropy_bits,
+            66.0 + 6.0
+        );
+
+        opts.language = Language::Japanese;
+        assert_eq!(generate_passphrase([5u8; 32], &opts).entropy_bits, 66.0);
+    }
+}
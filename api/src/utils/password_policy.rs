@@ -0,0 +1,300 @@
+//! Policy-driven password generation
+//!
+//! Builds passwords that always satisfy a declarative `PasswordPolicy`:
+//! - Required characters are drawn per class first, then the rest is filled
+//! - Fisher-Yates shuffle (seeded ChaCha8) places them at random positions
+//! - Candidates are re-checked and retried deterministically from the same stream
+
+use crate::types::PasswordPolicy;
+use crate::utils::endpoint_helpers::contains_unwanted_patterns;
+use crate::utils::random_generator::random_index;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Maximum candidates drawn from the seed stream before giving up
+const MAX_POLICY_ATTEMPTS: usize = 1000;
+
+/// Generate a password satisfying `policy` deterministically from `seed`
+///
+/// # Arguments
+/// * `length` - Password length
+/// * `alphabet` - Base alphabet (banned characters are removed from it)
+/// * `policy` - Composition policy to satisfy
+/// * `seed` - 32-byte seed for ChaCha8Rng
+///
+/// # Returns
+/// * `Result<String, String>` - Password or reason why the policy cannot be met
+pub fn generate_password_with_policy(
+    length: usize,
+    alphabet: &[char],
+    policy: &PasswordPolicy,
+    seed: [u8; 32],
+) -> Result<String, String> {
+    let allowed = allowed_chars(alphabet, policy);
+    let class_pools = class_pools(&allowed, policy);
+
+    // Reject policies that no password of this length/alphabet can satisfy
+    if allowed.len() < 2 {
+        return Err("Password policy bans too many characters from the alphabet".to_string());
+    }
+    if policy.required_chars() > length {
+        return Err(format!(
+            "Password policy requires {} characters but length is {}",
+            policy.required_chars(),
+            length
+        ));
+    }
+    for (min, class_name, class_pool) in &class_pools {
+        if *min > 0 && class_pool.is_empty() {
+            return Err(format!(
+                "Password policy requires {} {} character(s) but the alphabet allows none",
+                min, class_name
+            ));
+        }
+    }
+    if policy.start_with_letter && !allowed.iter().any(char::is_ascii_alphabetic) {
+        return Err(
+            "Password policy requires a leading letter but the alphabet allows none".to_string(),
+        );
+    }
+    if policy.max_repeat == Some(0) {
+        return Err("Password policy max_repeat must be at least 1".to_string());
+    }
+
+    let mut rng = ChaCha8Rng::from_seed(seed);
+
+    for _ in 0..MAX_POLICY_ATTEMPTS {
+        let mut chars: Vec<char> = Vec::with_capacity(length);
+
+        // Required characters per class, then fill with the whole allowed alphabet
+        for (min, _, class_pool) in &class_pools {
+            for _ in 0..*min {
+                chars.push(class_pool[random_index(&mut rng, class_pool.len())]);
+            }
+        }
+        while chars.len() < length {
+            chars.push(allowed[random_index(&mut rng, allowed.len())]);
+        }
+
+        // Fisher-Yates shuffle so required characters land at random positions
+        for i in (1..chars.len()).rev() {
+            let j = random_index(&mut rng, i + 1);
+            chars.swap(i, j);
+        }
+
+        // Move the first letter to the front when a leading letter is required
+        if policy.start_with_letter
+            && let Some(pos) = chars.iter().position(char::is_ascii_alphabetic)
+        {
+            chars.swap(0, pos);
+        }
+
+        let candidate: String = chars.into_iter().collect();
+        if satisfies_password_policy(&candidate, policy) {
+            return Ok(candidate);
+        }
+    }
+
+    Err("Unable to generate a password satisfying the policy".to_string())
+}
+
+/// Conservative entropy estimate (bits) for a policy-generated password
+///
+/// Counts the per-class required draws plus the fill draws from the allowed
+/// alphabet. The shuffle adds position entropy that is deliberately not
+/// counted, which more than offsets the few candidates lost to retries.
+pub fn policy_password_entropy_bits(
+    length: usize,
+    alphabet: &[char],
+    policy: &PasswordPolicy,
+) -> f64 {
+    let allowed = allowed_chars(alphabet, policy);
+    let required_bits: f64 = class_pools(&allowed, policy)
+        .iter()
+        .filter(|(_, _, class_pool)| !class_pool.is_empty())
+        .map(|(min, _, class_pool)| *min as f64 * (class_pool.len() as f64).log2())
+        .sum();
+    let fill = length.saturating_sub(policy.required_chars());
+
+    required_bits + fill as f64 * (allowed.len().max(1) as f64).log2()
+}
+
+/// Check whether `password` satisfies every constraint of `policy`
+///
+/// Also rejects the `--`/`__` patterns avoided by all password generation.
+pub fn satisfies_password_policy(password: &str, policy: &PasswordPolicy) -> bool {
+    let count = |predicate: fn(&char) -> bool| password.chars().filter(predicate).count();
+
+    if count(char::is_ascii_uppercase) < policy.min_uppercase
+        || count(char::is_ascii_lowercase) < policy.min_lowercase
+        || count(char::is_ascii_digit) < policy.min_digits
+        || password
+            .chars()
+            .filter(|c| !c.is_ascii_alphanumeric())
+            .count()
+            < policy.min_symbols
+    {
+        return false;
+    }
+
+    if password.chars().any(|c| policy.banned_chars.contains(c)) {
+        return false;
+    }
+
+    if policy.start_with_letter
+        && !password
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic())
+    {
+        return false;
+    }
+
+    if let Some(max_repeat) = policy.max_repeat
+        && longest_run(password) > max_repeat
+    {
+        return false;
+    }
+
+    !contains_unwanted_patterns(password)
+}
+
+/// Alphabet characters not banned by the policy
+fn allowed_chars(alphabet: &[char], policy: &PasswordPolicy) -> Vec<char> {
+    alphabet
+        .iter()
+        .copied()
+        .filter(|c| !policy.banned_chars.contains(*c))
+        .collect()
+}
+
+/// Required count, class name and character pool for each character class
+fn class_pools(allowed: &[char], policy: &PasswordPolicy) -> [(usize, &'static str, Vec<char>); 4] {
+    [
+        (
+            policy.min_uppercase,
+            "uppercase",
+            pool(allowed, char::is_ascii_uppercase),
+        ),
+        (
+            policy.min_lowercase,
+            "lowercase",
+            pool(allowed, char::is_ascii_lowercase),
+        ),
+        (
+            policy.min_digits,
+            "digit",
+            pool(allowed, char::is_ascii_digit),
+        ),
+        (
+            policy.min_symbols,
+            "symbol",
+            pool(allowed, |c| !c.is_ascii_alphanumeric()),
+        ),
+    ]
+}
+
+/// Characters of `alphabet` belonging to a character class
+fn pool(alphabet: &[char], predicate: impl Fn(&char) -> bool) -> Vec<char> {
+    alphabet.iter().copied().filter(|c| predicate(c)).collect()
+}
+
+/// Length of the longest run of identical consecutive characters
+fn longest_run(s: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut previous = None;
+
+    for c in s.chars() {
+        current = if previous == Some(c) { current + 1 } else { 1 };
+        longest = longest.max(current);
+        previous = Some(c);
+    }
+
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AlphabetType;
+    use crate::types::password_policy::PASSWORD_POLICY_PRESETS;
+
+    #[test]
+    fn test_presets_always_satisfied() {
+        let alphabet = AlphabetType::FullWithSymbols.as_chars();
+        for name in PASSWORD_POLICY_PRESETS {
+            let policy = PasswordPolicy::preset(name).unwrap();
+            for i in 0..50u8 {
+                let password = generate_password_with_policy(21, &alphabet, &policy, [i; 32])
+                    .unwrap_or_else(|e| panic!("{}: {}", name, e));
+                assert_eq!(password.chars().count(), 21);
+                assert!(
+                    satisfies_password_policy(&password, &policy),
+                    "{}: {}",
+                    name,
+                    password
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_policy_generation_is_deterministic() {
+        let alphabet = AlphabetType::FullWithSymbols.as_chars();
+        let policy = PasswordPolicy::preset("strict").unwrap();
+        let first = generate_password_with_policy(32, &alphabet, &policy, [9u8; 32]).unwrap();
+        let second = generate_password_with_policy(32, &alphabet, &policy, [9u8; 32]).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_heavy_minimums_and_single_repeat() {
+        let alphabet = AlphabetType::FullWithSymbols.as_chars();
+        let policy = PasswordPolicy::parse(
+            r#"{"min_uppercase":6,"min_digits":6,"min_symbols":6,"max_repeat":1}"#,
+        )
+        .unwrap();
+        for i in 0..20u8 {
+            let password = generate_password_with_policy(21, &alphabet, &policy, [i; 32]).unwrap();
+            assert!(satisfies_password_policy(&password, &policy));
+        }
+    }
+
+    #[test]
+    fn test_infeasible_policies_rejected() {
+        let no_symbols = AlphabetType::NoLookAlike.as_chars();
+        let policy = PasswordPolicy::preset("aws-iam").unwrap();
+        assert!(generate_password_with_policy(21, &no_symbols, &policy, [1u8; 32]).is_err());
+
+        let alphabet = AlphabetType::FullWithSymbols.as_chars();
+        let too_many = PasswordPolicy::parse(r#"{"min_digits":30}"#).unwrap();
+        assert!(generate_password_with_policy(21, &alphabet, &too_many, [1u8; 32]).is_err());
+
+        let all_digits_banned =
+            PasswordPolicy::parse(r#"{"min_digits":1,"banned_chars":"0123456789"}"#).unwrap();
+        assert!(
+            generate_password_with_policy(21, &alphabet, &all_digits_banned, [1u8; 32]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_policy_entropy_estimate() {
+        let alphabet = AlphabetType::FullWithSymbols.as_chars();
+        let unconstrained = PasswordPolicy::default();
+        let bits = policy_password_entropy_bits(21, &alphabet, &unconstrained);
+        assert!((bits - 21.0 * 73f64.log2()).abs() < 1e-9);
+
+        // Requiring digits draws them from a 10-character pool
+        let digits = PasswordPolicy::parse(r#"{"min_digits":21}"#).unwrap();
+        let bits = policy_password_entropy_bits(21, &alphabet, &digits);
+        assert!((bits - 21.0 * 10f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_longest_run() {
+        assert_eq!(longest_run(""), 0);
+        assert_eq!(longest_run("abc"), 1);
+        assert_eq!(longest_run("aabbbc"), 3);
+    }
+}
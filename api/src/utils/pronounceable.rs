@@ -0,0 +1,220 @@
+//! Pronounceable passwords and proquint identifiers
+//!
+//! Syllable mode draws consonant-vowel pairs uniformly with the seeded ChaCha8
+//! generator, so a password can be read over the phone letter by letter without
+//! spelling alphabets. Because consonants and vowels alternate strictly, every
+//! string maps back to exactly one syllable sequence and the entropy is simply
+//! `log2(80)` bits per syllable plus `log2(10)` per appended digit.
+//!
+//! Proquint mode encodes seeded random bytes as "PRO-nouncable QUINT-uplets"
+//! (CVCVC per 16 bits, <https://arxiv.org/html/0901.4016>).
+
+use crate::utils::digest::random_bytes;
+use crate::utils::random_generator::random_index;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Syllable consonants: no c/q/w/x/y, which are ambiguous when spoken
+const SYLLABLE_CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+/// Syllable vowels
+const SYLLABLE_VOWELS: &[u8; 5] = b"aeiou";
+
+/// Proquint consonants (4 bits each)
+const PROQUINT_CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+/// Proquint vowels (2 bits each)
+const PROQUINT_VOWELS: &[u8; 4] = b"aiou";
+
+/// Syllable password generation options
+#[derive(Debug, Clone)]
+pub struct SyllableOptions {
+    pub syllables: usize,
+    /// Syllables per separator-delimited group (0 = no grouping)
+    pub group: usize,
+    pub separator: String,
+    /// Random digits appended after the last syllable
+    pub digits: usize,
+}
+
+/// Generated pronounceable value with its entropy
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pronounceable {
+    pub value: String,
+    pub entropy_bits: f64,
+}
+
+/// Generate a syllable password deterministically from `seed`
+///
+/// # Arguments
+/// * `seed` - 32-byte seed for ChaCha8Rng
+/// * `options` - Syllable count, grouping, separator and digit suffix
+///
+/// # Returns
+/// * `Pronounceable` - Password plus entropy in bits
+pub fn generate_syllables(seed: [u8; 32], options: &SyllableOptions) -> Pronounceable {
+    let mut rng = ChaCha8Rng::from_seed(seed);
+
+    let syllables: Vec<String> = (0..options.syllables)
+        .map(|_| {
+            let consonant = SYLLABLE_CONSONANTS[random_index(&mut rng, SYLLABLE_CONSONANTS.len())];
+            let vowel = SYLLABLE_VOWELS[random_index(&mut rng, SYLLABLE_VOWELS.len())];
+            format!("{}{}", consonant as char, vowel as char)
+        })
+        .collect();
+
+    let mut value = if options.group == 0 {
+        syllables.concat()
+    } else {
+        syllables
+            .chunks(options.group)
+            .map(|group| group.concat())
+            .collect::<Vec<_>>()
+            .join(&options.separator)
+    };
+
+    if options.digits > 0 {
+        if options.group > 0 {
+            value.push_str(&options.separator);
+        }
+        for _ in 0..options.digits {
+            value.push((b'0' + random_index(&mut rng, 10) as u8) as char);
+        }
+    }
+
+    let syllable_space = (SYLLABLE_CONSONANTS.len() * SYLLABLE_VOWELS.len()) as f64;
+    Pronounceable {
+        value,
+        entropy_bits: options.syllables as f64 * syllable_space.log2()
+            + options.digits as f64 * 10f64.log2(),
+    }
+}
+
+/// Generate `bytes` random bytes from `seed` and encode them as proquints
+///
+/// # Arguments
+/// * `bytes` - Even number of bytes (16 bits per quint)
+pub fn generate_proquint(seed: [u8; 32], bytes: usize) -> Pronounceable {
+    Pronounceable {
+        value: encode_proquint(&random_bytes(seed, bytes)),
+        entropy_bits: (bytes * 8) as f64,
+    }
+}
+
+/// Encode bytes as dash-separated proquints (a trailing odd byte is ignored)
+pub fn encode_proquint(bytes: &[u8]) -> String {
+    bytes
+        .chunks_exact(2)
+        .map(|pair| {
+            let word = u16::from_be_bytes([pair[0], pair[1]]);
+            let consonant = |shift: u16| PROQUINT_CONSONANTS[((word >> shift) & 0x0F) as usize];
+            let vowel = |shift: u16| PROQUINT_VOWELS[((word >> shift) & 0x03) as usize];
+            [
+                consonant(12),
+                vowel(10),
+                consonant(6),
+                vowel(4),
+                consonant(0),
+            ]
+            .iter()
+            .map(|b| *b as char)
+            .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode dash-separated proquints back to bytes
+    fn decode_proquint(text: &str) -> Result<Vec<u8>, String> {
+        let mut bytes = Vec::new();
+        for quint in text.split('-') {
+            let letters = quint.as_bytes();
+            if letters.len() != 5 {
+                return Err(format!("Invalid proquint '{}': must be 5 letters", quint));
+            }
+            let mut word: u16 = 0;
+            for (i, letter) in letters.iter().enumerate() {
+                let (table, bits): (&[u8], u16) = if i % 2 == 0 {
+                    (PROQUINT_CONSONANTS, 4)
+                } else {
+                    (PROQUINT_VOWELS, 2)
+                };
+                let value = table
+                    .iter()
+                    .position(|c| c == letter)
+                    .ok_or_else(|| format!("Invalid proquint '{}'", quint))?;
+                word = (word << bits) | value as u16;
+            }
+            bytes.extend_from_slice(&word.to_be_bytes());
+        }
+        Ok(bytes)
+    }
+
+    fn options(syllables: usize) -> SyllableOptions {
+        SyllableOptions {
+            syllables,
+            group: 3,
+            separator: "-".to_string(),
+            digits: 0,
+        }
+    }
+
+    #[test]
+    fn test_proquint_reference_vectors() {
+        // IPv4 examples from the proquint paper
+        assert_eq!(encode_proquint(&[127, 0, 0, 1]), "lusab-babad");
+        assert_eq!(encode_proquint(&[63, 84, 220, 193]), "gutih-tugad");
+        assert_eq!(encode_proquint(&[140, 98, 193, 141]), "mudof-sakat");
+        assert_eq!(decode_proquint("lusab-babad").unwrap(), vec![127, 0, 0, 1]);
+        assert!(decode_proquint("lusab-baba").is_err());
+        assert!(decode_proquint("lusae-babad").is_err());
+    }
+
+    #[test]
+    fn test_proquint_roundtrip_and_entropy() {
+        let generated = generate_proquint([3u8; 32], 8);
+        assert_eq!(generated.value.len(), 4 * 5 + 3);
+        assert_eq!(generated.entropy_bits, 64.0);
+        assert_eq!(
+            decode_proquint(&generated.value).unwrap(),
+            random_bytes([3u8; 32], 8)
+        );
+    }
+
+    #[test]
+    fn test_syllables_are_deterministic() {
+        let a = generate_syllables([9u8; 32], &options(12));
+        let b = generate_syllables([9u8; 32], &options(12));
+        let c = generate_syllables([10u8; 32], &options(12));
+        assert_eq!(a, b);
+        assert_ne!(a.value, c.value);
+    }
+
+    #[test]
+    fn test_syllable_structure_and_entropy() {
+        let generated = generate_syllables([1u8; 32], &options(12));
+        let groups: Vec<&str> = generated.value.split('-').collect();
+        assert_eq!(groups.len(), 4);
+        for group in groups {
+            assert_eq!(group.len(), 6);
+            for (i, letter) in group.bytes().enumerate() {
+                if i % 2 == 0 {
+                    assert!(SYLLABLE_CONSONANTS.contains(&letter));
+                } else {
+                    assert!(SYLLABLE_VOWELS.contains(&letter));
+                }
+            }
+        }
+        assert!((generated.entropy_bits - 12.0 * 80f64.log2()).abs() < 1e-9);
+
+        let mut with_digits = options(4);
+        with_digits.group = 0;
+        with_digits.digits = 2;
+        let generated = generate_syllables([1u8; 32], &with_digits);
+        assert_eq!(generated.value.len(), 10);
+        assert!(generated.value[8..].bytes().all(|b| b.is_ascii_digit()));
+        assert!((generated.entropy_bits - (4.0 * 80f64.log2() + 2.0 * 10f64.log2())).abs() < 1e-9);
+    }
+}
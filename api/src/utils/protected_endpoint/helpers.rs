@@ -26,8 +26,12 @@ pub fn payload_to_params(payload: &serde_json::Value) -> HashMap<String, String>
                 serde_json::Value::Bool(b) => {
                     params.insert(key.clone(), b.to_string());
                 }
-                _ => {
-                    // Skip null, arrays, and objects for now
+                serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+                    // Nested structures (e.g. password policy objects) travel as compact JSON
+                    params.insert(key.clone(), value.to_string());
+                }
+                serde_json::Value::Null => {
+                    // Skip null values
                 }
             }
         }
@@ -39,15 +43,15 @@ pub fn payload_to_params(payload: &serde_json::Value) -> HashMap<String, String>
 /// Universal function to extract and validate seed from payload (DRY)
 ///
 /// # Arguments
-/// * `payload` - JSON value containing optional seed field
+/// * `payload` - JSON value containing optional seed field (bare seed or seed envelope)
 ///
 /// # Returns
 /// * `Result<Option<[u8; 32]>, String>` - Validated seed bytes or error
 pub fn extract_seed_from_payload(payload: &serde_json::Value) -> Result<Option<[u8; 32]>, String> {
     if let Some(seed_value) = payload.get("seed") {
         if let Some(seed_str) = seed_value.as_str() {
-            match crate::utils::base58_to_seed(seed_str) {
-                Ok(seed_bytes) => Ok(Some(seed_bytes)),
+            match crate::utils::seed_envelope::SeedEnvelope::decode(seed_str) {
+                Ok(envelope) => Ok(Some(envelope.seed)),
                 Err(e) => Err(format!("Invalid seed format: {}", e)),
             }
         } else {
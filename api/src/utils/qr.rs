@@ -0,0 +1,78 @@
+//! QR Code SVG rendering for `otpauth://` provisioning URIs
+//!
+//! Encoding is done by the `qrcodegen` reference implementation in byte mode at
+//! error correction level M (boosted when it fits in the same version); this
+//! module only renders the symbol as SVG with a quiet zone.
+
+use qrcodegen::{QrCode, QrCodeEcc};
+
+/// Quiet zone width in modules around the symbol
+const QUIET_ZONE: i32 = 4;
+
+/// Encode `text` as a QR Code in byte mode at error correction level M (or higher)
+fn encode(text: &str) -> Result<QrCode, String> {
+    QrCode::encode_binary(text.as_bytes(), QrCodeEcc::Medium)
+        .map_err(|_| format!("Text too long for a QR Code ({} bytes)", text.len()))
+}
+
+/// Render a symbol as a standalone SVG document with a quiet zone
+fn to_svg(qr: &QrCode) -> String {
+    let size = qr.size();
+    let dimension = size + QUIET_ZONE * 2;
+    let mut path = String::new();
+    for y in 0..size {
+        for x in 0..size {
+            if qr.get_module(x, y) {
+                if !path.is_empty() {
+                    path.push(' ');
+                }
+                path.push_str(&format!("M{},{}h1v1h-1z", x + QUIET_ZONE, y + QUIET_ZONE));
+            }
+        }
+    }
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" viewBox=\"0 0 {0} {0}\" shape-rendering=\"crispEdges\"><rect width=\"100%\" height=\"100%\" fill=\"#FFFFFF\"/><path d=\"{1}\" fill=\"#000000\"/></svg>",
+        dimension, path
+    )
+}
+
+/// Render `text` as an SVG QR Code
+pub fn qr_svg(text: &str) -> Result<String, String> {
+    encode(text).map(|qr| to_svg(&qr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_picks_smallest_version() {
+        assert_eq!(encode("hello").unwrap().version().value(), 1);
+        let uri = "otpauth://totp/ACME:ops%40example.com?secret=JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP&issuer=ACME&algorithm=SHA1&digits=6&period=30";
+        let qr = encode(uri).unwrap();
+        // 123 bytes: just above the version 7 byte-mode capacity at level M (122)
+        assert_eq!(qr.version().value(), 8);
+        assert_eq!(qr.size(), 49);
+        assert!(encode(&"a".repeat(2400)).is_err());
+    }
+
+    #[test]
+    fn test_svg_modules_match_symbol() {
+        let qr = encode("otpauth://totp/x?secret=JBSWY3DPEHPK3PXP").unwrap();
+        let svg = to_svg(&qr);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains(&format!("viewBox=\"0 0 {0} {0}\"", qr.size() + 8)));
+        assert!(svg.ends_with("</svg>"));
+
+        // Every dark module is drawn exactly once, offset by the quiet zone
+        let dark: Vec<String> = (0..qr.size())
+            .flat_map(|y| (0..qr.size()).map(move |x| (x, y)))
+            .filter(|(x, y)| qr.get_module(*x, *y))
+            .map(|(x, y)| format!("M{},{}h1v1h-1z", x + 4, y + 4))
+            .collect();
+        assert_eq!(svg.matches("h1v1h-1z").count(), dark.len());
+        assert!(dark.iter().all(|module| svg.contains(module.as_str())));
+        // Top-left finder pattern corner
+        assert!(svg.contains("\"M4,4h1v1h-1z"));
+    }
+}
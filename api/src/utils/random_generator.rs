@@ -1,7 +1,8 @@
+use crate::types::GenerationAlgorithm;
 use blake3;
 use bs58;
 use nanoid::nanoid;
-use rand::{Rng, SeedableRng};
+use rand::{Rng, RngCore, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
 /// Generates a random seed generator function for nanoid using ChaCha8Rng internally
@@ -21,8 +22,30 @@ pub fn create_seeded_generator(_seed_32: [u8; 32]) -> fn(usize) -> Vec<u8> {
     generator_fn
 }
 
-/// Generate hash using ChaCha8Rng with seed - simplified approach
-pub fn generate_with_seed(seed_32: [u8; 32], length: usize, alphabet: &[char]) -> String {
+/// Generate hash using ChaCha8Rng with seed and the requested algorithm version
+///
+/// # Arguments
+/// * `seed_32` - Array of 32 bytes used as seed for ChaCha8Rng
+/// * `length` - Number of characters to generate
+/// * `alphabet` - Characters to pick from
+/// * `algorithm` - Character-selection algorithm (must match the one used originally to reproduce a value)
+pub fn generate_with_seed(
+    seed_32: [u8; 32],
+    length: usize,
+    alphabet: &[char],
+    algorithm: GenerationAlgorithm,
+) -> String {
+    match algorithm {
+        GenerationAlgorithm::ModuloV1 => generate_with_seed_v1(seed_32, length, alphabet),
+        GenerationAlgorithm::RejectionV2 => generate_with_seed_v2(seed_32, length, alphabet),
+    }
+}
+
+/// Algorithm v1 (legacy): maps a random byte onto the alphabet with `byte % alphabet_len`
+///
+/// Biased towards the first `256 % alphabet_len` characters. Kept only so that
+/// seeds generated before v2 keep reproducing their original output.
+fn generate_with_seed_v1(seed_32: [u8; 32], length: usize, alphabet: &[char]) -> String {
     let mut rng = ChaCha8Rng::from_seed(seed_32);
 
     // Generate random indices manually to avoid the closure issue
@@ -38,6 +61,38 @@ pub fn generate_with_seed(seed_32: [u8; 32], length: usize, alphabet: &[char]) -
     result
 }
 
+/// Algorithm v2: unbiased character selection using rejection sampling
+fn generate_with_seed_v2(seed_32: [u8; 32], length: usize, alphabet: &[char]) -> String {
+    let mut rng = ChaCha8Rng::from_seed(seed_32);
+
+    (0..length)
+        .map(|_| alphabet[random_index(&mut rng, alphabet.len())])
+        .collect()
+}
+
+/// Draw a uniformly distributed index in `0..upper` using rejection sampling
+///
+/// Draws u32 values and discards those falling in the incomplete last "bucket"
+/// so every index is equally likely. Implemented here (instead of relying on
+/// `random_range`) so the output only depends on the ChaCha8 stream and stays
+/// identical across rand releases and between wasm32 and native targets.
+///
+/// # Panics
+/// Panics if `upper` is 0 or does not fit in a u32
+pub fn random_index<R: RngCore>(rng: &mut R, upper: usize) -> usize {
+    let upper = u32::try_from(upper).expect("random_index upper bound must fit in u32");
+    assert!(upper > 0, "random_index upper bound must be non-zero");
+
+    // Largest multiple of `upper` that fits in u32 range: values at or above it are rejected
+    let zone = u32::MAX - (u32::MAX - upper + 1) % upper;
+    loop {
+        let value = rng.next_u32();
+        if value <= zone {
+            return (value % upper) as usize;
+        }
+    }
+}
+
 /// Generates a random 32-byte seed using nanoid + Blake3
 ///
 /// This function:
@@ -93,6 +148,21 @@ pub fn base58_to_seed(base58_str: &str) -> Result<[u8; 32], String> {
     Ok(seed)
 }
 
+/// Blake3 KDF context for batch item seeds (domain separation per item)
+const BATCH_ITEM_SEED_CONTEXT: &str = "hashrand 2025 batch item seed v1";
+
+/// Derive the seed for item `index` of a batch generated from `seed`
+///
+/// Each item gets an independent, domain-separated seed so values in a batch
+/// never share ChaCha8 streams, yet the whole batch is reproducible from the
+/// single batch seed.
+pub fn derive_batch_item_seed(seed: [u8; 32], index: u32) -> [u8; 32] {
+    let mut key_material = [0u8; 36];
+    key_material[..32].copy_from_slice(&seed);
+    key_material[32..].copy_from_slice(&index.to_le_bytes());
+    blake3::derive_key(BATCH_ITEM_SEED_CONTEXT, &key_material)
+}
+
 /// Generate a 9-digit OTP using ChaCha8 with domain separation
 pub fn generate_otp(seed: [u8; 32]) -> String {
     use crate::types::AlphabetType;
@@ -122,11 +192,16 @@ mod tests {
         let seed = [42u8; 32];
         let alphabet = ['a', 'b', 'c', 'd', 'e']; // Simple alphabet for testing
 
-        let result1 = generate_with_seed(seed, 10, &alphabet);
-        let result2 = generate_with_seed(seed, 10, &alphabet);
+        for algorithm in [
+            GenerationAlgorithm::ModuloV1,
+            GenerationAlgorithm::RejectionV2,
+        ] {
+            let result1 = generate_with_seed(seed, 10, &alphabet, algorithm);
+            let result2 = generate_with_seed(seed, 10, &alphabet, algorithm);
 
-        // Same seed should produce same output
-        assert_eq!(result1, result2);
+            // Same seed should produce same output
+            assert_eq!(result1, result2);
+        }
     }
 
     #[test]
@@ -135,13 +210,62 @@ mod tests {
         let seed2 = [123u8; 32];
         let alphabet = ['a', 'b', 'c', 'd', 'e']; // Simple alphabet for testing
 
-        let result1 = generate_with_seed(seed1, 10, &alphabet);
-        let result2 = generate_with_seed(seed2, 10, &alphabet);
+        let result1 = generate_with_seed(seed1, 10, &alphabet, GenerationAlgorithm::latest());
+        let result2 = generate_with_seed(seed2, 10, &alphabet, GenerationAlgorithm::latest());
 
         // Different seeds should produce different output
         assert_ne!(result1, result2);
     }
 
+    #[test]
+    fn test_legacy_algorithm_reproduces_original_output() {
+        // Value produced by the pre-versioning generator for this seed - must never change
+        let alphabet = crate::types::AlphabetType::Base58.as_chars();
+        let result = generate_with_seed([42u8; 32], 21, &alphabet, GenerationAlgorithm::ModuloV1);
+        assert_eq!(result, "9XUTsh4uHUe8x873EcsBt");
+    }
+
+    #[test]
+    fn test_unbiased_algorithm_is_uniform() {
+        // 200-char alphabet: v1 picks indices 0..56 twice as often as the rest
+        let alphabet: Vec<char> = (0..200u32)
+            .map(|i| char::from_u32(0x100 + i).unwrap())
+            .collect();
+        let output = generate_with_seed(
+            [7u8; 32],
+            200_000,
+            &alphabet,
+            GenerationAlgorithm::RejectionV2,
+        );
+
+        let low = output.chars().filter(|c| (*c as u32) < 0x100 + 56).count() as f64;
+        let high = output.chars().count() as f64 - low;
+
+        // Uniform: low/high ≈ 56/144. Biased v1 would give ≈ 112/144.
+        let ratio = (low / 56.0) / (high / 144.0);
+        assert!((0.95..1.05).contains(&ratio), "ratio = {}", ratio);
+    }
+
+    #[test]
+    fn test_batch_item_seeds_are_distinct_and_stable() {
+        let seed = [3u8; 32];
+        let first = derive_batch_item_seed(seed, 0);
+        assert_eq!(first, derive_batch_item_seed(seed, 0));
+        assert_ne!(first, derive_batch_item_seed(seed, 1));
+        assert_ne!(first, seed);
+        assert_ne!(first, derive_batch_item_seed([4u8; 32], 0));
+    }
+
+    #[test]
+    fn test_random_index_bounds() {
+        let mut rng = ChaCha8Rng::from_seed([1u8; 32]);
+        for upper in [1usize, 2, 10, 49, 58, 62, 73, 1000] {
+            for _ in 0..1000 {
+                assert!(random_index(&mut rng, upper) < upper);
+            }
+        }
+    }
+
     #[test]
     fn test_random_seed_generation() {
         let seed1 = generate_random_seed();
@@ -1,9 +1,15 @@
 use crate::handlers::custom::handle_custom_request;
 use crate::handlers::login::handle_refresh;
 use crate::handlers::{
-    handle_api_key_request, handle_confirm_read, handle_create_secret, handle_delete_secret,
-    handle_keys_request, handle_login, handle_mnemonic_request, handle_password_request,
-    handle_retrieve_secret, handle_user_keys_request, handle_version,
+    handle_api_key_request, handle_api_key_verify_request, handle_confirm_read,
+    handle_create_request, handle_create_secret, handle_delete_secret, handle_download_attachment,
+    handle_draw_request, handle_from_seed_request, handle_fulfill_request, handle_get_request,
+    handle_identifier_request, handle_keypair_request, handle_keys_request, handle_login,
+    handle_mnemonic_request, handle_mnemonic_validate_request, handle_otp_request,
+    handle_otp_verify_request, handle_passphrase_request, handle_password_request,
+    handle_pronounceable_request, handle_retrieve_secret, handle_slip39_combine_request,
+    handle_slip39_split_request, handle_symmetric_key_request, handle_user_keys_request,
+    handle_version,
 };
 
 // Test endpoint handler (DEV-MODE ONLY - eliminated in production builds)
@@ -76,6 +82,10 @@ pub async fn route_request_with_req(
                 _ => handle_method_not_allowed(),
             }
         }
+        path if path.ends_with("/api/api-key/verify") => match *method {
+            Method::Post => handle_api_key_verify_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
         path if path.ends_with("/api/mnemonic") => {
             match *method {
                 Method::Get => {
@@ -89,6 +99,54 @@ pub async fn route_request_with_req(
                 _ => handle_method_not_allowed(),
             }
         }
+        path if path.ends_with("/api/mnemonic/validate") => match *method {
+            Method::Post => handle_mnemonic_validate_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/passphrase") => match *method {
+            Method::Get | Method::Post => handle_passphrase_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/pronounceable") => match *method {
+            Method::Get | Method::Post => handle_pronounceable_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/keypair") => match *method {
+            Method::Get | Method::Post => handle_keypair_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/symmetric-key") => match *method {
+            Method::Get | Method::Post => handle_symmetric_key_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/draw") => match *method {
+            Method::Get | Method::Post => handle_draw_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/otp") => match *method {
+            Method::Get | Method::Post => handle_otp_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/otp/verify") => match *method {
+            Method::Post => handle_otp_verify_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/from-seed") => match *method {
+            Method::Post => handle_from_seed_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/slip39/split") => match *method {
+            Method::Post => handle_slip39_split_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/slip39/combine") => match *method {
+            Method::Post => handle_slip39_combine_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/identifier") => match *method {
+            Method::Get | Method::Post => handle_identifier_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
 
         // GET-only public endpoints (no authentication required)
         path if path.ends_with("/api/version") => match *method {
@@ -121,6 +179,25 @@ pub async fn route_request_with_req(
             }
             _ => handle_method_not_allowed(),
         },
+        path if path.starts_with("/api/shared-secret/attachment") => match *method {
+            Method::Get => {
+                let hash = query_params.get("hash").map(|s| s.as_str()).unwrap_or("");
+                handle_download_attachment(req, hash).await
+            }
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/shared-secret/request/fulfill") => match *method {
+            Method::Post => handle_fulfill_request(req).await,
+            _ => handle_method_not_allowed(),
+        },
+        path if path.ends_with("/api/shared-secret/request") => match *method {
+            Method::Post => handle_create_request(req).await,
+            Method::Get => {
+                let hash = query_params.get("hash").map(|s| s.as_str()).unwrap_or("");
+                handle_get_request(req, hash).await
+            }
+            _ => handle_method_not_allowed(),
+        },
         path if path.starts_with("/api/shared-secret/") => {
             // Extract hash from path: /api/shared-secret/{hash}
             let hash = path.trim_start_matches("/api/shared-secret/");
@@ -155,13 +232,34 @@ fn handle_not_found() -> anyhow::Result<Response> {
 
 Available endpoints:
 - GET /api/custom?length=21&alphabet=0&prefix=&suffix=&raw=true
+- GET /api/custom?template=AA-9999&alphabet=1 (template with placeholders and check digits)
 - POST /api/custom (JSON body with optional seed parameter)
 - GET /api/password?length=21&alphabet=3&raw=true
 - POST /api/password (JSON body with optional seed parameter)
 - GET /api/api-key?length=44&alphabet=2&raw=true
 - POST /api/api-key (JSON body with optional seed parameter)
+- POST /api/api-key/verify (JSON body: key, optional expected prefix)
 - GET /api/mnemonic?language=0&words=12 (BIP39 mnemonic phrases)
 - POST /api/mnemonic (JSON body with seed parameter)
+- POST /api/mnemonic/validate (JSON body: mnemonic, optional language and passphrase)
+- GET /api/passphrase?words=6&language=0&separator=-&capitalization=0&insert=0
+- POST /api/passphrase (JSON body with optional seed parameter)
+- GET /api/pronounceable?mode=0&syllables=12&group=3&separator=-&digits=0
+- POST /api/pronounceable (JSON body with optional seed parameter)
+- GET /api/otp?account=ops@example.com&issuer=ACME&type=0&algorithm=0&digits=6&period=30
+- POST /api/otp (JSON body with optional seed parameter)
+- POST /api/otp/verify (JSON body: secret, code, optional type, algorithm, digits, period, counter, window)
+- POST /api/from-seed (JSON body: seed, generator 0-6 for bare seeds and the original endpoint's parameters)
+- POST /api/slip39/split (JSON body: groups, group_threshold, optional mnemonic and passphrase)
+- POST /api/slip39/combine (JSON body: shares, optional passphrase and language)
+- GET /api/identifier?format=0
+- GET /api/keypair?algorithm=0&comment=user@host (private key ECDH-encrypted to the requester)
+- POST /api/keypair (JSON body with optional seed parameter)
+- GET /api/symmetric-key?purpose=0 (HMAC/AEAD keys, salts, nonces, Fernet, JWK oct, age)
+- POST /api/symmetric-key (JSON body with optional seed parameter)
+- GET /api/draw?mode=0&min=1&max=6&draws=1 (dice, lotteries and shuffles, reproducible from seed)
+- POST /api/draw (JSON body with optional seed and items parameters)
+- POST /api/identifier (JSON body with optional seed and time_ms parameters)
 - POST /api/login/ (Generate magic link - JSON: {"email": "user@example.com"})
 - POST /api/login/magiclink/ (Validate magic link with Ed25519 signature and get JWT tokens)
 - POST /api/shared-secret/create (Create shared secret with dual-URL system)
@@ -169,6 +267,10 @@ Available endpoints:
 - POST /api/shared-secret/{hash} (Retrieve shared secret with OTP validation)
 - DELETE /api/shared-secret/{hash} (Delete shared secret if not fully consumed)
 - GET /api/shared-secret/confirm-read?hash={hash} (Confirm read tracking)
+- GET /api/shared-secret/attachment?hash={hash}&chunk={index} (Download encrypted attachment chunk)
+- POST /api/shared-secret/request (Request a secret from an invitee)
+- GET /api/shared-secret/request?hash={hash} (Secret request details for the invitee)
+- POST /api/shared-secret/request/fulfill (Send the requested secret)
 - GET /api/version
 
 Parameters:
@@ -176,13 +278,46 @@ Parameters:
 - alphabet: Integer 0-4 (custom: 0-4, password: 1 or 3, api-key: 1 or 2)
   0=base58 (default custom), 1=no-look-alike, 2=full (default api-key),
   3=full-with-symbols (default password), 4=numeric
-- language: Integer 0-9 (mnemonic only, default 0)
+- custom_alphabet: 2-256 distinct printable characters (custom only, replaces alphabet,
+  duplicates removed, default length ≈110 bits of entropy; use POST for non URL-safe chars)
+- policy: password only, preset name (strict, active-directory, aws-iam, oracle, url-safe)
+  or JSON object {"preset","min_uppercase","min_lowercase","min_digits","min_symbols",
+  "max_repeat","banned_chars","start_with_letter"}
+- language: Integer 0-9 (mnemonic/passphrase only, default 0)
   0=english, 1=spanish, 2=french, 3=portuguese, 4=japanese,
   5=chinese-simplified, 6=chinese-traditional, 7=italian, 8=korean, 9=czech
-- words: 12 (default), 24 (mnemonic only)
+- words: 12 (default), 15, 18, 21, 24 (mnemonic); 4-20, default 6 (passphrase)
+- separator: up to 3 characters, default "-" (passphrase/pronounceable only)
+- capitalization: 0=lowercase (default), 1=title, 2=uppercase, 3=random (passphrase only)
+- insert: 0=none (default), 1=digit, 2=symbol, 3=digit+symbol (passphrase only)
+- syllables: 4-32, default 12 (pronounceable mode 0, about 6.3 bits each);
+  group: syllables per group, 0-32 (default 3, 0=ungrouped); digits: 0-4 appended (default 0)
+- mode: 0=syllables (default), 1=proquint (pronounceable only); proquint bytes: even 2-32 (default 8)
+- format: 0=uuid-v4 (default), 1=uuid-v7, 2=ulid, 3=ksuid (identifier only)
+- time_ms: Unix ms embedded in uuid-v7/ulid/ksuid, defaults to request time (identifier only)
+- algorithm: 1 (legacy modulo), 2 (default, unbiased) - custom/password/api-key only,
+  must match the value returned with the seed to reproduce a result
+- algorithm: 0=ed25519 (default), 1=x25519 (keypair); comment: OpenSSH comment, max 128 chars
+- purpose: 0=hmac-sha256 (default), 1=hmac-sha512, 2=aead-key, 3=salt, 4=nonce, 5=fernet,
+  6=jwk-oct, 7=age (symmetric-key only); encoding (0-3) overrides hmac/aead/salt/nonce output
+- mode: 0=range (default), 1=sample without replacement, 2=shuffle (draw only);
+  min/max: inclusive 64-bit bounds (default 1-6), draws: 1-1000 values (range, default 1),
+  k: 1-1000 distinct picks (sample, default 1), items: JSON array or one per line, up to
+  1000 items of 256 chars (sample without items draws from min..max, at most 100000 integers)
+- count: 1-500 values from one seed (default 1), returned as "hashes" when > 1
+- min_entropy: bits (0-1024), rejects parameters whose effective entropy is lower;
+  responses report entropy_bits and strength (weak <64, fair <96, strong <128, very-strong)
 - raw: true (default), false (adds newline)
 - prefix/suffix: max 32 chars each (custom only)
-- seed: base58-encoded 32 bytes (optional for POST requests)"#;
+- checksum: 0=none (default, legacy ak_ keys), 1=crc32, 2=blake3 (api-key only); structured keys
+  take prefix (2-16 lowercase letters/digits, default "ak") and optional environment (e.g. live, test)
+- template: shape of the value (custom only, replaces length/prefix/suffix, max 128 chars):
+  X=alphabet char, A/a=upper/lowercase letter of the alphabet, 9=digit, {luhn}/{mod97}=check
+  digits over the preceding characters, \=escape; anything else is literal (e.g. AA-9999)
+- mode: 0=alphabet (default), 1=bytes (custom only); byte mode parameters:
+  bytes: 16-512 (default 32), input: data to digest (POST), encoding: 0=hex (default),
+  1=base58, 2=base64, 3=base64url, digest: 0=sha-256 (default), 1=sha-512, 2=sha3-256, 3=blake3
+- seed: seed envelope returned by a generator, or base58-encoded 32 bytes (optional for POST requests)"#;
 
     Ok(Response::builder()
         .status(404)
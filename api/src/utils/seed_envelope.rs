@@ -0,0 +1,243 @@
+//! Versioned seed envelope for forward-compatible reproducibility
+//!
+//! A bare 32-byte base58 seed only reproduces a value while the generation code
+//! stays unchanged. The envelope records which generator and which algorithm
+//! version produced the value, so the matching (possibly historical) code path
+//! can be selected when the seed comes back:
+//! - Version 0: bare 32-byte seed, as issued before envelopes existed. The
+//!   generator is unknown and the `algorithm` parameter (default: 1, the only
+//!   algorithm before versioning) applies
+//! - Version 1: `[1, generator, algorithm, seed(32), checksum(4)]` in base58,
+//!   where `algorithm` is 0 for generators without versioned selection and the
+//!   checksum is the first 4 bytes of BLAKE3 over the preceding 35 bytes
+//!
+//! Envelope version 1 also pins the current OTP derivation and pattern
+//! avoidance scheme: changing either requires a new envelope version, with the
+//! old code kept next to the new one and selected by version.
+
+use crate::types::{GenerationAlgorithm, GeneratorType};
+use crate::utils::{base58_to_seed, seed_to_base58};
+use std::collections::HashMap;
+
+/// Envelope version emitted for new generations
+pub const SEED_ENVELOPE_VERSION: u8 = 1;
+
+/// Encoded size of a version 1 envelope in bytes
+const ENVELOPE_V1_LEN: usize = 3 + 32 + 4;
+
+/// Seed plus the metadata needed to reproduce its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedEnvelope {
+    pub version: u8,
+    pub seed: [u8; 32],
+    /// Generator that produced the value (unknown for version 0)
+    pub generator: Option<GeneratorType>,
+    /// Character-selection algorithm (unknown for version 0, None if not applicable)
+    pub algorithm: Option<GenerationAlgorithm>,
+}
+
+impl SeedEnvelope {
+    /// Wrap a seed in the current envelope version
+    pub fn new(
+        seed: [u8; 32],
+        generator: GeneratorType,
+        algorithm: Option<GenerationAlgorithm>,
+    ) -> Self {
+        Self {
+            version: SEED_ENVELOPE_VERSION,
+            seed,
+            generator: Some(generator),
+            algorithm,
+        }
+    }
+
+    /// Base58 text form (version 0 stays a bare seed)
+    pub fn encode(&self) -> String {
+        if self.version == 0 {
+            return seed_to_base58(&self.seed);
+        }
+
+        let mut bytes = Vec::with_capacity(ENVELOPE_V1_LEN);
+        bytes.push(self.version);
+        bytes.push(self.generator.map(u8::from).unwrap_or(u8::MAX));
+        bytes.push(self.algorithm.map(u8::from).unwrap_or(0));
+        bytes.extend_from_slice(&self.seed);
+        let checksum = blake3::hash(&bytes);
+        bytes.extend_from_slice(&checksum.as_bytes()[..4]);
+        bs58::encode(bytes).into_string()
+    }
+
+    /// Parse a bare seed (version 0) or a versioned envelope
+    pub fn decode(text: &str) -> Result<Self, String> {
+        let bytes = bs58::decode(text)
+            .into_vec()
+            .map_err(|e| format!("Invalid base58 string: {}", e))?;
+
+        if bytes.len() == 32 {
+            return Ok(Self {
+                version: 0,
+                seed: base58_to_seed(text)?,
+                generator: None,
+                algorithm: None,
+            });
+        }
+
+        match bytes.first() {
+            Some(1) if bytes.len() == ENVELOPE_V1_LEN => {}
+            Some(1) => {
+                return Err(format!(
+                    "Seed envelope v1 must be {} bytes, got {} bytes",
+                    ENVELOPE_V1_LEN,
+                    bytes.len()
+                ));
+            }
+            Some(version) if bytes.len() > 32 => {
+                return Err(format!("Unsupported seed envelope version {}", version));
+            }
+            _ => {
+                return Err(format!(
+                    "Seed must be exactly 32 bytes, got {} bytes",
+                    bytes.len()
+                ));
+            }
+        }
+
+        let (body, checksum) = bytes.split_at(ENVELOPE_V1_LEN - 4);
+        if blake3::hash(body).as_bytes()[..4] != *checksum {
+            return Err("Seed envelope checksum mismatch".to_string());
+        }
+
+        let generator = GeneratorType::try_from(body[1])
+            .map_err(|_| format!("Unknown generator {} in seed envelope", body[1]))?;
+        let algorithm = match body[2] {
+            0 => None,
+            version => Some(
+                GenerationAlgorithm::try_from(version)
+                    .map_err(|_| format!("Unknown algorithm {} in seed envelope", version))?,
+            ),
+        };
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&body[3..]);
+
+        Ok(Self {
+            version: 1,
+            seed,
+            generator: Some(generator),
+            algorithm,
+        })
+    }
+}
+
+/// Replace an enveloped `seed` parameter with its bare seed and metadata
+///
+/// The recorded algorithm becomes the `algorithm` parameter (an explicit,
+/// different value is rejected). Version 0 seeds are left untouched.
+///
+/// # Returns
+/// * `Result<Option<GeneratorType>, String>` - Generator recorded in the envelope
+pub fn unwrap_seed_envelope(
+    params: &mut HashMap<String, String>,
+) -> Result<Option<GeneratorType>, String> {
+    let Some(seed_str) = params.get("seed") else {
+        return Ok(None);
+    };
+    let envelope = SeedEnvelope::decode(seed_str).map_err(|e| format!("Invalid seed: {}", e))?;
+    if envelope.version == 0 {
+        return Ok(None);
+    }
+
+    if let Some(algorithm) = envelope.algorithm {
+        let recorded = u8::from(algorithm).to_string();
+        match params.get("algorithm") {
+            Some(requested) if *requested != recorded => {
+                return Err(format!(
+                    "Parameter algorithm={} conflicts with algorithm {} recorded in the seed",
+                    requested, recorded
+                ));
+            }
+            _ => {
+                params.insert("algorithm".to_string(), recorded);
+            }
+        }
+    }
+    params.insert("seed".to_string(), seed_to_base58(&envelope.seed));
+
+    Ok(envelope.generator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::params;
+
+    #[test]
+    fn test_bare_seed_decodes_as_version_0() {
+        let bare = seed_to_base58(&[7u8; 32]);
+        let envelope = SeedEnvelope::decode(&bare).unwrap();
+        assert_eq!(envelope.version, 0);
+        assert_eq!(envelope.seed, [7u8; 32]);
+        assert_eq!(envelope.generator, None);
+        assert_eq!(envelope.encode(), bare);
+    }
+
+    #[test]
+    fn test_v1_roundtrip() {
+        let envelope = SeedEnvelope::new(
+            [9u8; 32],
+            GeneratorType::Password,
+            Some(GenerationAlgorithm::ModuloV1),
+        );
+        let text = envelope.encode();
+        assert_ne!(text, seed_to_base58(&[9u8; 32]));
+        assert_eq!(SeedEnvelope::decode(&text).unwrap(), envelope);
+
+        let without_algorithm = SeedEnvelope::new([9u8; 32], GeneratorType::Mnemonic, None);
+        assert_eq!(
+            SeedEnvelope::decode(&without_algorithm.encode()).unwrap(),
+            without_algorithm
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_corruption_and_unknown_versions() {
+        let mut bytes =
+            bs58::decode(SeedEnvelope::new([1u8; 32], GeneratorType::Custom, None).encode())
+                .into_vec()
+                .unwrap();
+        bytes[10] ^= 1;
+        assert!(SeedEnvelope::decode(&bs58::encode(&bytes).into_string()).is_err());
+
+        bytes[0] = 2;
+        let error = SeedEnvelope::decode(&bs58::encode(&bytes).into_string()).unwrap_err();
+        assert!(error.contains("version 2"));
+
+        assert!(SeedEnvelope::decode(&bs58::encode([1u8; 16]).into_string()).is_err());
+        assert!(SeedEnvelope::decode("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_unwrap_seed_envelope() {
+        let text = SeedEnvelope::new(
+            [3u8; 32],
+            GeneratorType::Custom,
+            Some(GenerationAlgorithm::ModuloV1),
+        )
+        .encode();
+
+        let mut request = params(&[("seed", &text)]);
+        assert_eq!(
+            unwrap_seed_envelope(&mut request).unwrap(),
+            Some(GeneratorType::Custom)
+        );
+        assert_eq!(request["seed"], seed_to_base58(&[3u8; 32]));
+        assert_eq!(request["algorithm"], "1");
+
+        let mut conflicting = params(&[("seed", &text), ("algorithm", "2")]);
+        assert!(unwrap_seed_envelope(&mut conflicting).is_err());
+
+        let bare = seed_to_base58(&[3u8; 32]);
+        let mut legacy = params(&[("seed", &bare)]);
+        assert_eq!(unwrap_seed_envelope(&mut legacy).unwrap(), None);
+        assert_eq!(legacy, params(&[("seed", &bare)]));
+    }
+}
@@ -0,0 +1,444 @@
+//! SLIP-39 Shamir backup of master secrets
+//!
+//! Splits a master secret (e.g. BIP39 mnemonic entropy) into groups of
+//! mnemonic shares with two-level thresholds, and recombines them:
+//! - Feistel encryption with PBKDF2-HMAC-SHA256 and an optional passphrase
+//! - Group and member Shamir sharing over GF(256) with digest verification
+//! - 10-bit word encoding with RS1024 checksums
+//!
+//! All randomness (identifier, share polynomials) comes from a caller-provided
+//! RNG so splits are reproducible from the request seed.
+
+pub mod shamir;
+pub mod share;
+pub mod wordlist;
+
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use shamir::{recover_secret, split_secret};
+use share::Share;
+use std::collections::BTreeMap;
+
+/// PBKDF2 base iteration count (split across the Feistel rounds)
+const BASE_ITERATION_COUNT: u32 = 10000;
+/// Number of Feistel rounds
+const ROUND_COUNT: u8 = 4;
+/// Minimum master secret length in bytes
+pub const MIN_SECRET_BYTES: usize = 16;
+/// Maximum number of groups and of members per group
+pub const MAX_SHARE_COUNT: u8 = 16;
+
+/// Member threshold and member count of one group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupSpec {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+/// Parameters shared by every share of a split
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitOptions {
+    pub group_threshold: u8,
+    pub iteration_exponent: u8,
+    pub extendable: bool,
+}
+
+/// Recovered master secret with the parameters found in the shares
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveredSecret {
+    pub master_secret: Vec<u8>,
+    pub identifier: u16,
+    pub extendable: bool,
+    pub iteration_exponent: u8,
+    pub group_threshold: u8,
+    pub group_count: u8,
+}
+
+/// Split a master secret into SLIP-39 share groups
+///
+/// # Arguments
+/// * `rng` - Random source for the identifier and share polynomials
+/// * `master_secret` - Secret to split (at least 16 bytes, even length)
+/// * `passphrase` - Printable ASCII passphrase ("" for none)
+/// * `options` - Group threshold, iteration exponent and extendable flag
+/// * `groups` - Member threshold/count for each group
+///
+/// # Returns
+/// * `Result<Vec<Vec<String>>, String>` - Share mnemonics per group
+pub fn generate_shares<R: RngCore>(
+    rng: &mut R,
+    master_secret: &[u8],
+    passphrase: &str,
+    options: SplitOptions,
+    groups: &[GroupSpec],
+) -> Result<Vec<Vec<String>>, String> {
+    validate_secret(master_secret)?;
+    validate_passphrase(passphrase)?;
+    if options.iteration_exponent > 15 {
+        return Err("Iteration exponent must be between 0 and 15".to_string());
+    }
+    if groups.is_empty() || groups.len() > MAX_SHARE_COUNT as usize {
+        return Err(format!(
+            "Number of groups must be between 1 and {}",
+            MAX_SHARE_COUNT
+        ));
+    }
+    if options.group_threshold == 0 || options.group_threshold as usize > groups.len() {
+        return Err("Group threshold must be between 1 and the number of groups".to_string());
+    }
+    for group in groups {
+        if group.member_threshold == 0
+            || group.member_threshold > group.member_count
+            || group.member_count > MAX_SHARE_COUNT
+        {
+            return Err(format!(
+                "Member threshold must be between 1 and the member count (at most {})",
+                MAX_SHARE_COUNT
+            ));
+        }
+        if group.member_threshold == 1 && group.member_count > 1 {
+            return Err(
+                "A member threshold of 1 requires a single member (use 1-of-1 instead)".to_string(),
+            );
+        }
+    }
+
+    let identifier = (rng.next_u32() & 0x7FFF) as u16;
+    let encrypted = encrypt(
+        master_secret,
+        passphrase,
+        options.iteration_exponent,
+        identifier,
+        options.extendable,
+    );
+
+    let group_shares = split_secret(rng, options.group_threshold, groups.len() as u8, &encrypted)?;
+
+    group_shares
+        .into_iter()
+        .zip(groups)
+        .map(|((group_index, group_secret), group)| {
+            let member_shares = split_secret(
+                rng,
+                group.member_threshold,
+                group.member_count,
+                &group_secret,
+            )?;
+            Ok(member_shares
+                .into_iter()
+                .map(|(member_index, value)| {
+                    Share {
+                        identifier,
+                        extendable: options.extendable,
+                        iteration_exponent: options.iteration_exponent,
+                        group_index,
+                        group_threshold: options.group_threshold,
+                        group_count: groups.len() as u8,
+                        member_index,
+                        member_threshold: group.member_threshold,
+                        value,
+                    }
+                    .to_mnemonic()
+                })
+                .collect())
+        })
+        .collect()
+}
+
+/// Recombine SLIP-39 share mnemonics into the master secret
+///
+/// Requires exactly the threshold number of groups, each with exactly its
+/// member threshold of shares; digests are verified at both levels.
+pub fn combine_shares(mnemonics: &[String], passphrase: &str) -> Result<RecoveredSecret, String> {
+    validate_passphrase(passphrase)?;
+    let shares: Vec<Share> = mnemonics
+        .iter()
+        .map(|mnemonic| Share::from_mnemonic(mnemonic))
+        .collect::<Result<_, _>>()?;
+
+    let first = shares.first().ok_or("No shares provided")?;
+    if shares.iter().any(|share| {
+        share.identifier != first.identifier
+            || share.extendable != first.extendable
+            || share.iteration_exponent != first.iteration_exponent
+            || share.group_threshold != first.group_threshold
+            || share.group_count != first.group_count
+            || share.value.len() != first.value.len()
+    }) {
+        return Err(
+            "All shares must belong to the same backup (identifier and parameters differ)"
+                .to_string(),
+        );
+    }
+
+    // Group shares by group index
+    let mut groups: BTreeMap<u8, Vec<&Share>> = BTreeMap::new();
+    for share in &shares {
+        groups.entry(share.group_index).or_default().push(share);
+    }
+
+    if groups.len() != first.group_threshold as usize {
+        return Err(format!(
+            "Wrong number of groups: expected {}, got {}",
+            first.group_threshold,
+            groups.len()
+        ));
+    }
+
+    let mut group_secrets: Vec<(u8, Vec<u8>)> = Vec::with_capacity(groups.len());
+    for (group_index, members) in &groups {
+        let member_threshold = members[0].member_threshold;
+        if members
+            .iter()
+            .any(|share| share.member_threshold != member_threshold)
+        {
+            return Err(format!(
+                "Group {} shares disagree on the member threshold",
+                group_index + 1
+            ));
+        }
+        if members.len() != member_threshold as usize {
+            return Err(format!(
+                "Group {} needs exactly {} share(s), got {}",
+                group_index + 1,
+                member_threshold,
+                members.len()
+            ));
+        }
+        let member_values: Vec<(u8, Vec<u8>)> = members
+            .iter()
+            .map(|share| (share.member_index, share.value.clone()))
+            .collect();
+        group_secrets.push((
+            *group_index,
+            recover_secret(member_threshold, &member_values)?,
+        ));
+    }
+
+    let encrypted = recover_secret(first.group_threshold, &group_secrets)?;
+    let master_secret = decrypt(
+        &encrypted,
+        passphrase,
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+    );
+
+    Ok(RecoveredSecret {
+        master_secret,
+        identifier: first.identifier,
+        extendable: first.extendable,
+        iteration_exponent: first.iteration_exponent,
+        group_threshold: first.group_threshold,
+        group_count: first.group_count,
+    })
+}
+
+/// Master secrets must be at least 128 bits and an even number of bytes
+fn validate_secret(master_secret: &[u8]) -> Result<(), String> {
+    if master_secret.len() < MIN_SECRET_BYTES || !master_secret.len().is_multiple_of(2) {
+        return Err(format!(
+            "Master secret must be an even number of bytes, at least {}",
+            MIN_SECRET_BYTES
+        ));
+    }
+    Ok(())
+}
+
+/// SLIP-39 passphrases are restricted to printable ASCII
+fn validate_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.bytes().any(|b| !(32..=126).contains(&b)) {
+        return Err("SLIP-39 passphrase must contain only printable ASCII characters".to_string());
+    }
+    Ok(())
+}
+
+/// Four-round Feistel encryption of the master secret
+fn encrypt(
+    master_secret: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Vec<u8> {
+    let salt = salt(identifier, extendable);
+    let (left, right) = master_secret.split_at(master_secret.len() / 2);
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    for round in 0..ROUND_COUNT {
+        let f = round_function(round, passphrase, iteration_exponent, &salt, &right);
+        let new_right = xor(&left, &f);
+        left = right;
+        right = new_right;
+    }
+    [right, left].concat()
+}
+
+/// Inverse of `encrypt` (rounds in reverse order)
+fn decrypt(
+    encrypted: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Vec<u8> {
+    let salt = salt(identifier, extendable);
+    let (left, right) = encrypted.split_at(encrypted.len() / 2);
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    for round in (0..ROUND_COUNT).rev() {
+        let f = round_function(round, passphrase, iteration_exponent, &salt, &right);
+        let new_right = xor(&left, &f);
+        left = right;
+        right = new_right;
+    }
+    [right, left].concat()
+}
+
+/// Feistel salt: "shamir" || identifier for non-extendable backups
+fn salt(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        Vec::new()
+    } else {
+        [b"shamir".as_slice(), &identifier.to_be_bytes()].concat()
+    }
+}
+
+/// PBKDF2-HMAC-SHA256(round || passphrase, salt || right, (10000 << e) / 4)
+fn round_function(
+    round: u8,
+    passphrase: &str,
+    iteration_exponent: u8,
+    salt: &[u8],
+    right: &[u8],
+) -> Vec<u8> {
+    let password = [&[round], passphrase.as_bytes()].concat();
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+    let salted = [salt, right].concat();
+
+    // Single PBKDF2 block: output length (half the secret) is at most 32 bytes
+    let hmac = |message: &[u8]| {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&password)
+            .expect("HMAC accepts keys of any length");
+        mac.update(message);
+        mac.finalize().into_bytes()
+    };
+    let mut block = hmac(&[salted.as_slice(), &1u32.to_be_bytes()].concat());
+    let mut output = block;
+    for _ in 1..iterations {
+        block = hmac(&block);
+        for (out, byte) in output.iter_mut().zip(block.iter()) {
+            *out ^= byte;
+        }
+    }
+
+    output[..right.len()].to_vec()
+}
+
+/// Bytewise XOR of equal-length slices
+fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().zip(b).map(|(x, y)| x ^ y).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_vector_single_share() {
+        // SLIP-39 test vector 1
+        let shares = strings(&[
+            "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard",
+        ]);
+        let recovered = combine_shares(&shares, "TREZOR").unwrap();
+        assert_eq!(
+            hex::encode(recovered.master_secret),
+            "bb54aac4b89dc868ba37d9cc21b2cece"
+        );
+    }
+
+    #[test]
+    fn test_vector_two_of_three() {
+        // SLIP-39 test vector 4 (basic 2-of-3 sharing)
+        let shares = strings(&[
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ]);
+        let recovered = combine_shares(&shares, "TREZOR").unwrap();
+        assert_eq!(
+            hex::encode(recovered.master_secret),
+            "b43ceb7e57a0ea8766221624d01b0864"
+        );
+        assert!(combine_shares(&shares[..1], "TREZOR").is_err());
+    }
+
+    #[test]
+    fn test_split_and_combine_groups() {
+        let mut rng = ChaCha8Rng::from_seed([9u8; 32]);
+        let secret = [7u8; 32];
+        let options = SplitOptions {
+            group_threshold: 2,
+            iteration_exponent: 0,
+            extendable: true,
+        };
+        let groups = [
+            GroupSpec {
+                member_threshold: 1,
+                member_count: 1,
+            },
+            GroupSpec {
+                member_threshold: 2,
+                member_count: 3,
+            },
+            GroupSpec {
+                member_threshold: 3,
+                member_count: 5,
+            },
+        ];
+        let shares = generate_shares(&mut rng, &secret, "pass", options, &groups).unwrap();
+        assert_eq!(shares.iter().map(Vec::len).collect::<Vec<_>>(), [1, 3, 5]);
+
+        let chosen = vec![
+            shares[1][0].clone(),
+            shares[1][2].clone(),
+            shares[2][1].clone(),
+            shares[2][3].clone(),
+            shares[2][4].clone(),
+        ];
+        let recovered = combine_shares(&chosen, "pass").unwrap();
+        assert_eq!(recovered.master_secret, secret.to_vec());
+        assert!(recovered.extendable);
+
+        // A wrong passphrase yields a different secret (plausible deniability)
+        assert_ne!(
+            combine_shares(&chosen, "other").unwrap().master_secret,
+            secret.to_vec()
+        );
+    }
+
+    #[test]
+    fn test_invalid_split_parameters() {
+        let mut rng = ChaCha8Rng::from_seed([9u8; 32]);
+        let options = SplitOptions {
+            group_threshold: 1,
+            iteration_exponent: 0,
+            extendable: false,
+        };
+        let one_of_three = [GroupSpec {
+            member_threshold: 1,
+            member_count: 3,
+        }];
+        assert!(generate_shares(&mut rng, &[0u8; 16], "", options, &one_of_three).is_err());
+        let two_of_three = [GroupSpec {
+            member_threshold: 2,
+            member_count: 3,
+        }];
+        assert!(generate_shares(&mut rng, &[0u8; 15], "", options, &two_of_three).is_err());
+        assert!(generate_shares(&mut rng, &[0u8; 16], "ñ", options, &two_of_three).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+//! Shamir secret sharing over GF(256) as specified by SLIP-39
+//!
+//! Uses the Rijndael field (x^8 + x^4 + x^3 + x + 1) with a digest share at
+//! x = 254 and the secret at x = 255, so recovery detects wrong share sets.
+
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+
+/// x coordinate holding the digest share
+const DIGEST_INDEX: u8 = 254;
+/// x coordinate holding the shared secret
+const SECRET_INDEX: u8 = 255;
+/// Digest length in bytes
+const DIGEST_LENGTH_BYTES: usize = 4;
+
+/// Exponent/logarithm tables for GF(256) with generator 3
+struct FieldTables {
+    exp: [u8; 255],
+    log: [u8; 256],
+}
+
+impl FieldTables {
+    fn new() -> Self {
+        let mut exp = [0u8; 255];
+        let mut log = [0u8; 256];
+        let mut poly: u16 = 1;
+        for (i, slot) in exp.iter_mut().enumerate() {
+            *slot = poly as u8;
+            log[poly as usize] = i as u8;
+            // Multiply by the generator (x + 1), reducing by the Rijndael polynomial
+            poly = (poly << 1) ^ poly;
+            if poly & 0x100 != 0 {
+                poly ^= 0x11B;
+            }
+        }
+        Self { exp, log }
+    }
+}
+
+/// Split `secret` into `share_count` shares, any `threshold` of which recover it
+///
+/// # Returns
+/// * `Vec<(u8, Vec<u8>)>` - (x coordinate, share value) pairs, x = 0..share_count
+pub fn split_secret<R: RngCore>(
+    rng: &mut R,
+    threshold: u8,
+    share_count: u8,
+    secret: &[u8],
+) -> Result<Vec<(u8, Vec<u8>)>, String> {
+    if threshold == 0 || threshold > share_count || share_count > 16 {
+        return Err("Threshold must be between 1 and the share count (at most 16)".to_string());
+    }
+
+    if threshold == 1 {
+        return Ok((0..share_count).map(|x| (x, secret.to_vec())).collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares: Vec<(u8, Vec<u8>)> = (0..random_share_count)
+        .map(|x| {
+            let mut value = vec![0u8; secret.len()];
+            rng.fill_bytes(&mut value);
+            (x, value)
+        })
+        .collect();
+
+    let mut random_part = vec![0u8; secret.len() - DIGEST_LENGTH_BYTES];
+    rng.fill_bytes(&mut random_part);
+    let mut digest_share = create_digest(&random_part, secret).to_vec();
+    digest_share.extend_from_slice(&random_part);
+
+    let mut base_shares = shares.clone();
+    base_shares.push((DIGEST_INDEX, digest_share));
+    base_shares.push((SECRET_INDEX, secret.to_vec()));
+
+    for x in random_share_count..share_count {
+        shares.push((x, interpolate(&base_shares, x)?));
+    }
+
+    Ok(shares)
+}
+
+/// Recover the secret from exactly `threshold` shares and verify its digest
+pub fn recover_secret(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    if threshold == 1 {
+        return shares
+            .first()
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| "No shares provided".to_string());
+    }
+
+    let secret = interpolate(shares, SECRET_INDEX)?;
+    let digest_share = interpolate(shares, DIGEST_INDEX)?;
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH_BYTES);
+
+    if digest != create_digest(random_part, &secret) {
+        return Err(
+            "Invalid digest of the shared secret (shares do not belong together)".to_string(),
+        );
+    }
+
+    Ok(secret)
+}
+
+/// HMAC-SHA256(random_part, secret) truncated to the digest length
+fn create_digest(random_part: &[u8], secret: &[u8]) -> [u8; DIGEST_LENGTH_BYTES] {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(random_part)
+        .expect("HMAC accepts keys of any length");
+    mac.update(secret);
+    let tag = mac.finalize().into_bytes();
+    let mut digest = [0u8; DIGEST_LENGTH_BYTES];
+    digest.copy_from_slice(&tag[..DIGEST_LENGTH_BYTES]);
+    digest
+}
+
+/// Lagrange interpolation of the shares' polynomial at `x`
+fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Result<Vec<u8>, String> {
+    let mut x_coordinates: Vec<u8> = shares.iter().map(|(share_x, _)| *share_x).collect();
+    x_coordinates.sort_unstable();
+    x_coordinates.dedup();
+    if x_coordinates.len() != shares.len() {
+        return Err("Share indices must be unique".to_string());
+    }
+
+    let length = shares.first().map(|(_, value)| value.len()).unwrap_or(0);
+    if shares.iter().any(|(_, value)| value.len() != length) {
+        return Err("All share values must have the same length".to_string());
+    }
+
+    if let Some((_, value)) = shares.iter().find(|(share_x, _)| *share_x == x) {
+        return Ok(value.clone());
+    }
+
+    let tables = FieldTables::new();
+    let log = |value: u8| tables.log[value as usize] as i32;
+
+    // log of prod(share_x ^ x) over all shares
+    let log_prod: i32 = x_coordinates.iter().map(|share_x| log(share_x ^ x)).sum();
+
+    let mut result = vec![0u8; length];
+    for (share_x, value) in shares {
+        // log of the Lagrange basis polynomial evaluated at x
+        let log_basis_eval = (log_prod
+            - log(share_x ^ x)
+            - x_coordinates
+                .iter()
+                .map(|other| log(share_x ^ other))
+                .sum::<i32>())
+        .rem_euclid(255);
+
+        for (out, byte) in result.iter_mut().zip(value) {
+            if *byte != 0 {
+                *out ^= tables.exp[((log(*byte) + log_basis_eval) % 255) as usize];
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_field_tables() {
+        let tables = FieldTables::new();
+        assert_eq!(tables.exp[0], 1);
+        assert_eq!(tables.exp[1], 3);
+        // Generator 3 has order 255: every non-zero element appears once
+        let mut seen = tables.exp.to_vec();
+        seen.sort_unstable();
+        seen.dedup();
+        assert_eq!(seen.len(), 255);
+    }
+
+    #[test]
+    fn test_split_and_recover_any_subset() {
+        let mut rng = ChaCha8Rng::from_seed([4u8; 32]);
+        let secret = b"0123456789abcdef".to_vec();
+        let shares = split_secret(&mut rng, 3, 5, &secret).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        for subset in [[0, 1, 2], [0, 2, 4], [1, 3, 4]] {
+            let chosen: Vec<(u8, Vec<u8>)> = subset.iter().map(|i| shares[*i].clone()).collect();
+            assert_eq!(recover_secret(3, &chosen).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn test_tampered_share_is_detected() {
+        let mut rng = ChaCha8Rng::from_seed([4u8; 32]);
+        let secret = b"0123456789abcdef".to_vec();
+        let mut shares = split_secret(&mut rng, 2, 3, &secret).unwrap();
+        shares[0].1[0] ^= 1;
+        assert!(recover_secret(2, &shares[..2]).is_err());
+    }
+}
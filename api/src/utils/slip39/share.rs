@@ -0,0 +1,271 @@
+//! SLIP-39 share encoding (10-bit words with RS1024 checksum)
+
+use super::wordlist::WORDLIST;
+
+/// Bits per mnemonic word
+const RADIX_BITS: usize = 10;
+/// Number of checksum words
+const CHECKSUM_WORDS: usize = 3;
+/// Words holding identifier, flags and group/member parameters
+const METADATA_WORDS: usize = 4;
+/// Shortest share (128-bit secret): metadata + 13 value words + checksum
+pub const MIN_MNEMONIC_WORDS: usize = 20;
+
+/// RS1024 generator constants
+const RS1024_GEN: [u32; 10] = [
+    0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412, 0x38086C24, 0x3090FC48,
+    0x21B1F890, 0x3F3F120,
+];
+
+/// One SLIP-39 share
+#[derive(Debug, Clone, PartialEq)]
+pub struct Share {
+    /// 15-bit random identifier common to all shares of a secret
+    pub identifier: u16,
+    /// Extendable backup flag (changes salt and checksum customization)
+    pub extendable: bool,
+    /// PBKDF2 iteration exponent (0-15)
+    pub iteration_exponent: u8,
+    /// Group index (0-15)
+    pub group_index: u8,
+    /// Number of groups required (1-16)
+    pub group_threshold: u8,
+    /// Total number of groups (1-16)
+    pub group_count: u8,
+    /// Member index within the group (0-15)
+    pub member_index: u8,
+    /// Number of members required within the group (1-16)
+    pub member_threshold: u8,
+    /// Share value (same length as the encrypted master secret)
+    pub value: Vec<u8>,
+}
+
+impl Share {
+    /// Encode the share as a space-separated mnemonic
+    pub fn to_mnemonic(&self) -> String {
+        let mut words: Vec<u16> = Vec::new();
+
+        // id (15) + ext (1) + e (4) = 2 words
+        let id_ext_e = ((self.identifier as u32) << 5)
+            | ((self.extendable as u32) << 4)
+            | self.iteration_exponent as u32;
+        words.extend(int_to_words(id_ext_e as u64, 2));
+
+        // GI, Gt-1, g-1, I, t-1 (4 bits each) = 2 words
+        let params = ((self.group_index as u64) << 16)
+            | (((self.group_threshold - 1) as u64) << 12)
+            | (((self.group_count - 1) as u64) << 8)
+            | ((self.member_index as u64) << 4)
+            | (self.member_threshold - 1) as u64;
+        words.extend(int_to_words(params, 2));
+
+        words.extend(bytes_to_words(&self.value));
+
+        let checksum = rs1024_create_checksum(customization(self.extendable), &words);
+        words.extend(checksum);
+
+        words
+            .iter()
+            .map(|w| WORDLIST[*w as usize])
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Decode and checksum-verify a share mnemonic
+    pub fn from_mnemonic(mnemonic: &str) -> Result<Self, String> {
+        let words: Vec<u16> = mnemonic
+            .split_whitespace()
+            .map(|word| {
+                let word = word.to_lowercase();
+                WORDLIST
+                    .iter()
+                    .position(|w| *w == word)
+                    .map(|index| index as u16)
+                    .ok_or_else(|| format!("Invalid SLIP-39 word: {}", word))
+            })
+            .collect::<Result<_, _>>()?;
+
+        if words.len() < MIN_MNEMONIC_WORDS {
+            return Err(format!(
+                "Invalid share length: {} words (minimum {})",
+                words.len(),
+                MIN_MNEMONIC_WORDS
+            ));
+        }
+
+        let padding_bits = (RADIX_BITS * (words.len() - METADATA_WORDS - CHECKSUM_WORDS)) % 16;
+        if padding_bits > 8 {
+            return Err("Invalid share length".to_string());
+        }
+
+        let id_ext_e = words_to_int(&words[..2]);
+        let extendable = (id_ext_e >> 4) & 1 == 1;
+        if !rs1024_verify_checksum(customization(extendable), &words) {
+            return Err("Invalid share checksum".to_string());
+        }
+
+        let params = words_to_int(&words[2..4]);
+        let group_threshold = ((params >> 12) & 0xF) as u8 + 1;
+        let group_count = ((params >> 8) & 0xF) as u8 + 1;
+        if group_threshold > group_count {
+            return Err("Invalid share: group threshold exceeds group count".to_string());
+        }
+
+        let value_words = &words[METADATA_WORDS..words.len() - CHECKSUM_WORDS];
+        let value_bits = RADIX_BITS * value_words.len() - padding_bits;
+        let value = words_to_bytes(value_words, value_bits / 8)?;
+
+        Ok(Self {
+            identifier: (id_ext_e >> 5) as u16,
+            extendable,
+            iteration_exponent: (id_ext_e & 0xF) as u8,
+            group_index: ((params >> 16) & 0xF) as u8,
+            group_threshold,
+            group_count,
+            member_index: ((params >> 4) & 0xF) as u8,
+            member_threshold: (params & 0xF) as u8 + 1,
+            value,
+        })
+    }
+}
+
+/// Checksum customization string
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+/// Split an integer into `count` big-endian 10-bit words
+fn int_to_words(value: u64, count: usize) -> Vec<u16> {
+    (0..count)
+        .rev()
+        .map(|i| ((value >> (i * RADIX_BITS)) & 0x3FF) as u16)
+        .collect()
+}
+
+/// Join big-endian 10-bit words into an integer
+fn words_to_int(words: &[u16]) -> u64 {
+    words
+        .iter()
+        .fold(0u64, |acc, w| (acc << RADIX_BITS) | *w as u64)
+}
+
+/// Encode bytes as 10-bit words, left-padding with zero bits
+fn bytes_to_words(bytes: &[u8]) -> Vec<u16> {
+    let word_count = (bytes.len() * 8).div_ceil(RADIX_BITS);
+    let padding = word_count * RADIX_BITS - bytes.len() * 8;
+
+    let mut words = Vec::with_capacity(word_count);
+    let mut accumulator: u32 = 0;
+    let mut bits = padding;
+    for byte in bytes {
+        accumulator = (accumulator << 8) | *byte as u32;
+        bits += 8;
+        while bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            words.push(((accumulator >> bits) & 0x3FF) as u16);
+        }
+    }
+    words
+}
+
+/// Decode 10-bit words into `length` bytes, requiring zero padding bits
+fn words_to_bytes(words: &[u16], length: usize) -> Result<Vec<u8>, String> {
+    let padding = words.len() * RADIX_BITS - length * 8;
+
+    let mut bytes = Vec::with_capacity(length);
+    let mut accumulator: u32 = 0;
+    let mut bits: isize = -(padding as isize);
+    for word in words {
+        accumulator = (accumulator << RADIX_BITS) | *word as u32;
+        bits += RADIX_BITS as isize;
+        if bits < 0 {
+            continue;
+        }
+        // Padding must be zero once it has been shifted through
+        if bytes.is_empty() && accumulator >> bits != 0 {
+            return Err("Invalid share padding".to_string());
+        }
+        while bits >= 8 {
+            bits -= 8;
+            bytes.push(((accumulator >> bits) & 0xFF) as u8);
+        }
+        accumulator &= (1 << bits) - 1;
+    }
+    Ok(bytes)
+}
+
+/// RS1024 polynomial modulus over 10-bit values
+fn rs1024_polymod(values: impl Iterator<Item = u32>) -> u32 {
+    let mut chk: u32 = 1;
+    for value in values {
+        let b = chk >> 20;
+        chk = ((chk & 0xFFFFF) << 10) ^ value;
+        for (i, generator) in RS1024_GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+/// Three checksum words for `data` under the customization string
+fn rs1024_create_checksum(customization: &[u8], data: &[u16]) -> Vec<u16> {
+    let values = customization
+        .iter()
+        .map(|b| *b as u32)
+        .chain(data.iter().map(|w| *w as u32))
+        .chain(std::iter::repeat_n(0, CHECKSUM_WORDS));
+    let polymod = rs1024_polymod(values) ^ 1;
+    (0..CHECKSUM_WORDS)
+        .rev()
+        .map(|i| ((polymod >> (RADIX_BITS * i)) & 0x3FF) as u16)
+        .collect()
+}
+
+/// Verify the checksum of a complete share (data + checksum words)
+fn rs1024_verify_checksum(customization: &[u8], words: &[u16]) -> bool {
+    let values = customization
+        .iter()
+        .map(|b| *b as u32)
+        .chain(words.iter().map(|w| *w as u32));
+    rs1024_polymod(values) == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // SLIP-39 test vector 1 (single share, 128-bit secret)
+    const VECTOR_1: &str = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+
+    #[test]
+    fn test_decode_and_reencode_vector() {
+        let share = Share::from_mnemonic(VECTOR_1).unwrap();
+        assert_eq!(share.value.len(), 16);
+        assert_eq!(share.group_threshold, 1);
+        assert_eq!(share.member_threshold, 1);
+        assert!(!share.extendable);
+        assert_eq!(share.to_mnemonic(), VECTOR_1);
+    }
+
+    #[test]
+    fn test_checksum_detects_changed_word() {
+        let tampered = VECTOR_1.replace("keyboard", "kidney");
+        assert!(Share::from_mnemonic(&tampered).is_err());
+        assert!(Share::from_mnemonic("duckling enlarge").is_err());
+    }
+
+    #[test]
+    fn test_word_packing_roundtrip() {
+        for length in [16usize, 20, 32] {
+            let bytes: Vec<u8> = (0..length as u8).map(|b| b.wrapping_mul(37)).collect();
+            let words = bytes_to_words(&bytes);
+            assert_eq!(words_to_bytes(&words, length).unwrap(), bytes);
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! Typed symmetric key material for /api/symmetric-key
+//!
+//! Each purpose fixes the key length (see `SymmetricKeyPurpose::key_len`) and
+//! its conventional text form:
+//! - HMAC and AEAD keys: base64; salts and nonces: hex (overridable)
+//! - Fernet: URL-safe base64 with padding, as `Fernet.generate_key()` emits
+//! - JWK: `{"kty":"oct","k":...}` with an RFC 7638 thumbprint as `kid`
+//! - age: Bech32 `AGE-SECRET-KEY-1...` identity plus its `age1...` recipient
+
+use crate::types::{ByteEncoding, SymmetricKeyPurpose};
+use crate::utils::digest::encode_bytes;
+use base64::Engine as _;
+use base64::engine::general_purpose::{URL_SAFE, URL_SAFE_NO_PAD};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use x25519_dalek::{PublicKey as X25519PublicKey, StaticSecret as X25519PrivateKey};
+
+/// Bech32 data alphabet (BIP-173)
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Encoded key material with its encoding name
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricKey {
+    pub key: String,
+    pub encoding: &'static str,
+    /// Public recipient for purposes that have one (age)
+    pub recipient: Option<String>,
+}
+
+/// Encode raw key bytes in the conventional form for `purpose`
+///
+/// # Arguments
+/// * `bytes` - Exactly `purpose.key_len()` random bytes
+/// * `encoding` - Optional override, only for HMAC/AEAD keys, salts and nonces
+pub fn encode_symmetric_key(
+    purpose: SymmetricKeyPurpose,
+    bytes: &[u8],
+    encoding: Option<ByteEncoding>,
+) -> Result<SymmetricKey, String> {
+    let plain = |key: String, encoding: &'static str| SymmetricKey {
+        key,
+        encoding,
+        recipient: None,
+    };
+
+    match purpose {
+        SymmetricKeyPurpose::HmacSha256
+        | SymmetricKeyPurpose::HmacSha512
+        | SymmetricKeyPurpose::AeadKey
+        | SymmetricKeyPurpose::Salt
+        | SymmetricKeyPurpose::Nonce => {
+            let default = match purpose {
+                SymmetricKeyPurpose::Salt | SymmetricKeyPurpose::Nonce => ByteEncoding::Hex,
+                _ => ByteEncoding::Base64,
+            };
+            let encoding = encoding.unwrap_or(default);
+            Ok(plain(
+                encode_bytes(bytes, encoding),
+                encoding_name(encoding),
+            ))
+        }
+        _ if encoding.is_some() => Err(format!(
+            "Parameter 'encoding' is not supported for {} (its format is fixed)",
+            purpose.label()
+        )),
+        SymmetricKeyPurpose::Fernet => Ok(plain(URL_SAFE.encode(bytes), "base64url-padded")),
+        SymmetricKeyPurpose::JwkOct => {
+            let k = URL_SAFE_NO_PAD.encode(bytes);
+            let thumbprint_input = format!(r#"{{"k":"{}","kty":"oct"}}"#, k);
+            let kid = URL_SAFE_NO_PAD.encode(Sha256::digest(thumbprint_input.as_bytes()));
+            let jwk = json!({ "kty": "oct", "k": k, "kid": kid });
+            Ok(plain(jwk.to_string(), "jwk"))
+        }
+        SymmetricKeyPurpose::Age => {
+            let secret: [u8; 32] = bytes
+                .try_into()
+                .map_err(|_| "age identities are 32 bytes".to_string())?;
+            let public = X25519PublicKey::from(&X25519PrivateKey::from(secret));
+            Ok(SymmetricKey {
+                key: bech32_encode("age-secret-key-", &secret).to_uppercase(),
+                encoding: "bech32",
+                recipient: Some(bech32_encode("age", public.as_bytes())),
+            })
+        }
+    }
+}
+
+/// Lowercase name of a byte encoding, as reported in responses
+fn encoding_name(encoding: ByteEncoding) -> &'static str {
+    match encoding {
+        ByteEncoding::Hex => "hex",
+        ByteEncoding::Base58 => "base58",
+        ByteEncoding::Base64 => "base64",
+        ByteEncoding::Base64Url => "base64url",
+    }
+}
+
+/// Bech32 (BIP-173) encoding of 8-bit data
+fn bech32_encode(hrp: &str, data: &[u8]) -> String {
+    // Regroup 8-bit bytes into 5-bit values, zero-padding the last group
+    let mut values = Vec::with_capacity(data.len() * 8 / 5 + 1);
+    let mut accumulator: u32 = 0;
+    let mut bits = 0;
+    for byte in data {
+        accumulator = (accumulator << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            values.push(((accumulator >> bits) & 0x1F) as u8);
+        }
+    }
+    if bits > 0 {
+        values.push(((accumulator << (5 - bits)) & 0x1F) as u8);
+    }
+    bech32_encode_values(hrp, &values)
+}
+
+/// Bech32 encoding of 5-bit values with the 6-character checksum
+fn bech32_encode_values(hrp: &str, values: &[u8]) -> String {
+    let mut checksum_input: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+    checksum_input.push(0);
+    checksum_input.extend(hrp.bytes().map(|b| b & 0x1F));
+    checksum_input.extend_from_slice(values);
+    checksum_input.extend_from_slice(&[0; 6]);
+    let polymod = bech32_polymod(&checksum_input) ^ 1;
+
+    let mut encoded = format!("{}1", hrp);
+    for value in values
+        .iter()
+        .copied()
+        .chain((0..6).map(|i| ((polymod >> (5 * (5 - i))) & 0x1F) as u8))
+    {
+        encoded.push(BECH32_CHARSET[value as usize] as char);
+    }
+    encoded
+}
+
+/// BCH checksum over GF(32) (BIP-173 reference `bech32_polymod`)
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut checksum: u32 = 1;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x01FF_FFFF) << 5) ^ *value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bech32_bip173_vectors() {
+        assert_eq!(bech32_encode_values("a", &[]), "a12uel5l");
+        let values: Vec<u8> = (0..32).collect();
+        assert_eq!(
+            bech32_encode_values("abcdef", &values),
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"
+        );
+    }
+
+    #[test]
+    fn test_default_encodings_and_lengths() {
+        let bytes = [0xABu8; 64];
+        let hmac = encode_symmetric_key(SymmetricKeyPurpose::HmacSha512, &bytes, None).unwrap();
+        assert_eq!(hmac.encoding, "base64");
+        assert_eq!(hmac.key.len(), 88);
+
+        let salt = encode_symmetric_key(SymmetricKeyPurpose::Salt, &bytes[..16], None).unwrap();
+        assert_eq!(salt.key, "ab".repeat(16));
+
+        let nonce = encode_symmetric_key(
+            SymmetricKeyPurpose::Nonce,
+            &bytes[..12],
+            Some(ByteEncoding::Base64Url),
+        )
+        .unwrap();
+        assert_eq!(nonce.encoding, "base64url");
+        assert_eq!(nonce.key.len(), 16);
+    }
+
+    #[test]
+    fn test_fernet_and_jwk() {
+        let fernet = encode_symmetric_key(SymmetricKeyPurpose::Fernet, &[0u8; 32], None).unwrap();
+        assert_eq!(fernet.key, format!("{}=", "A".repeat(43)));
+
+        let jwk = encode_symmetric_key(SymmetricKeyPurpose::JwkOct, &[1u8; 32], None).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&jwk.key).unwrap();
+        assert_eq!(parsed["kty"], "oct");
+        assert_eq!(
+            URL_SAFE_NO_PAD
+                .decode(parsed["k"].as_str().unwrap())
+                .unwrap(),
+            vec![1u8; 32]
+        );
+
+        assert!(
+            encode_symmetric_key(
+                SymmetricKeyPurpose::Fernet,
+                &[0u8; 32],
+                Some(ByteEncoding::Hex)
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_age_identity() {
+        let age = encode_symmetric_key(SymmetricKeyPurpose::Age, &[0x42u8; 32], None).unwrap();
+        assert_eq!(
+            age.key,
+            "AGE-SECRET-KEY-1GFPYYSJZGFPYYSJZGFPYYSJZGFPYYSJZGFPYYSJZGFPYYSJZGFPQ4EGAEX"
+        );
+        assert_eq!(
+            age.recipient.as_deref(),
+            Some("age1zvkyg2lqzraa2lnjvqej32nkuu0ues2s82hzrye869xeexvn73equnujwj")
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Template (mask) based generation for /api/custom
+//!
+//! A template describes the exact shape of the value, e.g. `XXXX-XXXX-XXXX`
+//! or `AA-9999`:
+//! - `X` any character of the selected alphabet
+//! - `A` / `a` an uppercase / lowercase letter of the selected alphabet
+//! - `9` a digit
+//! - `{luhn}` Luhn check digit over the digits before it
+//! - `{mod97}` two ISO 7064 MOD 97-10 check digits over the ASCII letters and
+//!   digits before it (letters count as 10-35, as in IBANs)
+//! - `\` escapes the next character; everything else is a literal
+//!
+//! Placeholders are drawn with the seeded ChaCha8 generator, one unbiased
+//! index each, so entropy is the sum of `log2(set size)` over placeholders.
+
+use crate::utils::random_generator::random_index;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Maximum template length in characters
+pub const MAX_TEMPLATE_CHARS: usize = 128;
+
+const DIGITS: &str = "0123456789";
+
+/// One parsed template element
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateToken {
+    Literal(char),
+    /// Random character drawn from the given set
+    Random(Vec<char>),
+    Luhn,
+    Mod97,
+}
+
+/// Parsed template ready for generation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    tokens: Vec<TemplateToken>,
+}
+
+impl Template {
+    /// Parse and validate a template against the selected alphabet
+    ///
+    /// # Arguments
+    /// * `template` - Template text (see module documentation)
+    /// * `alphabet` - Characters for `X`; `A`/`a` use its upper/lowercase letters
+    ///
+    /// # Returns
+    /// * `Result<Self, String>` - Parsed template or why it is invalid
+    pub fn parse(template: &str, alphabet: &[char]) -> Result<Self, String> {
+        if template.chars().count() > MAX_TEMPLATE_CHARS {
+            return Err(format!(
+                "Template must be at most {} characters",
+                MAX_TEMPLATE_CHARS
+            ));
+        }
+
+        let class = |placeholder: char, filter: fn(&char) -> bool| {
+            let set: Vec<char> = alphabet.iter().copied().filter(filter).collect();
+            if set.is_empty() {
+                Err(format!(
+                    "Template placeholder '{}' matches no characters of the selected alphabet",
+                    placeholder
+                ))
+            } else {
+                Ok(TemplateToken::Random(set))
+            }
+        };
+
+        let mut tokens = Vec::new();
+        let mut chars = template.chars();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                'X' => TemplateToken::Random(alphabet.to_vec()),
+                'A' => class('A', |c| c.is_uppercase())?,
+                'a' => class('a', |c| c.is_lowercase())?,
+                '9' => TemplateToken::Random(DIGITS.chars().collect()),
+                '\\' => match chars.next() {
+                    Some(escaped) => TemplateToken::Literal(escaped),
+                    None => return Err("Template ends with a dangling '\\'".to_string()),
+                },
+                '{' => {
+                    let rest = chars.as_str();
+                    let name = rest.find('}').map(|end| &rest[..end]).unwrap_or(rest);
+                    chars = rest[(name.len() + 1).min(rest.len())..].chars();
+                    match name {
+                        "luhn" if rest.len() > name.len() => TemplateToken::Luhn,
+                        "mod97" if rest.len() > name.len() => TemplateToken::Mod97,
+                        _ => {
+                            return Err(format!(
+                                "Unknown template check digit '{{{}}}'. Use {{luhn}} or {{mod97}} (escape literal braces with '\\')",
+                                name
+                            ));
+                        }
+                    }
+                }
+                c if c.is_control() => {
+                    return Err("Template must not contain control characters".to_string());
+                }
+                c => TemplateToken::Literal(c),
+            };
+
+            if token == TemplateToken::Luhn {
+                validate_luhn_payload(&tokens)?;
+            }
+            tokens.push(token);
+        }
+
+        if !tokens
+            .iter()
+            .any(|token| matches!(token, TemplateToken::Random(_)))
+        {
+            return Err(
+                "Template must contain at least one placeholder (X, A, a or 9)".to_string(),
+            );
+        }
+
+        Ok(Self { tokens })
+    }
+
+    /// Entropy in bits: placeholders only (literals and check digits add nothing)
+    pub fn entropy_bits(&self) -> f64 {
+        self.tokens
+            .iter()
+            .map(|token| match token {
+                TemplateToken::Random(set) => (set.len() as f64).log2(),
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Fill the template deterministically from `seed`
+    pub fn generate(&self, seed: [u8; 32]) -> String {
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let mut value = String::new();
+        for token in &self.tokens {
+            match token {
+                TemplateToken::Literal(c) => value.push(*c),
+                TemplateToken::Random(set) => value.push(set[random_index(&mut rng, set.len())]),
+                TemplateToken::Luhn => value.push(luhn_check_digit(&value)),
+                TemplateToken::Mod97 => value.push_str(&mod97_check_digits(&value)),
+            }
+        }
+        value
+    }
+}
+
+/// Luhn check digits only make sense over digits: letters before one are rejected
+fn validate_luhn_payload(tokens: &[TemplateToken]) -> Result<(), String> {
+    let mut has_digit = false;
+    for token in tokens {
+        match token {
+            TemplateToken::Literal(c) if c.is_ascii_digit() => has_digit = true,
+            TemplateToken::Literal(c) if !c.is_alphanumeric() => {}
+            TemplateToken::Random(set) if set.iter().all(char::is_ascii_digit) => has_digit = true,
+            TemplateToken::Luhn | TemplateToken::Mod97 => has_digit = true,
+            _ => {
+                return Err(
+                    "{luhn} requires only digits (9 or literal digits) and separators before it"
+                        .to_string(),
+                );
+            }
+        }
+    }
+    if has_digit {
+        Ok(())
+    } else {
+        Err("{luhn} requires at least one digit before it".to_string())
+    }
+}
+
+/// Luhn check digit for the ASCII digits of `payload` (other characters are skipped)
+pub fn luhn_check_digit(payload: &str) -> char {
+    let sum: u32 = payload
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, digit)| {
+            // The rightmost payload digit sits next to the check digit, so it is doubled
+            if i % 2 == 0 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    char::from_digit((10 - sum % 10) % 10, 10).expect("value below 10")
+}
+
+/// ISO 7064 MOD 97-10 check digits for the ASCII letters and digits of `payload`
+pub fn mod97_check_digits(payload: &str) -> String {
+    let remainder = payload
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .fold(0u32, |acc, c| {
+            let value = c.to_digit(36).expect("ASCII alphanumeric");
+            if value < 10 {
+                (acc * 10 + value) % 97
+            } else {
+                (acc * 100 + value) % 97
+            }
+        });
+    format!("{:02}", 98 - (remainder * 100) % 97)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AlphabetType;
+
+    fn base58() -> Vec<char> {
+        AlphabetType::Base58.as_chars()
+    }
+
+    #[test]
+    fn test_luhn_check_digit() {
+        assert_eq!(luhn_check_digit("7992739871"), '3');
+        assert_eq!(luhn_check_digit("4111 1111 1111 111"), '1');
+        assert_eq!(luhn_check_digit("0"), '0');
+    }
+
+    #[test]
+    fn test_mod97_check_digits() {
+        // IBAN GB82 WEST 1234 5698 7654 32, rearranged with the country code last
+        assert_eq!(mod97_check_digits("WEST12345698765432GB"), "82");
+        // ISO 7064 example
+        assert_eq!(mod97_check_digits("794"), "44");
+    }
+
+    #[test]
+    fn test_template_shape_and_entropy() {
+        let alphabet = AlphabetType::NoLookAlike.as_chars();
+        let template = Template::parse("AA-9999", &alphabet).unwrap();
+        let value = template.generate([4u8; 32]);
+        assert_eq!(value.len(), 7);
+        assert!(value[..2].chars().all(|c| c.is_ascii_uppercase()));
+        assert_eq!(&value[2..3], "-");
+        assert!(value[3..].chars().all(|c| c.is_ascii_digit()));
+
+        let uppercase = alphabet.iter().filter(|c| c.is_uppercase()).count() as f64;
+        let expected = 2.0 * uppercase.log2() + 4.0 * 10f64.log2();
+        assert!((template.entropy_bits() - expected).abs() < 1e-9);
+
+        // Deterministic for a seed
+        assert_eq!(value, template.generate([4u8; 32]));
+        assert_ne!(value, template.generate([5u8; 32]));
+    }
+
+    #[test]
+    fn test_template_check_digits() {
+        let card = Template::parse("4999 9999 9999 999{luhn}", &base58()).unwrap();
+        let value = card.generate([1u8; 32]);
+        assert_eq!(value.len(), 19);
+        let (payload, check) = value.split_at(18);
+        assert_eq!(luhn_check_digit(payload).to_string(), check);
+        assert!(value.starts_with('4'));
+        assert!((card.entropy_bits() - 14.0 * 10f64.log2()).abs() < 1e-9);
+
+        let reference = Template::parse("XXXXXX{mod97}", &base58()).unwrap();
+        let value = reference.generate([2u8; 32]);
+        let (payload, check) = value.split_at(6);
+        assert_eq!(mod97_check_digits(payload), check);
+    }
+
+    #[test]
+    fn test_template_literals_and_errors() {
+        let template = Template::parse(r"ID\-\X\{9", &base58()).unwrap();
+        let value = template.generate([0u8; 32]);
+        assert!(value.starts_with("ID-X{"));
+
+        assert!(Template::parse("XX{crc}", &base58()).is_err());
+        assert!(Template::parse("99{luhn", &base58()).is_err());
+        assert!(Template::parse("XX\\", &base58()).is_err());
+        assert!(Template::parse("XX-{luhn}", &base58()).is_err());
+        assert!(Template::parse("{luhn}9", &base58()).is_err());
+        assert!(Template::parse("ID-", &base58()).is_err());
+        assert!(Template::parse("aa", &AlphabetType::Numeric.as_chars()).is_err());
+        assert!(Template::parse(&"X".repeat(129), &base58()).is_err());
+    }
+}
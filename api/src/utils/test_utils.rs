@@ -0,0 +1,11 @@
+//! Shared fixtures for unit tests
+
+use std::collections::HashMap;
+
+/// Build request parameters from key/value pairs
+pub fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
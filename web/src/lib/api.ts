@@ -20,6 +20,12 @@ import type {
 	MagicLinkResponse,
 	CreateSharedSecretRequest,
 	CreateSharedSecretResponse,
+	CreateSecretRequestRequest,
+	CreateSecretRequestResponse,
+	FulfillSecretRequestRequest,
+	FulfillSecretRequestResponse,
+	SecretRequestDetails,
+	SharedSecretAttachmentChunk,
 	ViewSharedSecretRequest,
 	ViewSharedSecretResult
 } from './types';
@@ -41,8 +47,12 @@ import {
 	refreshToken as refreshTokenAuth,
 	createSharedSecret as createSharedSecretAPI,
 	viewSharedSecret as viewSharedSecretAPI,
+	downloadAttachmentChunk as downloadAttachmentChunkAPI,
 	deleteSharedSecret as deleteSharedSecretAPI,
-	confirmRead as confirmReadAPI
+	confirmRead as confirmReadAPI,
+	createSecretRequest as createSecretRequestAPI,
+	getSecretRequest as getSecretRequestAPI,
+	fulfillSecretRequest as fulfillSecretRequestAPI
 } from './api/index';
 
 const API_BASE = '/api';
@@ -139,6 +149,14 @@ export const api = {
 		return await viewSharedSecretAPI(hash, otpRequest);
 	},
 
+	async downloadAttachmentChunk(
+		hash: string,
+		chunk: number,
+		otp?: string
+	): Promise<SharedSecretAttachmentChunk> {
+		return await downloadAttachmentChunkAPI(hash, chunk, otp);
+	},
+
 	async deleteSharedSecret(hash: string): Promise<void> {
 		return await deleteSharedSecretAPI(hash);
 	},
@@ -151,6 +169,23 @@ export const api = {
 		message: string;
 	}> {
 		return await confirmReadAPI(hash);
+	},
+
+	// Secret Request endpoints
+	async createSecretRequest(
+		request: CreateSecretRequestRequest
+	): Promise<CreateSecretRequestResponse> {
+		return await createSecretRequestAPI(request);
+	},
+
+	async getSecretRequest(hash: string): Promise<SecretRequestDetails> {
+		return await getSecretRequestAPI(hash);
+	},
+
+	async fulfillSecretRequest(
+		request: FulfillSecretRequestRequest
+	): Promise<FulfillSecretRequestResponse> {
+		return await fulfillSecretRequestAPI(request);
 	}
 };
 
@@ -6,8 +6,14 @@
  */
 
 import type {
+	CreateSecretRequestRequest,
+	CreateSecretRequestResponse,
 	CreateSharedSecretRequest,
 	CreateSharedSecretResponse,
+	FulfillSecretRequestRequest,
+	FulfillSecretRequestResponse,
+	SecretRequestDetails,
+	SharedSecretAttachmentChunk,
 	ViewSharedSecretRequest,
 	ViewSharedSecretResult
 } from '../types';
@@ -55,6 +61,75 @@ export async function viewSharedSecret(
 	}
 }
 
+/**
+ * Download one encrypted attachment chunk (GET)
+ * Requires authentication and Ed25519 signature
+ * Receivers pass their OTP if the secret requires one; the sender never needs it
+ *
+ * @param hash - Base58 encoded shared secret hash
+ * @param chunk - Zero-based chunk index
+ * @param otp - Optional 9-digit OTP
+ * @returns Promise with the encrypted chunk and remaining downloads
+ */
+export async function downloadAttachmentChunk(
+	hash: string,
+	chunk: number,
+	otp?: string
+): Promise<SharedSecretAttachmentChunk> {
+	const params: Record<string, string> = { hash, chunk: String(chunk) };
+	if (otp) {
+		params.otp = otp;
+	}
+	const { httpAuthenticatedSignedGETRequest } = await import('../httpSignedRequests');
+	return await httpAuthenticatedSignedGETRequest<SharedSecretAttachmentChunk>(
+		`${API_BASE}/shared-secret/attachment`,
+		params
+	);
+}
+
+/**
+ * Ask an invitee for a secret (POST)
+ * Requires authentication and Ed25519 signature
+ * The invitee receives an upload link (?request={hash}) by email
+ */
+export async function createSecretRequest(
+	request: CreateSecretRequestRequest
+): Promise<CreateSecretRequestResponse> {
+	const { httpAuthenticatedSignedPOSTRequest } = await import('../httpSignedRequests');
+	return await httpAuthenticatedSignedPOSTRequest<
+		CreateSecretRequestRequest,
+		CreateSecretRequestResponse
+	>(`${API_BASE}/shared-secret/request`, request);
+}
+
+/**
+ * Get the details of a secret request for its upload form (GET, invitee only)
+ * Requires authentication and Ed25519 signature
+ *
+ * @param hash - Base58 encoded request hash from the invitee's link
+ */
+export async function getSecretRequest(hash: string): Promise<SecretRequestDetails> {
+	const { httpAuthenticatedSignedGETRequest } = await import('../httpSignedRequests');
+	return await httpAuthenticatedSignedGETRequest<SecretRequestDetails>(
+		`${API_BASE}/shared-secret/request`,
+		{ hash }
+	);
+}
+
+/**
+ * Fulfil a secret request with the E2E encrypted secret (POST, invitee only)
+ * Requires authentication and Ed25519 signature
+ */
+export async function fulfillSecretRequest(
+	request: FulfillSecretRequestRequest
+): Promise<FulfillSecretRequestResponse> {
+	const { httpAuthenticatedSignedPOSTRequest } = await import('../httpSignedRequests');
+	return await httpAuthenticatedSignedPOSTRequest<
+		FulfillSecretRequestRequest,
+		FulfillSecretRequestResponse
+	>(`${API_BASE}/shared-secret/request/fulfill`, request);
+}
+
 /**
  * Delete a shared secret (DELETE)
  * Requires authentication and Ed25519 signature
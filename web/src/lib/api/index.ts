@@ -32,6 +32,10 @@ export {
 export {
 	createSharedSecret,
 	viewSharedSecret,
+	downloadAttachmentChunk,
 	deleteSharedSecret,
-	confirmRead
+	confirmRead,
+	createSecretRequest,
+	getSecretRequest,
+	fulfillSecretRequest
 } from './api-shared-secret';
@@ -39,4 +39,10 @@ export {
 } from './crypto-utils';
 
 // Shared secret E2E encryption (ChaCha20-Poly1305 + ECDH)
-export { encryptSecretForCreation, decryptSecretAfterRetrieval } from './shared-secret-crypto';
+export {
+	encryptSecretForCreation,
+	decryptSecretAfterRetrieval,
+	decryptAttachmentAfterDownload,
+	ATTACHMENT_CHUNK_SIZE,
+	MAX_ATTACHMENT_SIZE
+} from './shared-secret-crypto';
@@ -12,6 +12,10 @@
  * 5. Backend encrypts key_material with ECDH (backend X25519 private + requester X25519 public) for response
  * 6. Frontend decrypts key_material with ECDH, then decrypts secret_text with ChaCha20-Poly1305
  *
+ * Attachments (optional file) are encrypted with the same cipher_key in
+ * ATTACHMENT_CHUNK_SIZE chunks; chunk i uses the key_material nonce with its last
+ * 4 bytes XORed with big-endian i + 1 (never the secret text's nonce, no reordering)
+ *
  * Security:
  * - WebCrypto native X25519 ECDH (non-extractable private keys in IndexedDB)
  * - Blake3 KDF for cipher key + nonce derivation from ECDH shared secret
@@ -33,6 +37,8 @@ const NONCE_LENGTH = 12; // ChaCha20-Poly1305 nonce size
 const CIPHER_KEY_LENGTH = 32; // ChaCha20 key size
 const KEY_MATERIAL_LENGTH = NONCE_LENGTH + CIPHER_KEY_LENGTH; // 44 bytes total
 const ECDH_CONTEXT = 'SharedSecretKeyMaterial_v1'; // Domain separation for ECDH
+export const ATTACHMENT_CHUNK_SIZE = 64 * 1024; // Plaintext bytes per attachment chunk
+export const MAX_ATTACHMENT_SIZE = 5 * 1024 * 1024; // 5 MiB
 
 /**
  * Perform ECDH key agreement using WebCrypto and derive cipher key + nonce
@@ -202,6 +208,110 @@ function decryptSecretText(encryptedSecret: Uint8Array, keyMaterial: Uint8Array)
 	return secretText;
 }
 
+/**
+ * Nonce of attachment chunk `index`: key_material nonce with its last 4 bytes XOR (index + 1)
+ *
+ * @param keyMaterial - 44 bytes: nonce[12] + cipher_key[32]
+ * @param index - Zero-based chunk index
+ * @returns 12-byte chunk nonce
+ */
+function attachmentChunkNonce(keyMaterial: Uint8Array, index: number): Uint8Array {
+	const nonce = keyMaterial.slice(0, NONCE_LENGTH);
+	const counter = new Uint8Array(4);
+	new DataView(counter.buffer).setUint32(0, index + 1);
+	for (let i = 0; i < 4; i++) {
+		nonce[NONCE_LENGTH - 4 + i] ^= counter[i];
+	}
+	return nonce;
+}
+
+/**
+ * Encrypt an attachment with ChaCha20-Poly1305 in ATTACHMENT_CHUNK_SIZE chunks
+ *
+ * @param data - File contents (1 byte to MAX_ATTACHMENT_SIZE)
+ * @param keyMaterial - 44 bytes: nonce[12] + cipher_key[32]
+ * @returns Encrypted chunks in order (each chunk + 16-byte auth tag)
+ */
+function encryptAttachmentChunks(data: Uint8Array, keyMaterial: Uint8Array): Uint8Array[] {
+	logger.debug(`🔐 Encrypting attachment (size=${data.length})`);
+
+	if (data.length === 0 || data.length > MAX_ATTACHMENT_SIZE) {
+		throw new Error(`Invalid attachment size: ${data.length}, expected 1-${MAX_ATTACHMENT_SIZE}`);
+	}
+
+	const cipherKey = keyMaterial.slice(NONCE_LENGTH);
+	const chunks: Uint8Array[] = [];
+	for (let offset = 0; offset < data.length; offset += ATTACHMENT_CHUNK_SIZE) {
+		const index = chunks.length;
+		const cipher = chacha20poly1305(cipherKey, attachmentChunkNonce(keyMaterial, index));
+		chunks.push(cipher.encrypt(data.subarray(offset, offset + ATTACHMENT_CHUNK_SIZE)));
+	}
+
+	logger.debug(`✅ Attachment encrypted (chunks=${chunks.length})`);
+	return chunks;
+}
+
+/**
+ * Decrypt attachment chunks with ChaCha20-Poly1305 and join them
+ *
+ * @param encryptedChunks - Encrypted chunks in order (each chunk + 16-byte auth tag)
+ * @param keyMaterial - 44 bytes: nonce[12] + cipher_key[32]
+ * @returns File contents
+ * @throws Error if any authentication tag verification fails
+ */
+function decryptAttachmentChunks(
+	encryptedChunks: Uint8Array[],
+	keyMaterial: Uint8Array
+): Uint8Array {
+	logger.debug(`🔓 Decrypting attachment (chunks=${encryptedChunks.length})`);
+
+	const cipherKey = keyMaterial.slice(NONCE_LENGTH);
+	const chunks = encryptedChunks.map((chunk, index) =>
+		chacha20poly1305(cipherKey, attachmentChunkNonce(keyMaterial, index)).decrypt(chunk)
+	);
+
+	const data = new Uint8Array(chunks.reduce((total, chunk) => total + chunk.length, 0));
+	let offset = 0;
+	for (const chunk of chunks) {
+		data.set(chunk, offset);
+		offset += chunk.length;
+	}
+
+	logger.debug(`✅ Attachment decrypted (size=${data.length})`);
+	return data;
+}
+
+/**
+ * Decrypt key_material received from backend with ECDH
+ *
+ * @param encryptedKeyMaterial - Encrypted key_material from backend (Uint8Array)
+ * @param backendPublicKeyHex - Backend's X25519 public key (hex, 64 chars)
+ * @returns Promise with key_material (44 bytes: nonce[12] + cipher_key[32])
+ */
+async function decryptKeyMaterial(
+	encryptedKeyMaterial: Uint8Array,
+	backendPublicKeyHex: string
+): Promise<Uint8Array> {
+	// 1. Get requester's X25519 private key from IndexedDB
+	const { getX25519PrivateKey } = await import('./keypair-storage');
+	const requesterX25519PrivateKey = await getX25519PrivateKey();
+
+	if (!requesterX25519PrivateKey) {
+		throw new Error('X25519 private key not found in IndexedDB');
+	}
+
+	// 2. Import backend's X25519 public key from hex
+	const { importX25519PublicKey } = await import('./keypair-generation');
+	const backendX25519PublicKey = await importX25519PublicKey(backendPublicKeyHex);
+
+	// 3. Decrypt key_material with ECDH (WebCrypto)
+	return await decryptWithECDH(
+		encryptedKeyMaterial,
+		requesterX25519PrivateKey,
+		backendX25519PublicKey
+	);
+}
+
 /**
  * Encrypt secret for creation (sender encrypts before sending to backend)
  *
@@ -211,15 +321,22 @@ function decryptSecretText(encryptedSecret: Uint8Array, keyMaterial: Uint8Array)
  * 3. Get sender's X25519 private key from IndexedDB
  * 4. Import backend's X25519 public key from hex
  * 5. Encrypt key_material with ECDH (sender X25519 private + backend X25519 public)
+ * 6. Encrypt the optional attachment in chunks with the same key_material
  *
  * @param secretText - Secret text to encrypt
  * @param backendPublicKeyHex - Backend's X25519 public key (hex, 64 chars)
- * @returns Promise with encrypted_secret and encrypted_key_material (both Uint8Array)
+ * @param attachment - Optional file contents (1 byte to MAX_ATTACHMENT_SIZE)
+ * @returns Promise with encrypted_secret, encrypted_key_material and encrypted attachment chunks
  */
 export async function encryptSecretForCreation(
 	secretText: string,
-	backendPublicKeyHex: string
-): Promise<{ encryptedSecret: Uint8Array; encryptedKeyMaterial: Uint8Array }> {
+	backendPublicKeyHex: string,
+	attachment?: Uint8Array
+): Promise<{
+	encryptedSecret: Uint8Array;
+	encryptedKeyMaterial: Uint8Array;
+	encryptedAttachmentChunks?: Uint8Array[];
+}> {
 	logger.debug('🔐 Starting secret encryption for creation', {
 		backendPublicKeyHex_received: backendPublicKeyHex,
 		length: backendPublicKeyHex?.length,
@@ -251,8 +368,13 @@ export async function encryptSecretForCreation(
 		backendX25519PublicKey
 	);
 
+	// 6. Encrypt attachment chunks with the same key_material
+	const encryptedAttachmentChunks = attachment
+		? encryptAttachmentChunks(attachment, keyMaterial)
+		: undefined;
+
 	logger.debug('✅ Secret encryption for creation complete');
-	return { encryptedSecret, encryptedKeyMaterial };
+	return { encryptedSecret, encryptedKeyMaterial, encryptedAttachmentChunks };
 }
 
 /**
@@ -276,24 +398,8 @@ export async function decryptSecretAfterRetrieval(
 ): Promise<string> {
 	logger.debug('🔓 Starting secret decryption after retrieval');
 
-	// 1. Get requester's X25519 private key from IndexedDB
-	const { getX25519PrivateKey } = await import('./keypair-storage');
-	const requesterX25519PrivateKey = await getX25519PrivateKey();
-
-	if (!requesterX25519PrivateKey) {
-		throw new Error('X25519 private key not found in IndexedDB');
-	}
-
-	// 2. Import backend's X25519 public key from hex
-	const { importX25519PublicKey } = await import('./keypair-generation');
-	const backendX25519PublicKey = await importX25519PublicKey(backendPublicKeyHex);
-
-	// 3. Decrypt key_material with ECDH (WebCrypto)
-	const keyMaterial = await decryptWithECDH(
-		encryptedKeyMaterial,
-		requesterX25519PrivateKey,
-		backendX25519PublicKey
-	);
+	// 1-3. Decrypt key_material with ECDH (requester X25519 private + backend X25519 public)
+	const keyMaterial = await decryptKeyMaterial(encryptedKeyMaterial, backendPublicKeyHex);
 
 	// 4. Decrypt secret_text with ChaCha20-Poly1305
 	const secretText = decryptSecretText(encryptedSecret, keyMaterial);
@@ -302,6 +408,28 @@ export async function decryptSecretAfterRetrieval(
 	return secretText;
 }
 
+/**
+ * Decrypt attachment after download (same key_material as the secret text)
+ *
+ * @param encryptedChunks - Encrypted attachment chunks from backend, in order
+ * @param encryptedKeyMaterial - Encrypted key_material from backend (Uint8Array)
+ * @param backendPublicKeyHex - Backend's X25519 public key (hex, 64 chars)
+ * @returns Promise with the file contents
+ */
+export async function decryptAttachmentAfterDownload(
+	encryptedChunks: Uint8Array[],
+	encryptedKeyMaterial: Uint8Array,
+	backendPublicKeyHex: string
+): Promise<Uint8Array> {
+	logger.debug('🔓 Starting attachment decryption after download');
+
+	const keyMaterial = await decryptKeyMaterial(encryptedKeyMaterial, backendPublicKeyHex);
+	const data = decryptAttachmentChunks(encryptedChunks, keyMaterial);
+
+	logger.debug('✅ Attachment decryption after download complete');
+	return data;
+}
+
 /**
  * Decrypt user private key context from backend (magic link validation)
  *
@@ -32,6 +32,13 @@ export const navigationItems: NavItem[] = [
 		description: 'Generate BIP39 mnemonic phrases for cryptocurrency wallets',
 		path: '/mnemonic',
 		icon: '💾'
+	},
+	{
+		id: 'secret-request',
+		title: 'Request a Secret',
+		description: 'Ask someone to send you a secret through an encrypted upload link',
+		path: '/shared-secret/request',
+		icon: '📨'
 	}
 ];
 
@@ -290,6 +290,19 @@ export const ar: I18nTexts = {
 		notAccessedYet: 'لم يتم الوصول بعد',
 		creationError: 'خطأ في إنشاء السر',
 		retrievalError: 'خطأ في استرداد السر',
+		notYetActive: 'هذا السر غير متاح حتى {date}',
+		addReceiver: 'إضافة مستلم',
+		removeReceiver: 'إزالة',
+		emailDuplicate: 'البريد الإلكتروني للمستلم موجود بالفعل في القائمة',
+		receiversStatus: 'المستلمون',
+		attachment: 'مرفق (اختياري)',
+		attachmentHint: 'حتى 5 ميبيبايت، يُشفَّر في متصفحك قبل الرفع',
+		attachmentTooLarge: 'يجب أن يكون حجم الملف بين 1 بايت و5 ميبيبايت',
+		attachmentNameTooLong: 'لا يمكن أن يتجاوز اسم الملف 255 بايت',
+		downloadAttachment: 'تنزيل',
+		downloadingAttachment: 'جارٍ التنزيل...',
+		downloadsRemaining: 'تنزيلات متبقية',
+		attachmentDownloadError: 'خطأ في تنزيل المرفق',
 		accessDenied: 'تم رفض الوصول: هذا السر يخص مستخدمًا آخر',
 		deletionError: 'خطأ في حذف السر',
 		// M3: Enhanced metadata
@@ -316,6 +329,31 @@ export const ar: I18nTexts = {
 		backendKeyNotAvailable: 'مفتاح تشفير الخادم غير متاح',
 		privateKeyNotAvailable: 'المفتاح الخاص غير متاح - يرجى تسجيل الدخول مرة أخرى'
 	},
+	secretRequest: {
+		title: 'طلب سر',
+		description: 'اطلب من شخص ما أن يرسل إليك سرًا عبر رابط رفع مشفّر',
+		uploadTitle: 'إرسال السر المطلوب',
+		inviteeEmail: 'البريد الإلكتروني للشخص الذي سيرسل السر',
+		emailSameAsRequester: 'لا يمكنك طلب سر من نفسك',
+		message: 'الرسالة (ما تحتاجه)',
+		messagePlaceholder: 'مثال: كلمة مرور قاعدة بيانات الاختبار',
+		messageTooLong: 'لا يمكن أن تتجاوز الرسالة 500 حرف',
+		createRequest: 'إرسال الطلب',
+		creating: 'جارٍ الإرسال...',
+		requestCreated: 'تم إرسال طلب السر',
+		creationError: 'خطأ في إنشاء طلب السر',
+		requesterUrl: 'رابطك (صالح بعد إرسال السر)',
+		requestUrl: 'رابط الرفع (أُرسل بالبريد الإلكتروني إلى الشخص الآخر)',
+		linkExpiresAt: 'ينتهي رابط الرفع في',
+		requestedBy: 'طلبه',
+		secretLifetime: 'مدة صلاحية السر',
+		sendSecret: 'إرسال السر',
+		sending: 'جارٍ الإرسال...',
+		secretSent: 'تم إرسال السر إلى الطالب',
+		fulfillError: 'خطأ في إرسال السر',
+		requestNotFound: 'طلب السر غير موجود أو منتهي الصلاحية أو تمت تلبيته',
+		accessDenied: 'تم رفض الوصول: هذا الطلب موجه إلى مستخدم آخر'
+	},
 	logout: {
 		title: 'تسجيل الخروج',
 		description: 'هل أنت متأكد من أنك تريد تسجيل الخروج؟',
@@ -293,6 +293,19 @@ export const ca: I18nTexts = {
 		notAccessedYet: 'Encara no accedit',
 		creationError: 'Error en crear el secret',
 		retrievalError: 'Error en recuperar el secret',
+		notYetActive: 'Aquest secret no estarà disponible fins al {date}',
+		addReceiver: 'Afegeix destinatari',
+		removeReceiver: 'Treu',
+		emailDuplicate: 'El correu del destinatari ja és a la llista',
+		receiversStatus: 'Destinataris',
+		attachment: 'Adjunt (opcional)',
+		attachmentHint: 'Fins a 5 MiB, xifrat al teu navegador abans de pujar-lo',
+		attachmentTooLarge: 'El fitxer ha de tenir entre 1 byte i 5 MiB',
+		attachmentNameTooLong: 'El nom del fitxer no pot superar els 255 bytes',
+		downloadAttachment: 'Descarrega',
+		downloadingAttachment: 'Descarregant...',
+		downloadsRemaining: 'descàrregues restants',
+		attachmentDownloadError: "Error en descarregar l'adjunt",
 		accessDenied: 'Accés denegat: Aquest secret pertany a un altre usuari',
 		deletionError: 'Error en eliminar el secret',
 		// M3: Enhanced metadata
@@ -319,6 +332,31 @@ export const ca: I18nTexts = {
 		backendKeyNotAvailable: 'Clau de xifrat del servidor no disponible',
 		privateKeyNotAvailable: 'Clau privada no disponible - Si us plau, inicieu sessió de nou'
 	},
+	secretRequest: {
+		title: 'Sol·licita un secret',
+		description: "Demana a algú que t'enviï un secret mitjançant un enllaç de pujada xifrat",
+		uploadTitle: 'Envia el secret sol·licitat',
+		inviteeEmail: 'Correu de la persona que enviarà el secret',
+		emailSameAsRequester: 'No et pots sol·licitar un secret a tu mateix',
+		message: 'Missatge (què necessites)',
+		messagePlaceholder: 'p. ex. Contrasenya de la base de dades de staging',
+		messageTooLong: 'El missatge no pot superar els 500 caràcters',
+		createRequest: 'Envia la sol·licitud',
+		creating: 'Enviant...',
+		requestCreated: 'Sol·licitud de secret enviada',
+		creationError: 'Error en crear la sol·licitud de secret',
+		requesterUrl: "La teva URL (vàlida quan s'hagi enviat el secret)",
+		requestUrl: "URL de pujada (enviada per correu a l'altra persona)",
+		linkExpiresAt: "L'enllaç de pujada caduca el",
+		requestedBy: 'Sol·licitat per',
+		secretLifetime: 'Durada del secret',
+		sendSecret: 'Envia el secret',
+		sending: 'Enviant...',
+		secretSent: 'Secret enviat al sol·licitant',
+		fulfillError: 'Error en enviar el secret',
+		requestNotFound: 'Sol·licitud de secret no trobada, caducada o ja atesa',
+		accessDenied: 'Accés denegat: aquesta sol·licitud està adreçada a un altre usuari'
+	},
 	logout: {
 		title: 'Tancar Sessió',
 		description: 'Esteu segur que voleu tancar la sessió?',
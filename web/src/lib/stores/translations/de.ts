@@ -302,6 +302,19 @@ export const de: I18nTexts = {
 		notAccessedYet: 'Noch nicht zugegriffen',
 		creationError: 'Fehler beim Erstellen des Geheimnisses',
 		retrievalError: 'Fehler beim Abrufen des Geheimnisses',
+		notYetActive: 'Dieses Geheimnis ist erst ab {date} verfügbar',
+		addReceiver: 'Empfänger hinzufügen',
+		removeReceiver: 'Entfernen',
+		emailDuplicate: 'Die Empfänger-E-Mail ist bereits in der Liste',
+		receiversStatus: 'Empfänger',
+		attachment: 'Anhang (optional)',
+		attachmentHint: 'Bis zu 5 MiB, vor dem Hochladen in Ihrem Browser verschlüsselt',
+		attachmentTooLarge: 'Die Datei muss zwischen 1 Byte und 5 MiB groß sein',
+		attachmentNameTooLong: 'Der Dateiname darf 255 Bytes nicht überschreiten',
+		downloadAttachment: 'Herunterladen',
+		downloadingAttachment: 'Wird heruntergeladen...',
+		downloadsRemaining: 'verbleibende Downloads',
+		attachmentDownloadError: 'Fehler beim Herunterladen des Anhangs',
 		accessDenied: 'Zugriff verweigert: Dieses Geheimnis gehört einem anderen Benutzer',
 		deletionError: 'Fehler beim Löschen des Geheimnisses',
 		// M3: Enhanced metadata
@@ -328,6 +341,31 @@ export const de: I18nTexts = {
 		backendKeyNotAvailable: 'Server-Verschlüsselungsschlüssel nicht verfügbar',
 		privateKeyNotAvailable: 'Privater Schlüssel nicht verfügbar - Bitte melden Sie sich erneut an'
 	},
+	secretRequest: {
+		title: 'Geheimnis anfordern',
+		description: 'Bitten Sie jemanden, Ihnen ein Geheimnis über einen verschlüsselten Upload-Link zu senden',
+		uploadTitle: 'Angefordertes Geheimnis senden',
+		inviteeEmail: 'E-Mail der Person, die das Geheimnis senden wird',
+		emailSameAsRequester: 'Sie können kein Geheimnis von sich selbst anfordern',
+		message: 'Nachricht (was Sie benötigen)',
+		messagePlaceholder: 'z. B. Passwort der Staging-Datenbank',
+		messageTooLong: 'Die Nachricht darf 500 Zeichen nicht überschreiten',
+		createRequest: 'Anfrage senden',
+		creating: 'Wird gesendet...',
+		requestCreated: 'Geheimnisanfrage gesendet',
+		creationError: 'Fehler beim Erstellen der Geheimnisanfrage',
+		requesterUrl: 'Ihre URL (gültig, sobald das Geheimnis gesendet wurde)',
+		requestUrl: 'Upload-URL (per E-Mail an die andere Person gesendet)',
+		linkExpiresAt: 'Upload-Link läuft ab am',
+		requestedBy: 'Angefordert von',
+		secretLifetime: 'Lebensdauer des Geheimnisses',
+		sendSecret: 'Geheimnis senden',
+		sending: 'Wird gesendet...',
+		secretSent: 'Geheimnis an den Anfragenden gesendet',
+		fulfillError: 'Fehler beim Senden des Geheimnisses',
+		requestNotFound: 'Geheimnisanfrage nicht gefunden, abgelaufen oder bereits erfüllt',
+		accessDenied: 'Zugriff verweigert: Diese Anfrage ist an einen anderen Benutzer gerichtet'
+	},
 	logout: {
 		title: 'Abmelden',
 		description: 'Sind Sie sicher, dass Sie sich abmelden möchten?',
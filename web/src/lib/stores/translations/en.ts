@@ -304,6 +304,19 @@ export const en: I18nTexts = {
 		notAccessedYet: 'Not accessed yet',
 		creationError: 'Error creating secret',
 		retrievalError: 'Error retrieving secret',
+		notYetActive: 'This secret is not available until {date}',
+		addReceiver: 'Add recipient',
+		removeReceiver: 'Remove',
+		emailDuplicate: 'Recipient email is already in the list',
+		receiversStatus: 'Recipients',
+		attachment: 'Attachment (optional)',
+		attachmentHint: 'Up to 5 MiB, encrypted in your browser before upload',
+		attachmentTooLarge: 'The file must be between 1 byte and 5 MiB',
+		attachmentNameTooLong: 'The file name cannot exceed 255 bytes',
+		downloadAttachment: 'Download',
+		downloadingAttachment: 'Downloading...',
+		downloadsRemaining: 'downloads remaining',
+		attachmentDownloadError: 'Error downloading attachment',
 		accessDenied: 'Access denied: This secret belongs to another user',
 		deletionError: 'Error deleting secret',
 		// M3: Enhanced metadata
@@ -330,6 +343,31 @@ export const en: I18nTexts = {
 		backendKeyNotAvailable: 'Backend encryption key not available',
 		privateKeyNotAvailable: 'Private key not available - Please log in again'
 	},
+	secretRequest: {
+		title: 'Request a Secret',
+		description: 'Ask someone to send you a secret through an encrypted upload link',
+		uploadTitle: 'Send the Requested Secret',
+		inviteeEmail: 'Email of the person who will send the secret',
+		emailSameAsRequester: 'You cannot request a secret from yourself',
+		message: 'Message (what you need)',
+		messagePlaceholder: 'e.g. Staging database password',
+		messageTooLong: 'Message cannot exceed 500 characters',
+		createRequest: 'Send Request',
+		creating: 'Sending...',
+		requestCreated: 'Secret request sent',
+		creationError: 'Error creating secret request',
+		requesterUrl: 'Your URL (valid once the secret has been sent)',
+		requestUrl: 'Upload URL (emailed to the other person)',
+		linkExpiresAt: 'Upload link expires at',
+		requestedBy: 'Requested by',
+		secretLifetime: 'Secret lifetime',
+		sendSecret: 'Send Secret',
+		sending: 'Sending...',
+		secretSent: 'Secret sent to the requester',
+		fulfillError: 'Error sending the secret',
+		requestNotFound: 'Secret request not found, expired or already fulfilled',
+		accessDenied: 'Access denied: This request is addressed to another user'
+	},
 	logout: {
 		title: 'Log Out',
 		description: 'Are you sure you want to log out?',
@@ -308,6 +308,19 @@ export const es: I18nTexts = {
 		notAccessedYet: 'Aún no accedido',
 		creationError: 'Error al crear el secreto',
 		retrievalError: 'Error al recuperar el secreto',
+		notYetActive: 'Este secreto no estará disponible hasta el {date}',
+		addReceiver: 'Añadir destinatario',
+		removeReceiver: 'Quitar',
+		emailDuplicate: 'El email del destinatario ya está en la lista',
+		receiversStatus: 'Destinatarios',
+		attachment: 'Adjunto (opcional)',
+		attachmentHint: 'Hasta 5 MiB, cifrado en tu navegador antes de subirlo',
+		attachmentTooLarge: 'El archivo debe tener entre 1 byte y 5 MiB',
+		attachmentNameTooLong: 'El nombre del archivo no puede superar los 255 bytes',
+		downloadAttachment: 'Descargar',
+		downloadingAttachment: 'Descargando...',
+		downloadsRemaining: 'descargas restantes',
+		attachmentDownloadError: 'Error al descargar el adjunto',
 		accessDenied: 'Acceso denegado: Este secreto pertenece a otro usuario',
 		deletionError: 'Error al eliminar el secreto',
 		// M3: Metadata enriquecida
@@ -334,6 +347,31 @@ export const es: I18nTexts = {
 		backendKeyNotAvailable: 'Clave de cifrado del servidor no disponible',
 		privateKeyNotAvailable: 'Clave privada no disponible - Por favor, inicia sesión nuevamente'
 	},
+	secretRequest: {
+		title: 'Solicitar un secreto',
+		description: 'Pide a alguien que te envíe un secreto mediante un enlace de subida cifrado',
+		uploadTitle: 'Enviar el secreto solicitado',
+		inviteeEmail: 'Email de la persona que enviará el secreto',
+		emailSameAsRequester: 'No puedes solicitarte un secreto a ti mismo',
+		message: 'Mensaje (qué necesitas)',
+		messagePlaceholder: 'p. ej. Contraseña de la base de datos de staging',
+		messageTooLong: 'El mensaje no puede superar los 500 caracteres',
+		createRequest: 'Enviar solicitud',
+		creating: 'Enviando...',
+		requestCreated: 'Solicitud de secreto enviada',
+		creationError: 'Error al crear la solicitud de secreto',
+		requesterUrl: 'Tu URL (válida cuando se haya enviado el secreto)',
+		requestUrl: 'URL de subida (enviada por email a la otra persona)',
+		linkExpiresAt: 'El enlace de subida caduca el',
+		requestedBy: 'Solicitado por',
+		secretLifetime: 'Duración del secreto',
+		sendSecret: 'Enviar secreto',
+		sending: 'Enviando...',
+		secretSent: 'Secreto enviado al solicitante',
+		fulfillError: 'Error al enviar el secreto',
+		requestNotFound: 'Solicitud de secreto no encontrada, caducada o ya atendida',
+		accessDenied: 'Acceso denegado: esta solicitud está dirigida a otro usuario'
+	},
 	logout: {
 		title: 'Cerrar Sesión',
 		description: '¿Estás seguro de que quieres cerrar tu sesión?',
@@ -289,6 +289,19 @@ export const eu: I18nTexts = {
 		notAccessedYet: 'Oraindik sartu gabe',
 		creationError: 'Akatsa sekretua sortzerakoan',
 		retrievalError: 'Akatsa sekretua eskuratzerakoan',
+		notYetActive: 'Sekretu hau ez dago eskuragarri {date} arte',
+		addReceiver: 'Gehitu hartzailea',
+		removeReceiver: 'Kendu',
+		emailDuplicate: 'Hartzailearen emaila dagoeneko zerrendan dago',
+		receiversStatus: 'Hartzaileak',
+		attachment: 'Eranskina (aukerakoa)',
+		attachmentHint: 'Gehienez 5 MiB, zure nabigatzailean zifratua igo aurretik',
+		attachmentTooLarge: 'Fitxategiak 1 byte eta 5 MiB artean izan behar ditu',
+		attachmentNameTooLong: 'Fitxategiaren izenak ezin ditu 255 byte gainditu',
+		downloadAttachment: 'Deskargatu',
+		downloadingAttachment: 'Deskargatzen...',
+		downloadsRemaining: 'deskarga geratzen dira',
+		attachmentDownloadError: 'Errorea eranskina deskargatzean',
 		accessDenied: 'Sarbidea ukatua: Sekretu hau beste erabiltzaile batena da',
 		deletionError: 'Akatsa sekretua ezabatzerakoan',
 		// M3: Enhanced metadata
@@ -315,6 +328,31 @@ export const eu: I18nTexts = {
 		backendKeyNotAvailable: 'Zerbitzariaren zifratzeko gakoa ez dago erabilgarri',
 		privateKeyNotAvailable: 'Gako pribatua ez dago erabilgarri - Mesedez, hasi saioa berriro'
 	},
+	secretRequest: {
+		title: 'Eskatu sekretu bat',
+		description: 'Eskatu norbaiti sekretu bat bidaltzeko igoera-esteka zifratu baten bidez',
+		uploadTitle: 'Bidali eskatutako sekretua',
+		inviteeEmail: 'Sekretua bidaliko duen pertsonaren emaila',
+		emailSameAsRequester: 'Ezin diozu zeure buruari sekretu bat eskatu',
+		message: 'Mezua (zer behar duzun)',
+		messagePlaceholder: 'adib. Staging datu-basearen pasahitza',
+		messageTooLong: 'Mezuak ezin ditu 500 karaktere gainditu',
+		createRequest: 'Bidali eskaera',
+		creating: 'Bidaltzen...',
+		requestCreated: 'Sekretu-eskaera bidali da',
+		creationError: 'Errorea sekretu-eskaera sortzean',
+		requesterUrl: 'Zure URLa (sekretua bidali ondoren baliozkoa)',
+		requestUrl: 'Igoera-URLa (beste pertsonari emailez bidalia)',
+		linkExpiresAt: 'Igoera-esteka iraungitzen da',
+		requestedBy: 'Eskatzailea',
+		secretLifetime: 'Sekretuaren iraupena',
+		sendSecret: 'Bidali sekretua',
+		sending: 'Bidaltzen...',
+		secretSent: 'Sekretua eskatzaileari bidali zaio',
+		fulfillError: 'Errorea sekretua bidaltzean',
+		requestNotFound: 'Sekretu-eskaera ez da aurkitu, iraungi da edo dagoeneko bete da',
+		accessDenied: 'Sarbidea ukatua: eskaera hau beste erabiltzaile bati zuzenduta dago'
+	},
 	logout: {
 		title: 'Amaitu Saioa',
 		description: 'Ziur zaude saioa amaitu nahi duzula?',
@@ -301,6 +301,19 @@ export const fr: I18nTexts = {
 		notAccessedYet: 'Pas encore accédé',
 		creationError: 'Erreur lors de la création du secret',
 		retrievalError: 'Erreur lors de la récupération du secret',
+		notYetActive: "Ce secret n'est pas disponible avant le {date}",
+		addReceiver: 'Ajouter un destinataire',
+		removeReceiver: 'Retirer',
+		emailDuplicate: "L'email du destinataire est déjà dans la liste",
+		receiversStatus: 'Destinataires',
+		attachment: 'Pièce jointe (facultative)',
+		attachmentHint: "Jusqu'à 5 Mio, chiffrée dans votre navigateur avant l'envoi",
+		attachmentTooLarge: 'Le fichier doit faire entre 1 octet et 5 Mio',
+		attachmentNameTooLong: 'Le nom du fichier ne peut pas dépasser 255 octets',
+		downloadAttachment: 'Télécharger',
+		downloadingAttachment: 'Téléchargement...',
+		downloadsRemaining: 'téléchargements restants',
+		attachmentDownloadError: 'Erreur lors du téléchargement de la pièce jointe',
 		accessDenied: 'Accès refusé : Ce secret appartient à un autre utilisateur',
 		deletionError: 'Erreur lors de la suppression du secret',
 		// M3: Enhanced metadata
@@ -327,6 +340,31 @@ export const fr: I18nTexts = {
 		backendKeyNotAvailable: 'Clé de chiffrement du serveur non disponible',
 		privateKeyNotAvailable: 'Clé privée non disponible - Veuillez vous reconnecter'
 	},
+	secretRequest: {
+		title: 'Demander un secret',
+		description: "Demandez à quelqu'un de vous envoyer un secret via un lien de dépôt chiffré",
+		uploadTitle: 'Envoyer le secret demandé',
+		inviteeEmail: 'Email de la personne qui enverra le secret',
+		emailSameAsRequester: 'Vous ne pouvez pas vous demander un secret à vous-même',
+		message: 'Message (ce dont vous avez besoin)',
+		messagePlaceholder: 'ex. Mot de passe de la base de données de staging',
+		messageTooLong: 'Le message ne peut pas dépasser 500 caractères',
+		createRequest: 'Envoyer la demande',
+		creating: 'Envoi...',
+		requestCreated: 'Demande de secret envoyée',
+		creationError: 'Erreur lors de la création de la demande de secret',
+		requesterUrl: 'Votre URL (valable une fois le secret envoyé)',
+		requestUrl: "URL de dépôt (envoyée par email à l'autre personne)",
+		linkExpiresAt: 'Le lien de dépôt expire le',
+		requestedBy: 'Demandé par',
+		secretLifetime: 'Durée de vie du secret',
+		sendSecret: 'Envoyer le secret',
+		sending: 'Envoi...',
+		secretSent: 'Secret envoyé au demandeur',
+		fulfillError: "Erreur lors de l'envoi du secret",
+		requestNotFound: 'Demande de secret introuvable, expirée ou déjà satisfaite',
+		accessDenied: 'Accès refusé : cette demande est adressée à un autre utilisateur'
+	},
 	logout: {
 		title: 'Se Déconnecter',
 		description: 'Êtes-vous sûr de vouloir vous déconnecter?',
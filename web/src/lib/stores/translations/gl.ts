@@ -292,6 +292,19 @@ export const gl: I18nTexts = {
 		notAccessedYet: 'Aínda non accedido',
 		creationError: 'Erro ao crear o segredo',
 		retrievalError: 'Erro ao recuperar o segredo',
+		notYetActive: 'Este segredo non estará dispoñible ata o {date}',
+		addReceiver: 'Engadir destinatario',
+		removeReceiver: 'Quitar',
+		emailDuplicate: 'O correo do destinatario xa está na lista',
+		receiversStatus: 'Destinatarios',
+		attachment: 'Anexo (opcional)',
+		attachmentHint: 'Ata 5 MiB, cifrado no teu navegador antes de subilo',
+		attachmentTooLarge: 'O ficheiro debe ter entre 1 byte e 5 MiB',
+		attachmentNameTooLong: 'O nome do ficheiro non pode superar os 255 bytes',
+		downloadAttachment: 'Descargar',
+		downloadingAttachment: 'Descargando...',
+		downloadsRemaining: 'descargas restantes',
+		attachmentDownloadError: 'Erro ao descargar o anexo',
 		accessDenied: 'Acceso denegado: Este segredo pertence a outro usuario',
 		deletionError: 'Erro ao eliminar o segredo',
 		// M3: Enhanced metadata
@@ -318,6 +331,31 @@ export const gl: I18nTexts = {
 		backendKeyNotAvailable: 'Chave de cifrado do servidor non disponible',
 		privateKeyNotAvailable: 'Chave privada non disponible - Por favor, inicie sesión de novo'
 	},
+	secretRequest: {
+		title: 'Solicitar un segredo',
+		description: 'Pídelle a alguén que che envíe un segredo mediante unha ligazón de subida cifrada',
+		uploadTitle: 'Enviar o segredo solicitado',
+		inviteeEmail: 'Correo da persoa que enviará o segredo',
+		emailSameAsRequester: 'Non podes solicitarte un segredo a ti mesmo',
+		message: 'Mensaxe (que necesitas)',
+		messagePlaceholder: 'p. ex. Contrasinal da base de datos de staging',
+		messageTooLong: 'A mensaxe non pode superar os 500 caracteres',
+		createRequest: 'Enviar solicitude',
+		creating: 'Enviando...',
+		requestCreated: 'Solicitude de segredo enviada',
+		creationError: 'Erro ao crear a solicitude de segredo',
+		requesterUrl: 'O teu URL (válido cando se enviase o segredo)',
+		requestUrl: 'URL de subida (enviado por correo á outra persoa)',
+		linkExpiresAt: 'A ligazón de subida caduca o',
+		requestedBy: 'Solicitado por',
+		secretLifetime: 'Duración do segredo',
+		sendSecret: 'Enviar segredo',
+		sending: 'Enviando...',
+		secretSent: 'Segredo enviado ao solicitante',
+		fulfillError: 'Erro ao enviar o segredo',
+		requestNotFound: 'Solicitude de segredo non atopada, caducada ou xa atendida',
+		accessDenied: 'Acceso denegado: esta solicitude está dirixida a outro usuario'
+	},
 	logout: {
 		title: 'Pechar Sesión',
 		description: 'Estás seguro de que queres pechar a sesión?',
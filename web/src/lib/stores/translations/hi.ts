@@ -291,6 +291,19 @@ export const hi: I18nTexts = {
 		notAccessedYet: 'अभी तक एक्सेस नहीं किया गया',
 		creationError: 'रहस्य बनाने में त्रुटि',
 		retrievalError: 'रहस्य प्राप्त करने में त्रुटि',
+		notYetActive: 'यह रहस्य {date} तक उपलब्ध नहीं है',
+		addReceiver: 'प्राप्तकर्ता जोड़ें',
+		removeReceiver: 'हटाएं',
+		emailDuplicate: 'प्राप्तकर्ता का ईमेल पहले से सूची में है',
+		receiversStatus: 'प्राप्तकर्ता',
+		attachment: 'अनुलग्नक (वैकल्पिक)',
+		attachmentHint: 'अधिकतम 5 MiB, अपलोड से पहले आपके ब्राउज़र में एन्क्रिप्ट किया जाता है',
+		attachmentTooLarge: 'फ़ाइल 1 बाइट से 5 MiB के बीच होनी चाहिए',
+		attachmentNameTooLong: 'फ़ाइल का नाम 255 बाइट से अधिक नहीं हो सकता',
+		downloadAttachment: 'डाउनलोड करें',
+		downloadingAttachment: 'डाउनलोड हो रहा है...',
+		downloadsRemaining: 'डाउनलोड शेष',
+		attachmentDownloadError: 'अनुलग्नक डाउनलोड करने में त्रुटि',
 		accessDenied: 'पहुंच अस्वीकृत: यह रहस्य किसी अन्य उपयोगकर्ता का है',
 		deletionError: 'रहस्य हटाने में त्रुटि',
 		// M3: Enhanced metadata
@@ -317,6 +330,31 @@ export const hi: I18nTexts = {
 		backendKeyNotAvailable: 'सर्वर एन्क्रिप्शन कुंजी उपलब्ध नहीं है',
 		privateKeyNotAvailable: 'निजी कुंजी उपलब्ध नहीं है - कृपया फिर से लॉगिन करें'
 	},
+	secretRequest: {
+		title: 'रहस्य का अनुरोध करें',
+		description: 'किसी से एन्क्रिप्टेड अपलोड लिंक के माध्यम से आपको रहस्य भेजने के लिए कहें',
+		uploadTitle: 'अनुरोधित रहस्य भेजें',
+		inviteeEmail: 'रहस्य भेजने वाले व्यक्ति का ईमेल',
+		emailSameAsRequester: 'आप स्वयं से रहस्य का अनुरोध नहीं कर सकते',
+		message: 'संदेश (आपको क्या चाहिए)',
+		messagePlaceholder: 'उदा. स्टेजिंग डेटाबेस पासवर्ड',
+		messageTooLong: 'संदेश 500 वर्णों से अधिक नहीं हो सकता',
+		createRequest: 'अनुरोध भेजें',
+		creating: 'भेजा जा रहा है...',
+		requestCreated: 'रहस्य अनुरोध भेजा गया',
+		creationError: 'रहस्य अनुरोध बनाने में त्रुटि',
+		requesterUrl: 'आपका URL (रहस्य भेजे जाने के बाद मान्य)',
+		requestUrl: 'अपलोड URL (दूसरे व्यक्ति को ईमेल किया गया)',
+		linkExpiresAt: 'अपलोड लिंक की समाप्ति',
+		requestedBy: 'अनुरोधकर्ता',
+		secretLifetime: 'रहस्य की अवधि',
+		sendSecret: 'रहस्य भेजें',
+		sending: 'भेजा जा रहा है...',
+		secretSent: 'रहस्य अनुरोधकर्ता को भेजा गया',
+		fulfillError: 'रहस्य भेजने में त्रुटि',
+		requestNotFound: 'रहस्य अनुरोध नहीं मिला, समाप्त हो गया या पहले ही पूरा हो चुका है',
+		accessDenied: 'पहुंच अस्वीकृत: यह अनुरोध किसी अन्य उपयोगकर्ता के लिए है'
+	},
 	logout: {
 		title: 'लॉग आउट करें',
 		description: 'क्या आप वाकई लॉग आउट करना चाहते हैं?',
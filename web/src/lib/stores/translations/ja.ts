@@ -293,6 +293,19 @@ export const ja: I18nTexts = {
 		notAccessedYet: 'まだアクセスされていません',
 		creationError: 'シークレットの作成エラー',
 		retrievalError: 'シークレットの取得エラー',
+		notYetActive: 'このシークレットは {date} まで利用できません',
+		addReceiver: '受信者を追加',
+		removeReceiver: '削除',
+		emailDuplicate: '受信者のメールアドレスは既にリストにあります',
+		receiversStatus: '受信者',
+		attachment: '添付ファイル（任意）',
+		attachmentHint: '最大 5 MiB、アップロード前にブラウザで暗号化されます',
+		attachmentTooLarge: 'ファイルサイズは 1 バイトから 5 MiB の間である必要があります',
+		attachmentNameTooLong: 'ファイル名は 255 バイトを超えることはできません',
+		downloadAttachment: 'ダウンロード',
+		downloadingAttachment: 'ダウンロード中...',
+		downloadsRemaining: '回のダウンロードが残っています',
+		attachmentDownloadError: '添付ファイルのダウンロード中にエラーが発生しました',
 		accessDenied: 'アクセス拒否：このシークレットは別のユーザーのものです',
 		deletionError: 'シークレットの削除エラー',
 		// M3: Enhanced metadata
@@ -319,6 +332,31 @@ export const ja: I18nTexts = {
 		backendKeyNotAvailable: 'サーバーの暗号化キーが利用できません',
 		privateKeyNotAvailable: '秘密鍵が利用できません - 再度ログインしてください'
 	},
+	secretRequest: {
+		title: 'シークレットをリクエスト',
+		description: '暗号化されたアップロードリンクでシークレットを送ってもらうよう依頼します',
+		uploadTitle: 'リクエストされたシークレットを送信',
+		inviteeEmail: 'シークレットを送信する人のメールアドレス',
+		emailSameAsRequester: '自分自身にシークレットをリクエストすることはできません',
+		message: 'メッセージ（必要なもの）',
+		messagePlaceholder: '例：ステージング環境のデータベースパスワード',
+		messageTooLong: 'メッセージは 500 文字を超えることはできません',
+		createRequest: 'リクエストを送信',
+		creating: '送信中...',
+		requestCreated: 'シークレットのリクエストを送信しました',
+		creationError: 'シークレットのリクエスト作成中にエラーが発生しました',
+		requesterUrl: 'あなたの URL（シークレット送信後に有効）',
+		requestUrl: 'アップロード URL（相手にメールで送信済み）',
+		linkExpiresAt: 'アップロードリンクの有効期限',
+		requestedBy: 'リクエスト元',
+		secretLifetime: 'シークレットの有効期間',
+		sendSecret: 'シークレットを送信',
+		sending: '送信中...',
+		secretSent: 'シークレットをリクエスト元に送信しました',
+		fulfillError: 'シークレットの送信中にエラーが発生しました',
+		requestNotFound: 'シークレットのリクエストが見つからないか、期限切れか、既に完了しています',
+		accessDenied: 'アクセス拒否：このリクエストは別のユーザー宛てです'
+	},
 	logout: {
 		title: 'ログアウト',
 		description: 'ログアウトしてもよろしいですか？',
@@ -298,6 +298,19 @@ export const pt: I18nTexts = {
 		notAccessedYet: 'Ainda não acedido',
 		creationError: 'Erro ao criar o segredo',
 		retrievalError: 'Erro ao recuperar o segredo',
+		notYetActive: 'Este segredo não estará disponível até {date}',
+		addReceiver: 'Adicionar destinatário',
+		removeReceiver: 'Remover',
+		emailDuplicate: 'O email do destinatário já está na lista',
+		receiversStatus: 'Destinatários',
+		attachment: 'Anexo (opcional)',
+		attachmentHint: 'Até 5 MiB, cifrado no seu navegador antes do envio',
+		attachmentTooLarge: 'O ficheiro deve ter entre 1 byte e 5 MiB',
+		attachmentNameTooLong: 'O nome do ficheiro não pode exceder 255 bytes',
+		downloadAttachment: 'Transferir',
+		downloadingAttachment: 'A transferir...',
+		downloadsRemaining: 'transferências restantes',
+		attachmentDownloadError: 'Erro ao transferir o anexo',
 		accessDenied: 'Acesso negado: Este segredo pertence a outro usuário',
 		deletionError: 'Erro ao excluir o segredo',
 		// M3: Enhanced metadata
@@ -324,6 +337,31 @@ export const pt: I18nTexts = {
 		backendKeyNotAvailable: 'Chave de encriptação do servidor não disponível',
 		privateKeyNotAvailable: 'Chave privada não disponível - Por favor, inicie sessão novamente'
 	},
+	secretRequest: {
+		title: 'Pedir um segredo',
+		description: 'Peça a alguém que lhe envie um segredo através de uma ligação de envio cifrada',
+		uploadTitle: 'Enviar o segredo pedido',
+		inviteeEmail: 'Email da pessoa que enviará o segredo',
+		emailSameAsRequester: 'Não pode pedir um segredo a si próprio',
+		message: 'Mensagem (o que precisa)',
+		messagePlaceholder: 'ex. Palavra-passe da base de dados de staging',
+		messageTooLong: 'A mensagem não pode exceder 500 caracteres',
+		createRequest: 'Enviar pedido',
+		creating: 'A enviar...',
+		requestCreated: 'Pedido de segredo enviado',
+		creationError: 'Erro ao criar o pedido de segredo',
+		requesterUrl: 'O seu URL (válido depois de o segredo ser enviado)',
+		requestUrl: 'URL de envio (enviado por email à outra pessoa)',
+		linkExpiresAt: 'A ligação de envio expira em',
+		requestedBy: 'Pedido por',
+		secretLifetime: 'Duração do segredo',
+		sendSecret: 'Enviar segredo',
+		sending: 'A enviar...',
+		secretSent: 'Segredo enviado ao requerente',
+		fulfillError: 'Erro ao enviar o segredo',
+		requestNotFound: 'Pedido de segredo não encontrado, expirado ou já satisfeito',
+		accessDenied: 'Acesso negado: este pedido destina-se a outro utilizador'
+	},
 	logout: {
 		title: 'Sair',
 		description: 'Tem certeza de que deseja sair?',
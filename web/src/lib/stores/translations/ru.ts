@@ -291,6 +291,19 @@ export const ru: I18nTexts = {
 		notAccessedYet: 'Ещё не открыто',
 		creationError: 'Ошибка при создании секрета',
 		retrievalError: 'Ошибка при получении секрета',
+		notYetActive: 'Этот секрет недоступен до {date}',
+		addReceiver: 'Добавить получателя',
+		removeReceiver: 'Удалить',
+		emailDuplicate: 'Email получателя уже есть в списке',
+		receiversStatus: 'Получатели',
+		attachment: 'Вложение (необязательно)',
+		attachmentHint: 'До 5 МиБ, шифруется в вашем браузере перед загрузкой',
+		attachmentTooLarge: 'Размер файла должен быть от 1 байта до 5 МиБ',
+		attachmentNameTooLong: 'Имя файла не может превышать 255 байт',
+		downloadAttachment: 'Скачать',
+		downloadingAttachment: 'Скачивание...',
+		downloadsRemaining: 'скачиваний осталось',
+		attachmentDownloadError: 'Ошибка при скачивании вложения',
 		accessDenied: 'Доступ запрещён: Этот секрет принадлежит другому пользователю',
 		deletionError: 'Ошибка при удалении секрета',
 		// M3: Enhanced metadata
@@ -317,6 +330,31 @@ export const ru: I18nTexts = {
 		backendKeyNotAvailable: 'Ключ шифрования сервера недоступен',
 		privateKeyNotAvailable: 'Закрытый ключ недоступен - Пожалуйста, войдите снова'
 	},
+	secretRequest: {
+		title: 'Запросить секрет',
+		description: 'Попросите кого-нибудь отправить вам секрет через зашифрованную ссылку для загрузки',
+		uploadTitle: 'Отправить запрошенный секрет',
+		inviteeEmail: 'Email человека, который отправит секрет',
+		emailSameAsRequester: 'Нельзя запросить секрет у самого себя',
+		message: 'Сообщение (что вам нужно)',
+		messagePlaceholder: 'напр. Пароль базы данных staging',
+		messageTooLong: 'Сообщение не может превышать 500 символов',
+		createRequest: 'Отправить запрос',
+		creating: 'Отправка...',
+		requestCreated: 'Запрос секрета отправлен',
+		creationError: 'Ошибка при создании запроса секрета',
+		requesterUrl: 'Ваш URL (действителен после отправки секрета)',
+		requestUrl: 'URL для загрузки (отправлен другому человеку по email)',
+		linkExpiresAt: 'Ссылка для загрузки истекает',
+		requestedBy: 'Запросил',
+		secretLifetime: 'Срок жизни секрета',
+		sendSecret: 'Отправить секрет',
+		sending: 'Отправка...',
+		secretSent: 'Секрет отправлен запросившему',
+		fulfillError: 'Ошибка при отправке секрета',
+		requestNotFound: 'Запрос секрета не найден, истёк или уже выполнен',
+		accessDenied: 'Доступ запрещён: этот запрос адресован другому пользователю'
+	},
 	logout: {
 		title: 'Выйти',
 		description: 'Вы уверены, что хотите выйти?',
@@ -282,6 +282,19 @@ export const zh: I18nTexts = {
 		notAccessedYet: '尚未访问',
 		creationError: '创建秘密时出错',
 		retrievalError: '检索秘密时出错',
+		notYetActive: '此秘密在 {date} 之前不可用',
+		addReceiver: '添加收件人',
+		removeReceiver: '移除',
+		emailDuplicate: '收件人邮箱已在列表中',
+		receiversStatus: '收件人',
+		attachment: '附件（可选）',
+		attachmentHint: '最大 5 MiB，上传前在浏览器中加密',
+		attachmentTooLarge: '文件大小必须在 1 字节到 5 MiB 之间',
+		attachmentNameTooLong: '文件名不能超过 255 字节',
+		downloadAttachment: '下载',
+		downloadingAttachment: '正在下载...',
+		downloadsRemaining: '次下载剩余',
+		attachmentDownloadError: '下载附件时出错',
 		accessDenied: '访问被拒绝：此秘密属于另一个用户',
 		deletionError: '删除秘密时出错',
 		// M3: Enhanced metadata
@@ -308,6 +321,31 @@ export const zh: I18nTexts = {
 		backendKeyNotAvailable: '服务器加密密钥不可用',
 		privateKeyNotAvailable: '私钥不可用 - 请重新登录'
 	},
+	secretRequest: {
+		title: '请求秘密',
+		description: '请他人通过加密上传链接向您发送秘密',
+		uploadTitle: '发送请求的秘密',
+		inviteeEmail: '将发送秘密的人的邮箱',
+		emailSameAsRequester: '不能向自己请求秘密',
+		message: '消息（您需要什么）',
+		messagePlaceholder: '例如：预发布数据库密码',
+		messageTooLong: '消息不能超过 500 个字符',
+		createRequest: '发送请求',
+		creating: '正在发送...',
+		requestCreated: '秘密请求已发送',
+		creationError: '创建秘密请求时出错',
+		requesterUrl: '您的 URL（秘密发送后有效）',
+		requestUrl: '上传 URL（已通过邮件发送给对方）',
+		linkExpiresAt: '上传链接过期时间',
+		requestedBy: '请求者',
+		secretLifetime: '秘密有效期',
+		sendSecret: '发送秘密',
+		sending: '正在发送...',
+		secretSent: '秘密已发送给请求者',
+		fulfillError: '发送秘密时出错',
+		requestNotFound: '秘密请求未找到、已过期或已完成',
+		accessDenied: '访问被拒绝：此请求发给其他用户'
+	},
 	logout: {
 		title: '退出登录',
 		description: '您确定要退出登录吗？',
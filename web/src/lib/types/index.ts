@@ -257,10 +257,13 @@ export interface AuthError {
 export interface CreateSharedSecretRequest {
 	sender_email: string;
 	receiver_email: string;
+	additional_receivers?: string[]; // Further receivers (at most 10 receivers in total)
 	// secret_text: string; // REMOVED: Now using E2E encryption
 	encrypted_secret: string; // ChaCha20-Poly1305 encrypted secret (base64)
 	encrypted_key_material: string; // ECDH encrypted key_material[44] (base64)
-	expires_hours: number; // 1-72
+	expires_hours?: number; // Legacy lifetime in hours (expires_seconds takes precedence)
+	expires_seconds?: number; // Lifetime in seconds (server-configured bounds, default 60s-30 days)
+	not_before?: number; // Optional activation time (Unix seconds) before which receivers cannot read
 	max_reads: number; // 1-10
 	require_otp: boolean;
 	send_copy_to_sender: boolean;
@@ -269,13 +272,30 @@ export interface CreateSharedSecretRequest {
 	receiver_language?: string; // Optional: language for receiver email (defaults to 'en')
 	sender_language?: string; // Optional: language for sender copy email (defaults to 'en')
 	ui_host: string; // Required: UI hostname for URL generation (e.g., "localhost" or "app.domain.com")
+	attachment?: SharedSecretAttachmentUpload; // Optional E2E encrypted file
+}
+
+export interface SharedSecretAttachmentUpload {
+	filename: string; // 1-255 bytes, no path separators or control characters
+	mime_type: string; // type/subtype (falls back to application/octet-stream)
+	size: number; // Plaintext size in bytes (1 byte to 5 MiB)
+	encrypted_chunks: string[]; // ChaCha20-Poly1305 encrypted 64 KiB chunks in order (base64)
+}
+
+export interface CreatedSharedSecretReceiver {
+	email: string;
+	url: string; // Full URL with this receiver's hash
+	otp?: string; // This receiver's 9-digit OTP if require_otp is true
 }
 
 export interface CreateSharedSecretResponse {
 	url_sender: string; // Full URL with hash
-	url_receiver: string; // Full URL with hash
+	url_receiver: string; // Full URL with hash (first receiver)
 	reference: string; // Base58 reference hash (16 bytes)
-	otp?: string; // 9-digit OTP if require_otp is true
+	otp?: string; // 9-digit OTP if require_otp is true (first receiver)
+	receivers: CreatedSharedSecretReceiver[]; // Every receiver, in request order
+	expires_at: number; // Unix timestamp in seconds
+	not_before?: number; // Activation time in seconds (if delayed)
 }
 
 export interface SharedSecretPayload {
@@ -300,11 +320,87 @@ export interface ViewSharedSecretResponse {
 	receiver_email: string;
 	pending_reads: number; // -1 for sender (unlimited), positive for receiver
 	max_reads: number; // Maximum reads allowed (from encrypted payload)
-	expires_at: number; // Unix timestamp
+	expires_at: number; // Unix timestamp in seconds
+	not_before?: number; // Activation time in seconds (receivers get HTTP 425 before it)
 	reference: string; // Base58 reference hash
 	role: 'sender' | 'receiver';
 	otp?: string; // 9-digit OTP (only for sender role)
 	read_at?: number; // First access timestamp in seconds (only for sender, null if not yet accessed)
+	receivers?: SharedSecretReceiverStatus[]; // Per-receiver read status (only for sender)
+	attachment?: SharedSecretAttachmentInfo; // Present if the secret carries a file
+}
+
+export interface SharedSecretAttachmentInfo {
+	filename: string;
+	mime_type: string;
+	size: number; // Plaintext size in bytes
+	chunk_count: number;
+	chunk_size: number; // Plaintext bytes per chunk (the last chunk may be shorter)
+	pending_downloads: number; // -1 for sender (unlimited), remaining downloads for receiver
+}
+
+export interface SharedSecretAttachmentChunk {
+	encrypted_chunk: string; // ChaCha20-Poly1305 encrypted chunk (base64)
+	chunk_index: number;
+	chunk_count: number;
+	pending_downloads: number; // Decremented once the last chunk has been served (receiver)
+	role: 'sender' | 'receiver';
+}
+
+export interface SharedSecretReceiverStatus {
+	email: string;
+	pending_reads: number;
+	read_at?: number; // First access timestamp in seconds (null if not yet accessed)
+	otp?: string; // This receiver's 9-digit OTP if required
+	pending_downloads?: number; // Remaining attachment downloads (only with an attachment)
+}
+
+// Secret Request types ("request a secret": the invitee fulfils it through an upload link)
+export interface CreateSecretRequestRequest {
+	requester_email: string;
+	invitee_email: string;
+	message: string; // Note shown to the invitee (max 500 characters)
+	expires_hours?: number; // Lifetime of the resulting secret in hours (counted from fulfilment)
+	max_reads: number; // 1-10
+	require_otp: boolean;
+	request_expires_hours?: number; // Upload link lifetime (1-72 hours, defaults to 72)
+	// EXCEPTION TO INTEGER ENCODING POLICY (see top of file):
+	invitee_language?: string; // Optional: language for the invitee email (defaults to 'en')
+	requester_language?: string; // Optional: language for the requester notification
+	ui_host: string; // Required: UI hostname for URL generation
+}
+
+export interface CreateSecretRequestResponse {
+	url_request: string; // Invitee's upload URL (?request={hash})
+	url_requester: string; // Requester's URL (?shared={hash}), valid once fulfilled
+	reference: string; // Base58 reference hash
+	expires_at: number; // Upload link expiration (Unix seconds)
+}
+
+export interface SecretRequestDetails {
+	requester_email: string;
+	message: string;
+	expires_seconds: number; // Lifetime of the resulting secret
+	max_reads: number;
+	require_otp: boolean;
+	expires_at: number; // Upload link expiration (Unix seconds)
+	reference: string; // Base58 reference hash
+}
+
+export interface FulfillSecretRequestRequest {
+	hash: string; // Request hash from the invitee's link
+	encrypted_secret: string; // ChaCha20-Poly1305 encrypted secret (base64)
+	encrypted_key_material: string; // ECDH encrypted key_material[44] (base64)
+	attachment?: SharedSecretAttachmentUpload; // Optional E2E encrypted file
+	ui_host: string; // Required: UI hostname for URL generation
+}
+
+export interface FulfillSecretRequestResponse {
+	url_sender: string; // Invitee's sender URL (?shared={same hash})
+	url_receiver: string; // Requester's URL
+	receiver_email: string; // Requester email
+	reference: string; // Base58 reference hash
+	otp?: string; // Requester's 9-digit OTP if required
 }
 
 // Shared Secret Error Responses (returned as HTTP 200 with error field in SignedResponse)
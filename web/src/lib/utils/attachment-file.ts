@@ -0,0 +1,61 @@
+/**
+ * Attachment File Helpers
+ *
+ * Single Responsibility: Check files picked as shared secret attachments the way the
+ * backend validates them, and build the upload payload from their encrypted chunks
+ */
+
+import { MAX_ATTACHMENT_SIZE } from '$lib/crypto/shared-secret-crypto';
+import { bytesToBase64 } from '$lib/crypto/crypto-encoding';
+import type { SharedSecretAttachmentUpload } from '$lib/types';
+
+// Limits matching backend
+const MAX_FILENAME_BYTES = 255;
+const MAX_MIME_TYPE_LENGTH = 127;
+
+/**
+ * Translation key describing why the backend would reject `file` ('' if accepted)
+ *
+ * @param file - File picked by the user
+ * @returns 'sharedSecret.attachmentTooLarge', 'sharedSecret.attachmentNameTooLong' or ''
+ */
+export function attachmentFileError(file: File): string {
+	if (file.size === 0 || file.size > MAX_ATTACHMENT_SIZE) {
+		return 'sharedSecret.attachmentTooLarge';
+	}
+	if (new TextEncoder().encode(file.name).length > MAX_FILENAME_BYTES) {
+		return 'sharedSecret.attachmentNameTooLong';
+	}
+	return '';
+}
+
+/**
+ * MIME type as the backend accepts it (type/subtype, printable ASCII, at most 127 characters)
+ *
+ * @param file - File picked by the user
+ * @returns The browser's MIME type, or application/octet-stream if unknown or unusable
+ */
+export function attachmentMimeType(file: File): string {
+	return file.type.length <= MAX_MIME_TYPE_LENGTH && /^[!-~]+\/[!-~]+$/.test(file.type)
+		? file.type
+		: 'application/octet-stream';
+}
+
+/**
+ * Build the attachment upload payload from the file and its encrypted chunks
+ *
+ * @param file - Original file (name, type and plaintext size)
+ * @param encryptedChunks - ChaCha20-Poly1305 encrypted chunks in order
+ * @returns Attachment payload with base64 encoded chunks
+ */
+export function buildAttachmentUpload(
+	file: File,
+	encryptedChunks: Uint8Array[]
+): SharedSecretAttachmentUpload {
+	return {
+		filename: file.name,
+		mime_type: attachmentMimeType(file),
+		size: file.size,
+		encrypted_chunks: encryptedChunks.map((chunk) => bytesToBase64(chunk))
+	};
+}
@@ -8,6 +8,7 @@
  * - Dual-URL system (sender unlimited, receiver limited)
  * - OTP protection flow
  * - Delete functionality
+ * - Attachment downloads after the last read
  *
  * This version works without browser dependencies
  */
@@ -23,6 +24,7 @@ import { publicKeyBytesToHex, signatureBase58ToBytes } from '../../src/lib/ed255
 import { ed25519 } from '@noble/curves/ed25519.js';
 import { readFileSync, writeFileSync } from 'fs';
 import { execSync } from 'child_process';
+import { randomBytes } from 'crypto';
 import path from 'path';
 import { fileURLToPath } from 'url';
 import {
@@ -1028,6 +1030,128 @@ test.describe('API-Only Shared Secret Tests', () => {
 		await senderSession.clear();
 	});
 
+	test('should download attachment after the last read is consumed', async ({ request }) => {
+		console.log('🧪 TEST: Attachment download after last read');
+		console.log('='.repeat(60));
+
+		if (!receiverSession || !receiverAccessToken || !receiverKeyPair) {
+			throw new Error('Receiver session not authenticated');
+		}
+
+		const senderSession = sharedSession;
+		const senderAccessToken = sharedAccessToken;
+		const serverPubKey = sharedServerPubKey;
+		const senderKeyPair = sharedKeyPair;
+
+		// Create secret with a 2-chunk attachment (backend stores chunks opaquely)
+		const secretText = 'Attachment after last read test';
+		const { encrypted_secret, encrypted_key_material } = encryptSharedSecret(
+			secretText,
+			sharedServerX25519PubKey
+		);
+
+		const chunkSize = 64 * 1024;
+		const attachmentSize = chunkSize + 100;
+		const encryptedChunks = [chunkSize, attachmentSize - chunkSize].map((plaintextLength) =>
+			Buffer.from(randomBytes(plaintextLength + 16)).toString('base64')
+		);
+
+		const createPayload = {
+			sender_email: 'me@arkaitz.dev',
+			receiver_email: 'arkaitzmugica@protonmail.com',
+			encrypted_secret,
+			encrypted_key_material,
+			attachment: {
+				filename: 'report.bin',
+				mime_type: 'application/octet-stream',
+				size: attachmentSize,
+				encrypted_chunks: encryptedChunks
+			},
+			expires_hours: 24,
+			max_reads: 1,
+			require_otp: false,
+			send_copy_to_sender: false,
+			ui_host: 'localhost'
+		};
+
+		const signedCreateRequest = createSignedRequestWithKeyPair(createPayload, senderKeyPair);
+		const createResponse = await request.post('http://localhost:3000/api/shared-secret/create', {
+			headers: {
+				'Content-Type': 'application/json',
+				Authorization: `Bearer ${senderAccessToken}`
+			},
+			data: signedCreateRequest
+		});
+
+		expect(createResponse.ok()).toBeTruthy();
+
+		const createSignedResponse = await createResponse.json();
+		const createData = verifySignedResponse(createSignedResponse, serverPubKey);
+		const receiverHash = new URL(createData.payload.url_receiver).searchParams.get('shared')!;
+
+		console.log('✅ Created secret with max_reads=1 and a 2-chunk attachment');
+
+		// Consume the only read as receiver
+		const confirmUrl = `http://localhost:3000/api/shared-secret/confirm-read`;
+		const confirmSignature = signQueryParamsWithKeyPair({ hash: receiverHash }, receiverKeyPair);
+		const signedConfirmUrl = `${confirmUrl}?hash=${receiverHash}&signature=${confirmSignature}`;
+		const confirmResponse = await request.get(signedConfirmUrl, {
+			headers: { Authorization: `Bearer ${receiverAccessToken}` }
+		});
+
+		expect(confirmResponse.ok()).toBeTruthy();
+		const confirmSignedResponse = await confirmResponse.json();
+		const confirmPayload = JSON.parse(decodePayloadBase64(confirmSignedResponse.payload));
+		expect(confirmPayload.pending_reads).toBe(0);
+
+		console.log('✅ Last read consumed');
+
+		// Download every chunk as receiver
+		const attachmentUrl = `http://localhost:3000/api/shared-secret/attachment`;
+		const downloadChunk = (chunk: number) => {
+			const params = { hash: receiverHash, chunk: String(chunk) };
+			const signature = signQueryParamsWithKeyPair(params, receiverKeyPair);
+			const url = `${attachmentUrl}?hash=${receiverHash}&chunk=${chunk}&signature=${signature}`;
+			return request.get(url, {
+				headers: { Authorization: `Bearer ${receiverAccessToken}` }
+			});
+		};
+
+		for (const [index, expectedChunk] of encryptedChunks.entries()) {
+			const downloadResponse = await downloadChunk(index);
+
+			if (!downloadResponse.ok()) {
+				const errorBody = await downloadResponse.text();
+				console.error(
+					`❌ Chunk ${index} download failed (${downloadResponse.status()}):`,
+					errorBody
+				);
+			}
+
+			expect(downloadResponse.ok()).toBeTruthy();
+
+			const downloadSignedResponse = await downloadResponse.json();
+			const downloadPayload = JSON.parse(decodePayloadBase64(downloadSignedResponse.payload));
+			expect(downloadPayload.encrypted_chunk).toBe(expectedChunk);
+			expect(downloadPayload.chunk_count).toBe(encryptedChunks.length);
+			// Download counted once its last chunk is served
+			const isLastChunk = index === encryptedChunks.length - 1;
+			expect(downloadPayload.pending_downloads).toBe(isLastChunk ? 0 : 1);
+		}
+
+		console.log('✅ All chunks downloaded after the last read');
+
+		// Reads and downloads consumed: nothing left for the receiver
+		const exhaustedResponse = await downloadChunk(0);
+		expect(exhaustedResponse.ok()).toBeFalsy();
+
+		console.log('✅ Receiver entry released after the last download');
+		console.log('🎉 TEST PASSED');
+		console.log('='.repeat(60));
+
+		await senderSession.clear();
+	});
+
 	test('should include ui_host in creation request', async ({ request }) => {
 		console.log('🧪 TEST: ui_host parameter validation');
 		console.log('='.repeat(60));